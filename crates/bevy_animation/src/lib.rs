@@ -27,7 +27,7 @@ pub mod prelude {
 }
 
 /// List of keyframes for one of the attribute of a [`Transform`].
-#[derive(Reflect, Clone, Debug)]
+#[derive(Reflect, Clone, Debug, PartialEq)]
 pub enum Keyframes {
     /// Keyframes for rotation.
     Rotation(Vec<Quat>),
@@ -49,7 +49,7 @@ pub enum Keyframes {
 /// Describes how an attribute of a [`Transform`] or [`MorphWeights`] should be animated.
 ///
 /// `keyframe_timestamps` and `keyframes` should have the same length.
-#[derive(Reflect, Clone, Debug)]
+#[derive(Reflect, Clone, Debug, PartialEq)]
 pub struct VariableCurve {
     /// Timestamp for each of the keyframes.
     pub keyframe_timestamps: Vec<f32>,
@@ -66,7 +66,7 @@ pub struct VariableCurve {
 }
 
 /// Interpolation method to use between keyframes.
-#[derive(Reflect, Clone, Debug)]
+#[derive(Reflect, Clone, Debug, PartialEq, Eq)]
 pub enum Interpolation {
     /// Linear interpolation between the two closest keyframes.
     Linear,
@@ -85,7 +85,7 @@ pub struct EntityPath {
 }
 
 /// A list of [`VariableCurve`], and the [`EntityPath`] to which they apply.
-#[derive(Asset, Reflect, Clone, Debug, Default)]
+#[derive(Asset, Reflect, Clone, Debug, Default, PartialEq)]
 pub struct AnimationClip {
     curves: Vec<Vec<VariableCurve>>,
     paths: HashMap<EntityPath, usize>,
@@ -893,6 +893,7 @@ impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<AnimationClip>()
             .register_asset_reflect::<AnimationClip>()
+            .register_asset_partial_eq::<AnimationClip>()
             .register_type::<AnimationPlayer>()
             .add_systems(
                 PostUpdate,
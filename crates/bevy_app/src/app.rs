@@ -1,6 +1,10 @@
-use crate::{First, Main, MainSchedulePlugin, Plugin, Plugins, StateTransition};
+use crate::{
+    First, Main, MainSchedulePlugin, Plugin, PluginDependency, PluginDependencyKind, Plugins,
+    StateTransition,
+};
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
+    event::ManualEventReader,
     prelude::*,
     schedule::{
         apply_state_transition, common_conditions::run_once as run_once_condition,
@@ -10,8 +14,11 @@ use bevy_ecs::{
 };
 use bevy_utils::{intern::Interned, thiserror::Error, tracing::debug, HashMap, HashSet};
 use std::{
+    any::TypeId,
     fmt::Debug,
+    num::NonZeroU8,
     panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    process::{ExitCode, Termination},
 };
 
 #[cfg(feature = "trace")]
@@ -32,6 +39,21 @@ pub type InternedAppLabel = Interned<dyn AppLabel>;
 pub(crate) enum AppError {
     #[error("duplicate plugin {plugin_name:?}")]
     DuplicatePlugin { plugin_name: String },
+    #[error("plugin {plugin_name:?} requires {dependency_name:?}, which must be added before it")]
+    MissingPluginDependency {
+        plugin_name: String,
+        dependency_name: String,
+    },
+    #[error("plugin {plugin_name:?} must be added before {dependent_name:?}, which depends on it")]
+    PluginAddedAfterDependent {
+        plugin_name: String,
+        dependent_name: String,
+    },
+    #[error("plugin {plugin_name:?} conflicts with {conflict_name:?}")]
+    ConflictingPlugins {
+        plugin_name: String,
+        conflict_name: String,
+    },
 }
 
 #[allow(clippy::needless_doctest_main)]
@@ -70,7 +92,7 @@ pub struct App {
     /// the application's event loop and advancing the [`Schedule`].
     /// Typically, it is not configured manually, but set by one of Bevy's built-in plugins.
     /// See `bevy::winit::WinitPlugin` and [`ScheduleRunnerPlugin`](crate::schedule_runner::ScheduleRunnerPlugin).
-    pub runner: Box<dyn FnOnce(App) + Send>, // Send bound is required to make App Send
+    pub runner: Box<dyn FnOnce(App) -> AppExit + Send>, // Send bound is required to make App Send
     /// The schedule that systems are added to by default.
     ///
     /// The schedule that runs the main loop of schedule execution.
@@ -79,12 +101,59 @@ pub struct App {
     pub main_schedule_label: InternedScheduleLabel,
     sub_apps: HashMap<InternedAppLabel, SubApp>,
     plugin_registry: Vec<Box<dyn Plugin>>,
-    plugin_name_added: HashSet<String>,
+    added_plugins: AddedPlugins,
     /// A private counter to prevent incorrect calls to `App::run()` from `Plugin::build()`
     building_plugin_depth: usize,
     plugins_state: PluginsState,
 }
 
+/// The names, types and declared dependencies of the plugins added to an [`App`].
+///
+/// Returned by [`App::added_plugins`], they can be inserted into another app with
+/// [`App::insert_added_plugins`] to build plugins there as if they were added to the original
+/// app, e.g. when reloading them.
+#[derive(Clone, Default)]
+pub struct AddedPlugins {
+    /// The names of the unique plugins added so far.
+    names: HashSet<String>,
+    /// The types of the plugins added so far, including the ones being built.
+    types: HashSet<TypeId>,
+    /// The dependencies declared by the plugins added so far, with the name of their plugin.
+    dependencies: Vec<(String, PluginDependency)>,
+}
+
+impl AddedPlugins {
+    /// Returns the plugins of `self` that aren't in `other`.
+    pub fn difference(&self, other: &AddedPlugins) -> AddedPlugins {
+        AddedPlugins {
+            names: self.names.difference(&other.names).cloned().collect(),
+            types: self.types.difference(&other.types).copied().collect(),
+            dependencies: self
+                .dependencies
+                .iter()
+                .filter(|(name, dependency)| {
+                    !other
+                        .dependencies
+                        .iter()
+                        .any(|(other_name, other_dependency)| {
+                            name == other_name
+                                && dependency.kind() == other_dependency.kind()
+                                && dependency.type_id() == other_dependency.type_id()
+                        })
+                })
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Adds the plugins of `other` to `self`.
+    pub fn extend(&mut self, other: AddedPlugins) {
+        self.names.extend(other.names);
+        self.types.extend(other.types);
+        self.dependencies.extend(other.dependencies);
+    }
+}
+
 impl Debug for App {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "App {{ sub_apps: ")?;
@@ -235,7 +304,7 @@ impl App {
             runner: Box::new(run_once),
             sub_apps: HashMap::default(),
             plugin_registry: Vec::default(),
-            plugin_name_added: Default::default(),
+            added_plugins: AddedPlugins::default(),
             main_schedule_label: Main.intern(),
             building_plugin_depth: 0,
             plugins_state: PluginsState::Adding,
@@ -291,10 +360,22 @@ impl App {
     ///
     /// By default, *Bevy* uses the `winit` crate for window creation.
     ///
+    /// # Exit status
+    ///
+    /// Returns the [`AppExit`] the runner stopped with, which can be returned from `main`
+    /// to report it to the operating system:
+    ///
+    /// ```no_run
+    /// # use bevy_app::prelude::*;
+    /// fn main() -> AppExit {
+    ///     App::new().run()
+    /// }
+    /// ```
+    ///
     /// # Panics
     ///
     /// Panics if called from `Plugin::build()`, because it would prevent other plugins to properly build.
-    pub fn run(&mut self) {
+    pub fn run(&mut self) -> AppExit {
         #[cfg(feature = "trace")]
         let _bevy_app_run_span = info_span!("bevy_app").entered();
 
@@ -304,7 +385,28 @@ impl App {
         }
 
         let runner = std::mem::replace(&mut app.runner, Box::new(run_once));
-        runner(app);
+        runner(app)
+    }
+
+    /// Returns the [`AppExit`] requested by the [`AppExit`] events of this frame and the previous one,
+    /// or `None` if no exit was requested.
+    ///
+    /// When several exit events were sent, the first [error](AppExit::Error) is returned,
+    /// so that a failure isn't hidden by a success sent in the same frame.
+    /// [`AppExit::Success`] is only returned if every event is a success.
+    ///
+    /// Runners should call this after each [`update`](Self::update), and stop if it returns `Some`.
+    pub fn should_exit(&self) -> Option<AppExit> {
+        let events = self.world.get_resource::<Events<AppExit>>()?;
+        let mut reader = ManualEventReader::default();
+        let mut exit = None;
+        for event in reader.read(events) {
+            if event.is_error() {
+                return Some(*event);
+            }
+            exit = Some(AppExit::Success);
+        }
+        exit
     }
 
     /// Check the state of all plugins already added to this app. This is usually called by the
@@ -619,17 +721,23 @@ impl App {
     /// ```
     /// # use bevy_app::prelude::*;
     /// #
-    /// fn my_runner(mut app: App) {
+    /// fn my_runner(mut app: App) -> AppExit {
     ///     loop {
     ///         println!("In main loop");
     ///         app.update();
+    ///         if let Some(exit) = app.should_exit() {
+    ///             return exit;
+    ///         }
     ///     }
     /// }
     ///
     /// App::new()
     ///     .set_runner(my_runner);
     /// ```
-    pub fn set_runner(&mut self, run_fn: impl FnOnce(App) + 'static + Send) -> &mut Self {
+    pub fn set_runner(
+        &mut self,
+        run_fn: impl FnOnce(App) -> AppExit + 'static + Send,
+    ) -> &mut Self {
         self.runner = Box::new(run_fn);
         self
     }
@@ -641,11 +749,15 @@ impl App {
         plugin: Box<dyn Plugin>,
     ) -> Result<&mut Self, AppError> {
         debug!("added plugin: {}", plugin.name());
-        if plugin.is_unique() && !self.plugin_name_added.insert(plugin.name().to_string()) {
+        if plugin.is_unique() && self.added_plugins.names.contains(plugin.name()) {
             Err(AppError::DuplicatePlugin {
                 plugin_name: plugin.name().to_string(),
             })?;
         }
+        self.add_plugin_dependencies(plugin.as_ref())?;
+        if plugin.is_unique() {
+            self.added_plugins.names.insert(plugin.name().to_string());
+        }
 
         // Reserve that position in the plugin registry. if a plugin adds plugins, they will be correctly ordered
         let plugin_position_in_registry = self.plugin_registry.len();
@@ -661,6 +773,86 @@ impl App {
         Ok(self)
     }
 
+    /// Checks the [dependencies](Plugin::dependencies) of `plugin` against the plugins already
+    /// added, adding its missing default-constructible dependencies, and records them.
+    fn add_plugin_dependencies(&mut self, plugin: &dyn Plugin) -> Result<(), AppError> {
+        let type_id = plugin.as_any().type_id();
+        let already_added = self.added_plugins.types.contains(&type_id);
+        for (dependent_name, dependency) in &self.added_plugins.dependencies {
+            if dependency.type_id() != type_id {
+                continue;
+            }
+            match dependency.kind() {
+                PluginDependencyKind::Required | PluginDependencyKind::Optional
+                    if !already_added =>
+                {
+                    return Err(AppError::PluginAddedAfterDependent {
+                        plugin_name: plugin.name().to_string(),
+                        dependent_name: dependent_name.clone(),
+                    });
+                }
+                PluginDependencyKind::Conflict => {
+                    return Err(AppError::ConflictingPlugins {
+                        plugin_name: plugin.name().to_string(),
+                        conflict_name: dependent_name.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let dependencies = plugin.dependencies();
+        for dependency in &dependencies {
+            let added = self.added_plugins.types.contains(&dependency.type_id());
+            match dependency.kind() {
+                PluginDependencyKind::Required if !added => {
+                    let Some(default_plugin) = dependency.default_plugin() else {
+                        return Err(AppError::MissingPluginDependency {
+                            plugin_name: plugin.name().to_string(),
+                            dependency_name: dependency.name().to_string(),
+                        });
+                    };
+                    debug!(
+                        "adding {} as a dependency of {}",
+                        dependency.name(),
+                        plugin.name()
+                    );
+                    self.add_boxed_plugin(default_plugin)?;
+                }
+                PluginDependencyKind::Conflict if added => {
+                    return Err(AppError::ConflictingPlugins {
+                        plugin_name: plugin.name().to_string(),
+                        conflict_name: dependency.name().to_string(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        self.added_plugins.types.insert(type_id);
+        self.added_plugins.dependencies.extend(
+            dependencies
+                .into_iter()
+                .map(|dependency| (plugin.name().to_string(), dependency)),
+        );
+        Ok(())
+    }
+
+    /// Returns the names, types and declared dependencies of the plugins added to this app.
+    pub fn added_plugins(&self) -> AddedPlugins {
+        self.added_plugins.clone()
+    }
+
+    /// Records `plugins` as added to this app, so the plugins added afterwards are checked for
+    /// duplicates and [dependencies](Plugin::dependencies) against them.
+    ///
+    /// The plugins themselves aren't added: they aren't built and aren't returned by
+    /// [`is_plugin_added`](Self::is_plugin_added) or [`get_added_plugins`](Self::get_added_plugins).
+    pub fn insert_added_plugins(&mut self, plugins: AddedPlugins) -> &mut Self {
+        self.added_plugins.extend(plugins);
+        self
+    }
+
     /// Checks if a [`Plugin`] has already been added.
     ///
     /// This can be used by plugins to check if a plugin they depend upon has already been
@@ -1034,7 +1226,7 @@ impl App {
     }
 }
 
-fn run_once(mut app: App) {
+fn run_once(mut app: App) -> AppExit {
     while app.plugins_state() == PluginsState::Adding {
         #[cfg(not(target_arch = "wasm32"))]
         bevy_tasks::tick_global_task_pools_on_main_thread();
@@ -1043,6 +1235,8 @@ fn run_once(mut app: App) {
     app.cleanup();
 
     app.update();
+
+    app.should_exit().unwrap_or_default()
 }
 
 /// An event that indicates the [`App`] should exit. This will fully exit the app process at the
@@ -1055,19 +1249,75 @@ fn run_once(mut app: App) {
 /// If you don't require access to other components or resources, consider implementing the [`Drop`]
 /// trait on components/resources for code that runs on exit. That saves you from worrying about
 /// system schedule ordering, and is idiomatic Rust.
-#[derive(Event, Debug, Clone, Default)]
-pub struct AppExit;
+///
+/// The exit is either a success or an error with a non-zero exit code, which [`App::run`] returns.
+/// If several exit events are sent in the same frame, the app exits with the first error among them,
+/// or with a success if there is none. See [`App::should_exit`].
+#[derive(Event, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AppExit {
+    /// The app exits successfully.
+    #[default]
+    Success,
+    /// The app exits with an error, and the given exit code.
+    Error(NonZeroU8),
+}
+
+impl AppExit {
+    /// Creates an [`AppExit::Error`] with the generic error code `1`.
+    pub const fn error() -> Self {
+        Self::Error(NonZeroU8::MIN)
+    }
+
+    /// Returns `true` if this is an [`AppExit::Success`].
+    pub const fn is_success(&self) -> bool {
+        matches!(self, AppExit::Success)
+    }
+
+    /// Returns `true` if this is an [`AppExit::Error`].
+    pub const fn is_error(&self) -> bool {
+        matches!(self, AppExit::Error(_))
+    }
+
+    /// Creates an [`AppExit`] from an exit code: `0` is a success, any other code an error.
+    pub const fn from_code(code: u8) -> Self {
+        match NonZeroU8::new(code) {
+            Some(code) => Self::Error(code),
+            None => Self::Success,
+        }
+    }
+
+    /// Returns the exit code to report to the operating system.
+    pub const fn code(&self) -> u8 {
+        match self {
+            AppExit::Success => 0,
+            AppExit::Error(code) => code.get(),
+        }
+    }
+}
+
+impl From<u8> for AppExit {
+    fn from(code: u8) -> Self {
+        Self::from_code(code)
+    }
+}
+
+impl Termination for AppExit {
+    fn report(self) -> ExitCode {
+        ExitCode::from(self.code())
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use std::marker::PhantomData;
 
     use bevy_ecs::{
+        event::EventWriter,
         schedule::{OnEnter, States},
-        system::Commands,
+        system::{Commands, Resource},
     };
 
-    use crate::{App, Plugin};
+    use crate::{App, AppExit, Plugin, PluginDependency, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1274,13 +1524,15 @@ mod tests {
         #[derive(Resource)]
         struct MyState {}
 
-        fn my_runner(mut app: App) {
+        fn my_runner(mut app: App) -> AppExit {
             let my_state = MyState {};
             app.world.insert_resource(my_state);
 
             for _ in 0..5 {
                 app.update();
             }
+
+            AppExit::Success
         }
 
         fn my_system(_: Res<MyState>) {
@@ -1293,4 +1545,144 @@ mod tests {
             .add_systems(PreUpdate, my_system)
             .run();
     }
+
+    #[test]
+    fn run_returns_app_exit() {
+        assert_eq!(AppExit::Success, App::new().run());
+
+        fn exit_with_error(mut app_exit_events: EventWriter<AppExit>) {
+            app_exit_events.send(AppExit::from_code(3));
+        }
+        assert_eq!(
+            AppExit::from_code(3),
+            App::new().add_systems(Update, exit_with_error).run()
+        );
+    }
+
+    #[test]
+    fn first_exit_error_wins() {
+        fn exit_several_times(mut app_exit_events: EventWriter<AppExit>) {
+            app_exit_events.send(AppExit::Success);
+            app_exit_events.send(AppExit::from_code(4));
+            app_exit_events.send(AppExit::error());
+        }
+
+        let mut app = App::new();
+        app.add_systems(Update, exit_several_times);
+        assert_eq!(None, app.should_exit());
+        app.update();
+        assert_eq!(Some(AppExit::from_code(4)), app.should_exit());
+
+        let mut app = App::new();
+        app.world.send_event(AppExit::Success);
+        app.world.send_event(AppExit::Success);
+        assert_eq!(Some(AppExit::Success), app.should_exit());
+    }
+
+    #[test]
+    fn app_exit_codes() {
+        assert_eq!(AppExit::Success, AppExit::from_code(0));
+        assert_eq!(0, AppExit::Success.code());
+        assert_eq!(1, AppExit::error().code());
+        assert!(AppExit::from(7).is_error());
+        assert_eq!(7, AppExit::from(7).code());
+    }
+
+    #[derive(Resource, Default)]
+    struct BuildOrder(Vec<&'static str>);
+
+    #[derive(Default)]
+    struct TimePlugin;
+    impl Plugin for TimePlugin {
+        fn build(&self, app: &mut App) {
+            app.init_resource::<BuildOrder>()
+                .world
+                .resource_mut::<BuildOrder>()
+                .0
+                .push("time");
+        }
+    }
+
+    struct NetworkPlugin;
+    impl Plugin for NetworkPlugin {
+        fn build(&self, app: &mut App) {
+            app.init_resource::<BuildOrder>()
+                .world
+                .resource_mut::<BuildOrder>()
+                .0
+                .push("network");
+        }
+
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![
+                PluginDependency::required::<TimePlugin>(),
+                PluginDependency::optional::<PluginA>(),
+                PluginDependency::conflicts::<PluginB>(),
+            ]
+        }
+    }
+
+    struct AutoNetworkPlugin;
+    impl Plugin for AutoNetworkPlugin {
+        fn build(&self, _app: &mut App) {}
+
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::required_or_default::<TimePlugin>()]
+        }
+    }
+
+    #[test]
+    fn plugin_dependencies_are_met() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, PluginA, NetworkPlugin));
+        assert_eq!(
+            vec!["time", "network"],
+            app.world.resource::<BuildOrder>().0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "requires \"bevy_app::app::tests::TimePlugin\"")]
+    fn missing_required_plugin() {
+        App::new().add_plugins((NetworkPlugin, TimePlugin));
+    }
+
+    #[test]
+    fn missing_default_plugin_is_added() {
+        let mut app = App::new();
+        app.add_plugins(AutoNetworkPlugin);
+        assert!(app.is_plugin_added::<TimePlugin>());
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, AutoNetworkPlugin));
+        assert_eq!(1, app.get_added_plugins::<TimePlugin>().len());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be added before \"bevy_app::app::tests::NetworkPlugin\"")]
+    fn optional_plugin_added_too_late() {
+        App::new().add_plugins((TimePlugin, NetworkPlugin, PluginA));
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicts with")]
+    fn conflicting_plugins() {
+        App::new().add_plugins((TimePlugin, NetworkPlugin, PluginB));
+    }
+
+    #[test]
+    fn added_plugins_are_checked_in_another_app() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+
+        let mut other = App::empty();
+        other.insert_added_plugins(app.added_plugins());
+        other.add_plugins(NetworkPlugin);
+        assert!(!other.is_plugin_added::<TimePlugin>());
+
+        let added = other.added_plugins().difference(&app.added_plugins());
+        let mut third = App::empty();
+        third.insert_added_plugins(added);
+        assert!(third.add_boxed_plugin(Box::new(TimePlugin)).is_err());
+    }
 }
@@ -1,16 +1,48 @@
 //! Utilities for testing in CI environments.
 
-use crate::{app::AppExit, App, Update};
-use serde::Deserialize;
+use crate::{app::AppExit, App, Last, Startup, Update};
+use serde::{
+    de::{self, DeserializeSeed, EnumAccess, IgnoredAny, MapAccess, SeqAccess, VariantAccess},
+    Deserialize, Deserializer,
+};
+use std::fmt;
 
-use bevy_ecs::prelude::Resource;
-use bevy_utils::tracing::info;
+use bevy_ecs::{
+    prelude::{Local, Mut, Resource, World},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectResource},
+};
+use bevy_reflect::{serde::UntypedReflectDeserializer, GetPath, Reflect, TypeRegistry};
+use bevy_utils::tracing::{error, info};
 
 /// A configuration struct for automated CI testing.
 ///
 /// It gets used when the `bevy_ci_testing` feature is enabled to automatically
 /// exit a Bevy app when run through the CI. This is needed because otherwise
 /// Bevy apps would be stuck in the game loop and wouldn't allow the CI to progress.
+///
+/// The configuration can also script the run with a timeline of [`CiTestingEvent`]s, for example:
+///
+/// ```ron
+/// (
+///     exit_after: Some(20),
+///     events: [
+///         (5, SendEvent({
+///             "bevy_input::keyboard::KeyboardInput": (
+///                 key_code: Space,
+///                 state: Pressed,
+///                 window: 8589934591,
+///             ),
+///         })),
+///         (10, AssertResource(
+///             resource: "my_game::Score",
+///             path: ".0",
+///             value: {"u32": 1},
+///         )),
+///     ],
+/// )
+/// ```
+///
+/// If an event can't be run or an assertion fails, the app exits with [`AppExit::error`].
 #[derive(Deserialize, Resource)]
 pub struct CiTestingConfig {
     /// The number of frames after which Bevy should exit.
@@ -20,8 +52,136 @@ pub struct CiTestingConfig {
     /// Frames at which to capture a screenshot.
     #[serde(default)]
     pub screenshot_frames: Vec<u32>,
+    /// Events to run at the end of the given frames.
+    ///
+    /// Their values are reflected, so they are read from the `events` field of the configuration
+    /// file in [`Startup`], once the app's types have been registered.
+    #[serde(skip)]
+    pub events: Vec<CiTestingEventOnFrame>,
+}
+
+/// A [`CiTestingEvent`] to run at the end of a given frame, after [`Update`].
+///
+/// It is written as a `(frame, event)` tuple in the configuration file.
+#[derive(Debug)]
+pub struct CiTestingEventOnFrame {
+    /// The frame at which to run the event, starting at 0 for the first frame.
+    pub frame: u32,
+    /// The event to run.
+    pub event: CiTestingEvent,
+}
+
+/// A scripted event of a [`CiTestingConfig`] timeline.
+///
+/// Reflected values are written as a map from their type path to their value, as read by
+/// [`UntypedReflectDeserializer`].
+#[derive(Debug)]
+pub enum CiTestingEvent {
+    /// Sends an event, such as a keyboard, mouse or gamepad input event from `bevy_input`.
+    ///
+    /// The event type needs to be registered with `#[reflect(Event)]`.
+    /// Input events sent at the end of a frame are seen by the app in the following frame.
+    SendEvent(Box<dyn Reflect>),
+    /// Checks that the value at `path` in a resource is equal to `value`.
+    AssertResource {
+        /// The type path of the resource, which needs to be registered with `#[reflect(Resource)]`.
+        resource: String,
+        /// The reflection path of the checked value in the resource. An empty path checks the
+        /// whole resource.
+        path: String,
+        /// The expected value.
+        value: Box<dyn Reflect>,
+    },
+    /// Checks that the value at `path` in a component is equal to `value`, for every entity with
+    /// this component. Fails if no entity has the component.
+    AssertComponent {
+        /// The type path of the component, which needs to be registered with `#[reflect(Component)]`.
+        component: String,
+        /// The reflection path of the checked value in the component. An empty path checks the
+        /// whole component.
+        path: String,
+        /// The expected value.
+        value: Box<dyn Reflect>,
+    },
+}
+
+impl CiTestingEvent {
+    /// Runs this event on the world, returning a description of the failure if it couldn't be
+    /// run or if its assertion doesn't hold.
+    pub fn run(&self, world: &mut World, registry: &TypeRegistry) -> Result<(), String> {
+        match self {
+            CiTestingEvent::SendEvent(event) => {
+                let type_path = event.reflect_type_path();
+                let reflect_event = event
+                    .get_represented_type_info()
+                    .and_then(|info| registry.get_type_data::<ReflectEvent>(info.type_id()))
+                    .ok_or_else(|| format!("`{type_path}` is not registered as an event"))?;
+                if !reflect_event.send(world, event.as_ref()) {
+                    return Err(format!("failed to send event `{type_path}`"));
+                }
+                Ok(())
+            }
+            CiTestingEvent::AssertResource {
+                resource,
+                path,
+                value,
+            } => {
+                let reflect_resource = registry
+                    .get_with_type_path(resource)
+                    .and_then(|registration| registration.data::<ReflectResource>())
+                    .ok_or_else(|| format!("`{resource}` is not registered as a resource"))?;
+                let actual = reflect_resource
+                    .reflect(world)
+                    .ok_or_else(|| format!("resource `{resource}` does not exist"))?;
+                assert_value(resource, actual, path, value.as_ref())
+            }
+            CiTestingEvent::AssertComponent {
+                component,
+                path,
+                value,
+            } => {
+                let reflect_component = registry
+                    .get_with_type_path(component)
+                    .and_then(|registration| registration.data::<ReflectComponent>())
+                    .ok_or_else(|| format!("`{component}` is not registered as a component"))?;
+                let mut found = false;
+                for entity in world.iter_entities() {
+                    if let Some(actual) = reflect_component.reflect(entity) {
+                        found = true;
+                        assert_value(component, actual, path, value.as_ref())
+                            .map_err(|err| format!("{err} on entity {:?}", entity.id()))?;
+                    }
+                }
+                if !found {
+                    return Err(format!("no entity has component `{component}`"));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn assert_value(
+    type_path: &str,
+    actual: &dyn Reflect,
+    path: &str,
+    expected: &dyn Reflect,
+) -> Result<(), String> {
+    let actual = actual
+        .reflect_path(path)
+        .map_err(|err| format!("invalid path `{path}` in `{type_path}`: {err}"))?;
+    if actual.reflect_partial_eq(expected) != Some(true) {
+        return Err(format!(
+            "expected `{type_path}{path}` to be {expected:?}, found {actual:?}"
+        ));
+    }
+    Ok(())
 }
 
+/// The configuration file, kept until its events can be read with the app's type registry.
+#[derive(Resource)]
+struct CiTestingConfigSource(String);
+
 fn ci_testing_exit_after(
     mut current_frame: bevy_ecs::prelude::Local<u32>,
     ci_testing_config: bevy_ecs::prelude::Res<CiTestingConfig>,
@@ -29,32 +189,453 @@ fn ci_testing_exit_after(
 ) {
     if let Some(exit_after) = ci_testing_config.exit_after {
         if *current_frame > exit_after {
-            app_exit_events.send(AppExit);
+            app_exit_events.send(AppExit::Success);
             info!("Exiting after {} frames. Test successful!", exit_after);
         }
     }
     *current_frame += 1;
 }
 
+fn ci_testing_read_events(world: &mut World) {
+    let Some(CiTestingConfigSource(source)) = world.remove_resource::<CiTestingConfigSource>()
+    else {
+        return;
+    };
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let events = deserialize_events(&source, &registry.read())
+        .expect("error deserializing CI testing events");
+    world
+        .resource_mut::<CiTestingConfig>()
+        .events
+        .extend(events);
+}
+
+fn ci_testing_run_events(world: &mut World, mut current_frame: Local<u32>) {
+    let frame = *current_frame;
+    *current_frame += 1;
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    world.resource_scope(|world, config: Mut<CiTestingConfig>| {
+        for CiTestingEventOnFrame { event, .. } in
+            config.events.iter().filter(|event| event.frame == frame)
+        {
+            if let Err(err) = event.run(world, &registry) {
+                error!("CI testing event failed on frame {}: {}", frame, err);
+                world.send_event(AppExit::error());
+                return;
+            }
+        }
+    });
+}
+
+fn deserialize_events(
+    source: &str,
+    registry: &TypeRegistry,
+) -> ron::error::SpannedResult<Vec<CiTestingEventOnFrame>> {
+    let mut deserializer = ron::Deserializer::from_str(source)?;
+    let events = CiTestingEventsDeserializer { registry }
+        .deserialize(&mut deserializer)
+        .map_err(|error| deserializer.span_error(error))?;
+    deserializer
+        .end()
+        .map_err(|error| deserializer.span_error(error))?;
+    Ok(events)
+}
+
+const CONFIG_STRUCT: &str = "CiTestingConfig";
+const CONFIG_FIELDS: &[&str] = &["exit_after", "frame_time", "screenshot_frames", "events"];
+const CONFIG_FIELD_EVENTS: &str = "events";
+
+const EVENT_ENUM: &str = "CiTestingEvent";
+const EVENT_SEND_EVENT: &str = "SendEvent";
+const EVENT_ASSERT_RESOURCE: &str = "AssertResource";
+const EVENT_ASSERT_COMPONENT: &str = "AssertComponent";
+const EVENT_VARIANTS: &[&str] = &[
+    EVENT_SEND_EVENT,
+    EVENT_ASSERT_RESOURCE,
+    EVENT_ASSERT_COMPONENT,
+];
+
+const ASSERT_FIELD_RESOURCE: &str = "resource";
+const ASSERT_FIELD_COMPONENT: &str = "component";
+const ASSERT_FIELD_PATH: &str = "path";
+const ASSERT_FIELD_VALUE: &str = "value";
+const ASSERT_RESOURCE_FIELDS: &[&str] =
+    &[ASSERT_FIELD_RESOURCE, ASSERT_FIELD_PATH, ASSERT_FIELD_VALUE];
+const ASSERT_COMPONENT_FIELDS: &[&str] = &[
+    ASSERT_FIELD_COMPONENT,
+    ASSERT_FIELD_PATH,
+    ASSERT_FIELD_VALUE,
+];
+
+/// A struct field or enum variant name.
+struct Ident(String);
+
+impl<'de> Deserialize<'de> for Ident {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IdentVisitor;
+
+        impl<'de> de::Visitor<'de> for IdentVisitor {
+            type Value = Ident;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("identifier")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(Ident(value.to_string()))
+            }
+
+            fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+                Ok(Ident(value))
+            }
+        }
+
+        deserializer.deserialize_identifier(IdentVisitor)
+    }
+}
+
+/// Reads the `events` field of a [`CiTestingConfig`], ignoring the other fields.
+struct CiTestingEventsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CiTestingEventsDeserializer<'a> {
+    type Value = Vec<CiTestingEventOnFrame>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_struct(CONFIG_STRUCT, CONFIG_FIELDS, self)
+    }
+}
+
+impl<'a, 'de> de::Visitor<'de> for CiTestingEventsDeserializer<'a> {
+    type Value = Vec<CiTestingEventOnFrame>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("CI testing config struct")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut events = Vec::new();
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            if key == CONFIG_FIELD_EVENTS {
+                events = map.next_value_seed(EventSeqDeserializer {
+                    registry: self.registry,
+                })?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(events)
+    }
+}
+
+struct EventSeqDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EventSeqDeserializer<'a> {
+    type Value = Vec<CiTestingEventOnFrame>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> de::Visitor<'de> for EventSeqDeserializer<'a> {
+    type Value = Vec<CiTestingEventOnFrame>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("list of (frame, event) tuples")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut events = Vec::new();
+        while let Some(event) = seq.next_element_seed(EventOnFrameDeserializer {
+            registry: self.registry,
+        })? {
+            events.push(event);
+        }
+        Ok(events)
+    }
+}
+
+struct EventOnFrameDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EventOnFrameDeserializer<'a> {
+    type Value = CiTestingEventOnFrame;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> de::Visitor<'de> for EventOnFrameDeserializer<'a> {
+    type Value = CiTestingEventOnFrame;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("(frame, event) tuple")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let frame = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let event = seq
+            .next_element_seed(EventDeserializer {
+                registry: self.registry,
+            })?
+            .ok_or_else(|| de::Error::invalid_length(1, &"(frame, event) tuple"))?;
+        Ok(CiTestingEventOnFrame { frame, event })
+    }
+}
+
+struct EventDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EventDeserializer<'a> {
+    type Value = CiTestingEvent;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum(EVENT_ENUM, EVENT_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> de::Visitor<'de> for EventDeserializer<'a> {
+    type Value = CiTestingEvent;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("CI testing event")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (Ident(variant), access) = data.variant::<Ident>()?;
+        match variant.as_str() {
+            EVENT_SEND_EVENT => access
+                .newtype_variant_seed(UntypedReflectDeserializer::new(self.registry))
+                .map(CiTestingEvent::SendEvent),
+            EVENT_ASSERT_RESOURCE => {
+                let (resource, path, value) = access.struct_variant(
+                    ASSERT_RESOURCE_FIELDS,
+                    AssertVisitor {
+                        registry: self.registry,
+                        fields: ASSERT_RESOURCE_FIELDS,
+                    },
+                )?;
+                Ok(CiTestingEvent::AssertResource {
+                    resource,
+                    path,
+                    value,
+                })
+            }
+            EVENT_ASSERT_COMPONENT => {
+                let (component, path, value) = access.struct_variant(
+                    ASSERT_COMPONENT_FIELDS,
+                    AssertVisitor {
+                        registry: self.registry,
+                        fields: ASSERT_COMPONENT_FIELDS,
+                    },
+                )?;
+                Ok(CiTestingEvent::AssertComponent {
+                    component,
+                    path,
+                    value,
+                })
+            }
+            _ => Err(de::Error::unknown_variant(&variant, EVENT_VARIANTS)),
+        }
+    }
+}
+
+/// Reads the fields of an assertion: the type path in its first field, an optional `path`, and
+/// the expected `value`.
+struct AssertVisitor<'a> {
+    registry: &'a TypeRegistry,
+    fields: &'static [&'static str],
+}
+
+impl<'a, 'de> de::Visitor<'de> for AssertVisitor<'a> {
+    type Value = (String, String, Box<dyn Reflect>);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("CI testing assertion")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let type_field = self.fields[0];
+        let mut type_path = None;
+        let mut path = None;
+        let mut value = None;
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            match key.as_str() {
+                key if key == type_field => {
+                    if type_path.is_some() {
+                        return Err(de::Error::duplicate_field(type_field));
+                    }
+                    type_path = Some(map.next_value::<String>()?);
+                }
+                ASSERT_FIELD_PATH => {
+                    if path.is_some() {
+                        return Err(de::Error::duplicate_field(ASSERT_FIELD_PATH));
+                    }
+                    path = Some(map.next_value::<String>()?);
+                }
+                ASSERT_FIELD_VALUE => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field(ASSERT_FIELD_VALUE));
+                    }
+                    value =
+                        Some(map.next_value_seed(UntypedReflectDeserializer::new(self.registry))?);
+                }
+                key => return Err(de::Error::unknown_field(key, self.fields)),
+            }
+        }
+        let type_path = type_path.ok_or_else(|| de::Error::missing_field(type_field))?;
+        let value = value.ok_or_else(|| de::Error::missing_field(ASSERT_FIELD_VALUE))?;
+        Ok((type_path, path.unwrap_or_default(), value))
+    }
+}
+
 pub(crate) fn setup_app(app: &mut App) -> &mut App {
     #[cfg(not(target_arch = "wasm32"))]
-    let config: CiTestingConfig = {
+    let source = {
         let filename = std::env::var("CI_TESTING_CONFIG")
             .unwrap_or_else(|_| "ci_testing_config.ron".to_string());
-        ron::from_str(
-            &std::fs::read_to_string(filename)
-                .expect("error reading CI testing configuration file"),
-        )
-        .expect("error deserializing CI testing configuration file")
+        std::fs::read_to_string(filename).expect("error reading CI testing configuration file")
     };
     #[cfg(target_arch = "wasm32")]
-    let config: CiTestingConfig = {
-        let config = include_str!("../../../ci_testing_config.ron");
-        ron::from_str(config).expect("error deserializing CI testing configuration file")
-    };
+    let source = include_str!("../../../ci_testing_config.ron").to_string();
+
+    setup_app_with_config(app, source)
+}
+
+fn setup_app_with_config(app: &mut App, source: String) -> &mut App {
+    let config: CiTestingConfig =
+        ron::from_str(&source).expect("error deserializing CI testing configuration file");
 
     app.insert_resource(config)
-        .add_systems(Update, ci_testing_exit_after);
+        .insert_resource(CiTestingConfigSource(source))
+        .add_systems(Startup, ci_testing_read_events)
+        .add_systems(Update, ci_testing_exit_after)
+        .add_systems(Last, ci_testing_run_events);
 
     app
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MainSchedulePlugin;
+    use bevy_ecs::prelude::*;
+
+    #[derive(Event, Reflect)]
+    #[reflect(Event)]
+    struct Jump {
+        height: f32,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Player {
+        jumps: u32,
+    }
+
+    fn jump(
+        mut jumps: EventReader<Jump>,
+        mut score: ResMut<Score>,
+        mut players: Query<&mut Player>,
+    ) {
+        for jump in jumps.read() {
+            if jump.height > 1.0 {
+                score.0 += 1;
+                for mut player in &mut players {
+                    player.jumps += 1;
+                }
+            }
+        }
+    }
+
+    fn test_app(source: &str) -> App {
+        let mut app = App::empty();
+        app.init_resource::<AppTypeRegistry>()
+            .add_plugins(MainSchedulePlugin)
+            .add_event::<AppExit>()
+            .add_event::<Jump>()
+            .register_type::<Jump>()
+            .register_type::<Score>()
+            .register_type::<Player>()
+            .init_resource::<Score>()
+            .add_systems(Update, jump);
+        app.world.spawn(Player::default());
+        setup_app_with_config(&mut app, source.to_string());
+        app
+    }
+
+    #[test]
+    fn run_scripted_events() {
+        let mut app = test_app(
+            r#"(
+                exit_after: None,
+                events: [
+                    (0, SendEvent({
+                        "bevy_app::ci_testing::tests::Jump": (height: 2.0),
+                    })),
+                    (1, AssertResource(
+                        resource: "bevy_app::ci_testing::tests::Score",
+                        path: ".0",
+                        value: {"u32": 1},
+                    )),
+                    (1, AssertComponent(
+                        component: "bevy_app::ci_testing::tests::Player",
+                        path: "jumps",
+                        value: {"u32": 1},
+                    )),
+                ],
+            )"#,
+        );
+
+        for _ in 0..3 {
+            app.update();
+            assert_eq!(None, app.should_exit());
+        }
+        assert_eq!(3, app.world.resource::<CiTestingConfig>().events.len());
+    }
+
+    #[test]
+    fn failed_assertion_exits_with_error() {
+        let mut app = test_app(
+            r#"(
+                exit_after: None,
+                events: [
+                    (1, AssertResource(
+                        resource: "bevy_app::ci_testing::tests::Score",
+                        value: {"bevy_app::ci_testing::tests::Score": (1)},
+                    )),
+                ],
+            )"#,
+        );
+
+        app.update();
+        assert_eq!(None, app.should_exit());
+        app.update();
+        assert_eq!(Some(AppExit::error()), app.should_exit());
+    }
+
+    #[test]
+    fn unknown_event_is_an_error() {
+        let registry = TypeRegistry::new();
+        assert!(deserialize_events(r#"(events: [(0, Exit)])"#, &registry).is_err());
+
+        let mut world = World::new();
+        let event = CiTestingEvent::SendEvent(Box::new(Jump { height: 1.0 }));
+        assert!(event.run(&mut world, &registry).is_err());
+    }
+}
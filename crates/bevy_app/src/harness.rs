@@ -0,0 +1,398 @@
+//! A harness to drive an [`App`] from tests, without a window or a runner.
+
+use crate::{App, PluginsState};
+use bevy_ecs::{
+    event::{Event, Events, ManualEventReader},
+    system::Resource,
+    world::{Mut, World},
+};
+use bevy_utils::{thiserror::Error, Duration, Instant};
+
+/// Wraps an [`App`] to write integration tests against it.
+///
+/// The harness finishes the app's plugins on creation, then runs frames on demand with
+/// [`update`](Self::update), [`run_frames`](Self::run_frames),
+/// [`run_frames_until`](Self::run_frames_until) or [`run_until`](Self::run_until).
+/// It can record the events sent while it runs, and snapshot the world through reflection to
+/// compare it with a golden file.
+///
+/// Other crates add helpers to the harness through extension traits, for example to use a fixed
+/// frame time in `bevy_time` or to press keys in `bevy_input`.
+///
+/// ```
+/// # use bevy_app::{prelude::*, AppHarness};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Event, Clone, Debug, PartialEq)]
+/// struct Ping(u32);
+///
+/// fn ping(mut count: Local<u32>, mut pings: EventWriter<Ping>) {
+///     *count += 1;
+///     pings.send(Ping(*count));
+/// }
+///
+/// let mut app = App::new();
+/// app.add_event::<Ping>().add_systems(Update, ping);
+///
+/// let mut harness = AppHarness::new(app);
+/// harness.record_events::<Ping>().run_frames(3);
+/// assert_eq!(harness.events::<Ping>(), &[Ping(1), Ping(2), Ping(3)]);
+/// ```
+pub struct AppHarness {
+    app: App,
+    frame: u32,
+    event_recorders: Vec<fn(&mut World)>,
+}
+
+/// The error returned by [`AppHarness::run_until`] and [`AppHarness::run_frames_until`] when
+/// their condition doesn't hold in time.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("condition did not hold after {frames:?} frames")]
+pub struct RunUntilTimeout {
+    /// The number of frames that were run.
+    pub frames: u32,
+}
+
+/// The events of type `E` recorded by an [`AppHarness`].
+#[derive(Resource)]
+struct RecordedEvents<E: Event> {
+    reader: ManualEventReader<E>,
+    events: Vec<E>,
+}
+
+impl AppHarness {
+    /// Creates a harness for the given app, waiting for its plugins to be ready and finishing them.
+    pub fn new(mut app: App) -> Self {
+        while app.plugins_state() == PluginsState::Adding {
+            #[cfg(not(target_arch = "wasm32"))]
+            bevy_tasks::tick_global_task_pools_on_main_thread();
+        }
+        app.finish();
+        app.cleanup();
+
+        Self {
+            app,
+            frame: 0,
+            event_recorders: Vec::new(),
+        }
+    }
+
+    /// Returns the wrapped app.
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns the wrapped app mutably.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Returns the world of the wrapped app.
+    pub fn world(&self) -> &World {
+        &self.app.world
+    }
+
+    /// Returns the world of the wrapped app mutably.
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.app.world
+    }
+
+    /// Returns the wrapped app, consuming the harness.
+    pub fn into_app(self) -> App {
+        self.app
+    }
+
+    /// Returns the number of frames run by the harness so far.
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Runs a single frame of the app, then records the events it sent.
+    pub fn update(&mut self) -> &mut Self {
+        self.app.update();
+        self.frame += 1;
+        for record in &self.event_recorders {
+            record(&mut self.app.world);
+        }
+        self
+    }
+
+    /// Runs `frames` frames of the app.
+    pub fn run_frames(&mut self, frames: u32) -> &mut Self {
+        for _ in 0..frames {
+            self.update();
+        }
+        self
+    }
+
+    /// Runs frames until `condition` holds, checking it after each frame.
+    ///
+    /// This is a frame limit: returns the number of frames that were run, or a
+    /// [`RunUntilTimeout`] if the condition still doesn't hold after `max_frames` frames.
+    pub fn run_frames_until(
+        &mut self,
+        max_frames: u32,
+        mut condition: impl FnMut(&World) -> bool,
+    ) -> Result<u32, RunUntilTimeout> {
+        for frames in 1..=max_frames {
+            self.update();
+            if condition(&self.app.world) {
+                return Ok(frames);
+            }
+        }
+        Err(RunUntilTimeout { frames: max_frames })
+    }
+
+    /// Runs frames until `condition` holds, checking it after each frame.
+    ///
+    /// This is a wall-clock limit, meant for conditions depending on background work such as
+    /// asset loading: returns the number of frames that were run, or a [`RunUntilTimeout`] if
+    /// the condition still doesn't hold once `timeout` elapsed. At least one frame is run.
+    pub fn run_until(
+        &mut self,
+        timeout: Duration,
+        mut condition: impl FnMut(&World) -> bool,
+    ) -> Result<u32, RunUntilTimeout> {
+        let start = Instant::now();
+        let mut frames = 0;
+        loop {
+            self.update();
+            frames += 1;
+            if condition(&self.app.world) {
+                return Ok(frames);
+            }
+            if start.elapsed() >= timeout {
+                return Err(RunUntilTimeout { frames });
+            }
+        }
+    }
+
+    /// Starts recording the events of type `E` sent from now on.
+    ///
+    /// Recorded events can be read with [`events`](Self::events) and
+    /// [`take_events`](Self::take_events). Unlike an event reader, the recording isn't limited to
+    /// the events of the last two frames. Recording the same event type twice has no effect.
+    pub fn record_events<E: Event + Clone>(&mut self) -> &mut Self {
+        if self.app.world.contains_resource::<RecordedEvents<E>>() {
+            return self;
+        }
+        let reader = self
+            .app
+            .world
+            .get_resource::<Events<E>>()
+            .map(|events| events.get_reader_current())
+            .unwrap_or_default();
+        self.app.world.insert_resource(RecordedEvents::<E> {
+            reader,
+            events: Vec::new(),
+        });
+        self.event_recorders.push(record_events::<E>);
+        self
+    }
+
+    /// Returns the events of type `E` recorded so far.
+    ///
+    /// Returns an empty slice if these events aren't [recorded](Self::record_events).
+    pub fn events<E: Event>(&self) -> &[E] {
+        self.app
+            .world
+            .get_resource::<RecordedEvents<E>>()
+            .map_or(&[], |recorded| &recorded.events)
+    }
+
+    /// Returns the events of type `E` recorded so far, and clears them.
+    pub fn take_events<E: Event>(&mut self) -> Vec<E> {
+        self.app
+            .world
+            .get_resource_mut::<RecordedEvents<E>>()
+            .map(|mut recorded| std::mem::take(&mut recorded.events))
+            .unwrap_or_default()
+    }
+}
+
+fn record_events<E: Event + Clone>(world: &mut World) {
+    world.resource_scope(|world, mut recorded: Mut<RecordedEvents<E>>| {
+        let Some(events) = world.get_resource::<Events<E>>() else {
+            return;
+        };
+        let RecordedEvents {
+            reader,
+            events: recorded,
+        } = &mut *recorded;
+        recorded.extend(reader.read(events).cloned());
+    });
+}
+
+#[cfg(feature = "bevy_reflect")]
+mod snapshot {
+    use super::AppHarness;
+    use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+    use bevy_reflect::TypeRegistration;
+    use std::{fmt::Write, path::Path};
+
+    /// The environment variable that makes [`AppHarness::assert_snapshot`] overwrite golden files
+    /// instead of comparing against them.
+    const UPDATE_SNAPSHOTS_VAR: &str = "BEVY_UPDATE_SNAPSHOTS";
+
+    impl AppHarness {
+        /// Returns a text snapshot of the world, for golden-file comparisons.
+        ///
+        /// Every entity is listed in order with the reflected value of each of its components
+        /// registered with `#[reflect(Component)]`, sorted by type path.
+        /// Components that aren't registered are left out, as are resources.
+        pub fn snapshot(&self) -> String {
+            self.snapshot_with(|_| true)
+        }
+
+        /// Like [`snapshot`](Self::snapshot), only including the components whose registration
+        /// matches `filter`.
+        pub fn snapshot_with(&self, filter: impl Fn(&TypeRegistration) -> bool) -> String {
+            let world = &self.app.world;
+            let registry = world.resource::<AppTypeRegistry>().read();
+
+            let mut entities = world.iter_entities().collect::<Vec<_>>();
+            entities.sort_by_key(|entity| entity.id());
+
+            let mut snapshot = String::new();
+            for entity in entities {
+                let mut components = entity
+                    .archetype()
+                    .components()
+                    .filter_map(|component_id| {
+                        let type_id = world.components().get_info(component_id)?.type_id()?;
+                        let registration = registry.get(type_id)?;
+                        if !filter(registration) {
+                            return None;
+                        }
+                        let component = registration.data::<ReflectComponent>()?.reflect(entity)?;
+                        Some((registration.type_info().type_path(), component))
+                    })
+                    .collect::<Vec<_>>();
+                components.sort_by_key(|(type_path, _)| *type_path);
+
+                writeln!(snapshot, "{:?}", entity.id()).unwrap();
+                for (type_path, component) in components {
+                    let value = format!("{component:#?}").replace('\n', "\n  ");
+                    writeln!(snapshot, "  {type_path}: {value}").unwrap();
+                }
+            }
+            snapshot
+        }
+
+        /// Compares the [snapshot](Self::snapshot) of the world with the golden file at `path`.
+        ///
+        /// The golden file is written instead if it doesn't exist yet, or if the
+        /// `BEVY_UPDATE_SNAPSHOTS` environment variable is set.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the snapshot differs from the golden file, or if the file can't be read or
+        /// written.
+        pub fn assert_snapshot(&self, path: impl AsRef<Path>) {
+            let path = path.as_ref();
+            let snapshot = self.snapshot();
+            if std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() || !path.exists() {
+                std::fs::write(path, snapshot).unwrap_or_else(|err| {
+                    panic!("failed to write snapshot {}: {err}", path.display())
+                });
+                return;
+            }
+
+            let expected = std::fs::read_to_string(path)
+                .unwrap_or_else(|err| panic!("failed to read snapshot {}: {err}", path.display()));
+            assert!(
+                expected == snapshot,
+                "world snapshot differs from {}, set {UPDATE_SNAPSHOTS_VAR} to update it\n\
+                 expected:\n{expected}\nfound:\n{snapshot}",
+                path.display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AppHarness, RunUntilTimeout};
+    use crate::{App, Update};
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+    use bevy_utils::Duration;
+
+    #[derive(Event, Clone, Debug, PartialEq)]
+    struct Tick(u32);
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    fn tick(mut counter: ResMut<Counter>, mut ticks: EventWriter<Tick>) {
+        counter.0 += 1;
+        ticks.send(Tick(counter.0));
+    }
+
+    fn harness() -> AppHarness {
+        let mut app = App::new();
+        app.add_event::<Tick>()
+            .init_resource::<Counter>()
+            .add_systems(Update, tick);
+        AppHarness::new(app)
+    }
+
+    #[test]
+    fn run_frames_and_record_events() {
+        let mut harness = harness();
+        harness.update().record_events::<Tick>().run_frames(4);
+
+        assert_eq!(5, harness.frame());
+        assert_eq!(5, harness.world().resource::<Counter>().0);
+        assert_eq!(
+            vec![Tick(2), Tick(3), Tick(4), Tick(5)],
+            harness.take_events::<Tick>()
+        );
+        assert!(harness.events::<Tick>().is_empty());
+    }
+
+    #[test]
+    fn run_until_condition_or_timeout() {
+        let mut harness = harness();
+        assert_eq!(
+            Ok(3),
+            harness.run_frames_until(10, |world| world.resource::<Counter>().0 == 3)
+        );
+        assert_eq!(
+            Err(RunUntilTimeout { frames: 2 }),
+            harness.run_frames_until(2, |world| world.resource::<Counter>().0 == 0)
+        );
+        assert_eq!(5, harness.frame());
+
+        assert_eq!(
+            Ok(2),
+            harness.run_until(Duration::from_secs(60), |world| {
+                world.resource::<Counter>().0 == 7
+            })
+        );
+        assert_eq!(
+            Err(RunUntilTimeout { frames: 1 }),
+            harness.run_until(Duration::ZERO, |world| world.resource::<Counter>().0 == 0)
+        );
+    }
+
+    #[test]
+    fn snapshot_reflected_components() {
+        let mut harness = harness();
+        harness.app_mut().register_type::<Health>();
+        let entity = harness.world_mut().spawn(Health(3)).id();
+        let empty = harness.world_mut().spawn_empty().id();
+
+        let expected = format!(
+            "{entity:?}\n  bevy_app::harness::tests::Health: bevy_app::harness::tests::Health(\n      3,\n  )\n{empty:?}\n"
+        );
+        assert_eq!(expected, harness.snapshot());
+        assert_eq!(
+            format!("{entity:?}\n{empty:?}\n"),
+            harness.snapshot_with(|_| false)
+        );
+    }
+}
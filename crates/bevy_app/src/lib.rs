@@ -3,6 +3,7 @@
 #![warn(missing_docs)]
 
 mod app;
+mod harness;
 mod main_schedule;
 mod plugin;
 mod plugin_group;
@@ -13,6 +14,7 @@ pub mod ci_testing;
 
 pub use app::*;
 pub use bevy_derive::DynamicPlugin;
+pub use harness::*;
 pub use main_schedule::*;
 pub use plugin::*;
 pub use plugin_group::*;
@@ -22,7 +24,7 @@ pub use schedule_runner::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        app::App,
+        app::{App, AppExit},
         main_schedule::{
             First, FixedFirst, FixedLast, FixedPostUpdate, FixedPreUpdate, FixedUpdate, Last, Main,
             PostStartup, PostUpdate, PreStartup, PreUpdate, SpawnScene, Startup, StateTransition,
@@ -1,7 +1,7 @@
 use downcast_rs::{impl_downcast, Downcast};
 
 use crate::App;
-use std::any::Any;
+use std::any::{Any, TypeId};
 
 /// A collection of Bevy app logic and configuration.
 ///
@@ -21,6 +21,12 @@ use std::any::Any;
 /// * once the app started, it will wait for all registered [`Plugin::ready`] to return `true`
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
+///
+/// ## Dependencies
+///
+/// A plugin can declare the plugins it needs or conflicts with in
+/// [`dependencies()`](Self::dependencies). They are checked when the plugin is added, before it
+/// is built, so that a missing plugin is reported clearly instead of failing later on.
 pub trait Plugin: Downcast + Any + Send + Sync {
     /// Configures the [`App`] to which this plugin is added.
     fn build(&self, app: &mut App);
@@ -56,6 +62,120 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// The plugins this plugin depends on or conflicts with. See [`PluginDependency`].
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, PluginDependency};
+    /// # #[derive(Default)]
+    /// # struct TimePlugin;
+    /// # impl Plugin for TimePlugin {
+    /// #     fn build(&self, _app: &mut App) {}
+    /// # }
+    /// struct NetworkPlugin;
+    ///
+    /// impl Plugin for NetworkPlugin {
+    ///     fn build(&self, _app: &mut App) {}
+    ///
+    ///     fn dependencies(&self) -> Vec<PluginDependency> {
+    ///         vec![PluginDependency::required_or_default::<TimePlugin>()]
+    ///     }
+    /// }
+    ///
+    /// // `TimePlugin` is added before `NetworkPlugin`, as it wasn't added yet.
+    /// let mut app = App::new();
+    /// app.add_plugins(NetworkPlugin);
+    /// assert!(app.is_plugin_added::<TimePlugin>());
+    /// ```
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        Vec::new()
+    }
+}
+
+/// How a [`Plugin`] relates to another plugin, see [`PluginDependency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluginDependencyKind {
+    /// The other plugin must be added before this one.
+    Required,
+    /// If the other plugin is used, it must be added before this one.
+    Optional,
+    /// The other plugin can't be added to the same app as this one.
+    Conflict,
+}
+
+/// A dependency of a [`Plugin`] on another plugin, returned by [`Plugin::dependencies`].
+///
+/// Dependencies are checked by [`App::add_plugins`] before the plugin is built:
+/// * a [required](Self::required) plugin must already be added, otherwise adding the plugin
+///   panics. With [`required_or_default`](Self::required_or_default), the missing plugin is
+///   added first from its [`Default`] value instead.
+/// * an [optional](Self::optional) plugin doesn't need to be added, but adding it after the
+///   plugin panics, since the plugin was built without it.
+/// * a [conflicting](Self::conflicts) plugin can't be added before or after the plugin.
+///
+/// Plugins are identified by their type, so a dependency on a generic plugin only matches the
+/// same type parameters.
+#[derive(Clone)]
+pub struct PluginDependency {
+    kind: PluginDependencyKind,
+    type_id: TypeId,
+    name: &'static str,
+    default: Option<fn() -> Box<dyn Plugin>>,
+}
+
+impl PluginDependency {
+    fn new<T: Plugin>(kind: PluginDependencyKind) -> Self {
+        Self {
+            kind,
+            type_id: TypeId::of::<T>(),
+            name: std::any::type_name::<T>(),
+            default: None,
+        }
+    }
+
+    /// The plugin `T` must be added before this one.
+    pub fn required<T: Plugin>() -> Self {
+        Self::new::<T>(PluginDependencyKind::Required)
+    }
+
+    /// The plugin `T` must be added before this one. If it's missing, its default value is
+    /// added to the app first.
+    pub fn required_or_default<T: Plugin + Default>() -> Self {
+        Self {
+            default: Some(|| Box::<T>::default()),
+            ..Self::new::<T>(PluginDependencyKind::Required)
+        }
+    }
+
+    /// If the plugin `T` is used, it must be added before this one.
+    pub fn optional<T: Plugin>() -> Self {
+        Self::new::<T>(PluginDependencyKind::Optional)
+    }
+
+    /// The plugin `T` can't be added to the same app as this one.
+    pub fn conflicts<T: Plugin>() -> Self {
+        Self::new::<T>(PluginDependencyKind::Conflict)
+    }
+
+    /// Returns how the plugin relates to the other plugin.
+    pub fn kind(&self) -> PluginDependencyKind {
+        self.kind
+    }
+
+    /// Returns the [`TypeId`] of the other plugin.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the type name of the other plugin.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the default value of the other plugin, if it should be added when missing.
+    pub(crate) fn default_plugin(&self) -> Option<Box<dyn Plugin>> {
+        self.default.map(|default| default())
+    }
 }
 
 impl_downcast!(Plugin);
@@ -91,12 +211,12 @@ mod sealed {
     impl<P: Plugin> Plugins<PluginMarker> for P {
         #[track_caller]
         fn add_to_app(self, app: &mut App) {
-            if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                app.add_boxed_plugin(Box::new(self))
-            {
-                panic!(
+            match app.add_boxed_plugin(Box::new(self)) {
+                Err(AppError::DuplicatePlugin { plugin_name }) => panic!(
                     "Error adding plugin {plugin_name}: : plugin was already added in application"
-                )
+                ),
+                Err(error) => panic!("Error adding plugin: {error}"),
+                Ok(_) => {}
             }
         }
     }
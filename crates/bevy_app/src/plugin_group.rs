@@ -1,4 +1,4 @@
-use crate::{App, AppError, Plugin};
+use crate::{App, AppError, Plugin, PluginDependencyKind};
 use bevy_utils::{tracing::debug, tracing::warn, HashMap};
 use std::any::TypeId;
 
@@ -171,26 +171,61 @@ impl PluginGroupBuilder {
     ///
     /// # Panics
     ///
-    /// Panics if one of the plugin in the group was already added to the application.
+    /// Panics if one of the plugin in the group was already added to the application, or if the
+    /// [dependencies](Plugin::dependencies) of a plugin aren't met. In particular, a plugin
+    /// can't be placed before another enabled plugin of the group it depends on.
     #[track_caller]
     pub fn finish(mut self, app: &mut App) {
+        self.check_dependency_order();
         for ty in &self.order {
             if let Some(entry) = self.plugins.remove(ty) {
                 if entry.enabled {
                     debug!("added plugin: {}", entry.plugin.name());
-                    if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                        app.add_boxed_plugin(entry.plugin)
-                    {
-                        panic!(
+                    match app.add_boxed_plugin(entry.plugin) {
+                        Err(AppError::DuplicatePlugin { plugin_name }) => panic!(
                             "Error adding plugin {} in group {}: plugin was already added in application",
                             plugin_name,
                             self.group_name
-                        );
+                        ),
+                        Err(error) => {
+                            panic!("Error adding plugin in group {}: {error}", self.group_name)
+                        }
+                        Ok(_) => {}
                     }
                 }
             }
         }
     }
+
+    /// Panics if an enabled plugin depends on an enabled plugin placed after it in the group,
+    /// as it would be reported missing when building the group.
+    #[track_caller]
+    fn check_dependency_order(&self) {
+        let enabled_position = |ty: &TypeId| {
+            self.plugins
+                .get(ty)
+                .filter(|entry| entry.enabled)
+                .and_then(|_| self.order.iter().position(|other| other == ty))
+        };
+        for (position, ty) in self.order.iter().enumerate() {
+            let Some(entry) = self.plugins.get(ty).filter(|entry| entry.enabled) else {
+                continue;
+            };
+            for dependency in entry.plugin.dependencies() {
+                if dependency.kind() == PluginDependencyKind::Conflict {
+                    continue;
+                }
+                if enabled_position(&dependency.type_id()).is_some_and(|other| other > position) {
+                    panic!(
+                        "Error adding plugin {} in group {}: it depends on {}, which must be placed before it",
+                        entry.plugin.name(),
+                        self.group_name,
+                        dependency.name()
+                    );
+                }
+            }
+        }
+    }
 }
 
 /// A plugin group which doesn't do anything. Useful for examples:
@@ -214,8 +249,9 @@ impl PluginGroup for NoopPluginGroup {
 #[cfg(test)]
 mod tests {
     use super::PluginGroupBuilder;
-    use crate::{App, NoopPluginGroup, Plugin};
+    use crate::{App, NoopPluginGroup, Plugin, PluginDependency};
 
+    #[derive(Default)]
     struct PluginA;
     impl Plugin for PluginA {
         fn build(&self, _: &mut App) {}
@@ -335,4 +371,41 @@ mod tests {
             ]
         );
     }
+
+    #[derive(Default)]
+    struct DependentPlugin;
+    impl Plugin for DependentPlugin {
+        fn build(&self, _: &mut App) {}
+
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::required_or_default::<PluginA>()]
+        }
+    }
+
+    #[test]
+    fn dependencies_in_order() {
+        let mut app = App::new();
+        PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(DependentPlugin)
+            .finish(&mut app);
+        assert!(app.is_plugin_added::<DependentPlugin>());
+
+        let mut app = App::new();
+        PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(DependentPlugin)
+            .add(PluginA)
+            .disable::<PluginA>()
+            .finish(&mut app);
+        assert!(app.is_plugin_added::<PluginA>());
+    }
+
+    #[test]
+    #[should_panic(expected = "which must be placed before it")]
+    fn dependency_placed_after() {
+        PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(DependentPlugin)
+            .add(PluginA)
+            .finish(&mut App::new());
+    }
 }
@@ -3,7 +3,6 @@ use crate::{
     plugin::Plugin,
     PluginsState,
 };
-use bevy_ecs::event::{Events, ManualEventReader};
 use bevy_utils::{Duration, Instant};
 
 #[cfg(target_arch = "wasm32")]
@@ -82,24 +81,21 @@ impl Plugin for ScheduleRunnerPlugin {
                 app.cleanup();
             }
 
-            let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
             match run_mode {
-                RunMode::Once => app.update(),
+                RunMode::Once => {
+                    app.update();
+                    app.should_exit().unwrap_or_default()
+                }
                 RunMode::Loop { wait } => {
-                    let mut tick = move |app: &mut App,
-                                         wait: Option<Duration>|
+                    let tick = move |app: &mut App,
+                                     wait: Option<Duration>|
                           -> Result<Option<Duration>, AppExit> {
                         let start_time = Instant::now();
 
                         app.update();
 
-                        if let Some(app_exit_events) =
-                            app.world.get_resource_mut::<Events<AppExit>>()
-                        {
-                            if let Some(exit) = app_exit_event_reader.read(&app_exit_events).last()
-                            {
-                                return Err(exit.clone());
-                            }
+                        if let Some(exit) = app.should_exit() {
+                            return Err(exit);
                         }
 
                         let end_time = Instant::now();
@@ -116,9 +112,11 @@ impl Plugin for ScheduleRunnerPlugin {
 
                     #[cfg(not(target_arch = "wasm32"))]
                     {
-                        while let Ok(delay) = tick(&mut app, wait) {
-                            if let Some(delay) = delay {
-                                std::thread::sleep(delay);
+                        loop {
+                            match tick(&mut app, wait) {
+                                Ok(Some(delay)) => std::thread::sleep(delay),
+                                Ok(None) => {}
+                                Err(exit) => return exit,
                             }
                         }
                     }
@@ -152,7 +150,11 @@ impl Plugin for ScheduleRunnerPlugin {
                         };
                         *g.borrow_mut() = Some(Closure::wrap(Box::new(c) as Box<dyn FnMut()>));
                         set_timeout(g.borrow().as_ref().unwrap(), asap);
-                    };
+
+                        // The browser drives the loop from here on, so there is no exit status
+                        // to report back to the caller.
+                        AppExit::Success
+                    }
                 }
             }
         });
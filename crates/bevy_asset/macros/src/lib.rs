@@ -1,13 +1,17 @@
 use bevy_macro_utils::BevyManifest;
 use proc_macro::{Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DeriveInput, Path};
+use syn::{
+    parenthesized, parse_macro_input, punctuated::Punctuated, Data, DeriveInput, LitStr, Path,
+    Token,
+};
 
 pub(crate) fn bevy_asset_path() -> Path {
     BevyManifest::default().get_path("bevy_asset")
 }
 
 const DEPENDENCY_ATTRIBUTE: &str = "dependency";
+const ASSET_ATTRIBUTE: &str = "asset";
 
 #[proc_macro_derive(Asset, attributes(dependency))]
 pub fn derive_asset(input: TokenStream) -> TokenStream {
@@ -37,6 +41,123 @@ pub fn derive_asset_dependency_visitor(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_asset_path: Path = bevy_asset_path();
+    match derive_asset_collection_internal(&ast, &bevy_asset_path) {
+        Ok(asset_collection) => TokenStream::from(asset_collection),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn derive_asset_collection_internal(
+    ast: &DeriveInput,
+    bevy_asset_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let Data::Struct(data_struct) = &ast.data else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs",
+        ));
+    };
+    let syn::Fields::Named(fields) = &data_struct.fields else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs with named fields",
+        ));
+    };
+
+    let mut field_loaders = Vec::new();
+    let mut field_visitors = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().unwrap();
+        let Some(attribute) = field
+            .attrs
+            .iter()
+            .find(|a| a.path().is_ident(ASSET_ATTRIBUTE))
+        else {
+            field_loaders.push(quote!(#ident: ::core::default::Default::default()));
+            continue;
+        };
+
+        let mut entry = quote!(::core::option::Option::None);
+        let mut extension = quote!(::core::option::Option::None);
+        if !matches!(attribute.meta, syn::Meta::Path(_)) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    entry = quote!(::core::option::Option::Some(
+                        #bevy_asset_path::AssetCollectionEntry::Path(#path.to_string())
+                    ));
+                    Ok(())
+                } else if meta.path.is_ident("paths") {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    let paths = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                    let paths = paths.iter();
+                    entry = quote!(::core::option::Option::Some(
+                        #bevy_asset_path::AssetCollectionEntry::Paths(vec![#(#paths.to_string()),*])
+                    ));
+                    Ok(())
+                } else if meta.path.is_ident("extension") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    extension = quote!(::core::option::Option::Some(#value));
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `path`, `paths` or `extension`"))
+                }
+            })?;
+        }
+
+        let ty = &field.ty;
+        let name = ident.to_string();
+        field_loaders.push(quote! {
+            #ident: <#ty as #bevy_asset_path::AssetCollectionField>::load_field(
+                context,
+                #name,
+                context.entry(#name, #entry),
+                #extension,
+            )
+            .await?
+        });
+        field_visitors.push(
+            quote!(#bevy_asset_path::VisitAssetDependencies::visit_dependencies(&self.#ident, visit);),
+        );
+    }
+
+    let visit = if field_visitors.is_empty() {
+        quote! { _visit }
+    } else {
+        quote! { visit }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #bevy_asset_path::Asset for #struct_name #type_generics #where_clause { }
+
+        impl #impl_generics #bevy_asset_path::VisitAssetDependencies for #struct_name #type_generics #where_clause {
+            fn visit_dependencies(&self, #visit: &mut impl FnMut(#bevy_asset_path::UntypedAssetId)) {
+                #(#field_visitors)*
+            }
+        }
+
+        impl #impl_generics #bevy_asset_path::AssetCollection for #struct_name #type_generics #where_clause {
+            fn load_collection<'a>(
+                context: &'a #bevy_asset_path::AssetCollectionContext,
+            ) -> #bevy_asset_path::BoxedFuture<'a, ::core::result::Result<Self, #bevy_asset_path::AssetCollectionError>> {
+                ::std::boxed::Box::pin(async move {
+                    ::core::result::Result::Ok(Self {
+                        #(#field_loaders,)*
+                    })
+                })
+            }
+        }
+    })
+}
+
 fn derive_dependency_visitor_internal(
     ast: &DeriveInput,
     bevy_asset_path: &Path,
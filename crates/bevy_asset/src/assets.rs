@@ -276,6 +276,8 @@ pub struct Assets<A: Asset> {
     hash_map: HashMap<Uuid, A>,
     handle_provider: AssetHandleProvider,
     queued_events: Vec<AssetEvent<A>>,
+    /// If set, reloaded assets that are equal to their current value are not replaced. See [`Assets::set_partial_eq`].
+    partial_eq: Option<fn(&A, &A) -> bool>,
 }
 
 impl<A: Asset> Default for Assets<A> {
@@ -288,6 +290,7 @@ impl<A: Asset> Default for Assets<A> {
             handle_provider,
             hash_map: Default::default(),
             queued_events: Default::default(),
+            partial_eq: None,
         }
     }
 }
@@ -304,6 +307,28 @@ impl<A: Asset> Assets<A> {
         self.handle_provider.reserve_handle().typed::<A>()
     }
 
+    /// Uses [`PartialEq`] to detect reloaded assets that did not actually change. When an asset is reloaded by the
+    /// [`AssetServer`] with a value equal to its current one, the current value is kept and no [`AssetEvent::Modified`]
+    /// is sent for it. This is especially useful for labeled assets, which are all reloaded whenever their source file changes.
+    ///
+    /// [`AssetServer`]: crate::AssetServer
+    pub fn set_partial_eq(&mut self)
+    where
+        A: PartialEq,
+    {
+        self.partial_eq = Some(<A as PartialEq>::eq);
+    }
+
+    /// Inserts the loaded `asset`, unless it is equal to the current value for `id` (see [`Assets::set_partial_eq`]).
+    pub(crate) fn insert_loaded(&mut self, id: AssetId<A>, asset: A) {
+        if let (Some(partial_eq), Some(current)) = (self.partial_eq, self.get(id)) {
+            if partial_eq(current, &asset) {
+                return;
+            }
+        }
+        self.insert(id, asset);
+    }
+
     /// Inserts the given `asset`, identified by the given `id`. If an asset already exists for `id`, it will be replaced.
     pub fn insert(&mut self, id: impl Into<AssetId<A>>, asset: A) {
         let id: AssetId<A> = id.into();
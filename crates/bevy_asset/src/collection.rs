@@ -0,0 +1,381 @@
+use crate::{
+    io::{
+        AssetReader, AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError,
+        Reader,
+    },
+    Asset, AssetLoader, AssetPath, AssetServer, AssetServerMode, Handle, LoadContext,
+};
+use bevy_utils::{BoxedFuture, HashMap};
+use futures_lite::{AsyncReadExt, StreamExt};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// A typed collection of assets that is loaded as a single [`Asset`]. The collection's load state only reaches
+/// [`RecursiveDependencyLoadState::Loaded`] once every asset in it (and their dependencies) finished loading.
+///
+/// This is typically derived. Each field marked with `#[asset(..)]` must implement [`AssetCollectionField`], and all other
+/// fields are initialized with [`Default`]:
+///
+/// ```
+/// # use bevy_asset::{AssetCollection, Handle};
+/// # use bevy_reflect::TypePath;
+/// # use bevy_utils::HashMap;
+/// # #[derive(bevy_asset::Asset, TypePath)]
+/// # struct Image;
+/// #[derive(AssetCollection, TypePath)]
+/// struct LevelAssets {
+///     // A single asset.
+///     #[asset(path = "background.png")]
+///     background: Handle<Image>,
+///     // A list of assets.
+///     #[asset(paths("enemies/slime.png", "enemies/bat.png"))]
+///     enemies: Vec<Handle<Image>>,
+///     // Every asset with the given extension in a folder, keyed by their path relative to the folder.
+///     #[asset(path = "tiles", extension = "png")]
+///     tiles: HashMap<String, Handle<Image>>,
+///     // No default path. This must be provided by an `AssetCollectionManifest`.
+///     #[asset]
+///     music: Handle<Image>,
+///     // An optional asset, which is `None` unless an `AssetCollectionManifest` provides its path.
+///     #[asset]
+///     bonus: Option<Handle<Image>>,
+/// }
+/// ```
+///
+/// The derive also implements [`Asset`] and [`VisitAssetDependencies`] (visiting every `#[asset(..)]` field) for the type.
+///
+/// Collections can be loaded directly with [`AssetServer::load_collection`], or from a RON [`AssetCollectionManifest`] file
+/// (which can override the path of each field) using an [`AssetCollectionLoader`].
+///
+/// [`RecursiveDependencyLoadState::Loaded`]: crate::RecursiveDependencyLoadState::Loaded
+/// [`VisitAssetDependencies`]: crate::VisitAssetDependencies
+pub trait AssetCollection: Asset + Sized {
+    /// Loads every asset in the collection using the given `context`.
+    fn load_collection<'a>(
+        context: &'a AssetCollectionContext,
+    ) -> BoxedFuture<'a, Result<Self, AssetCollectionError>>;
+}
+
+/// A field of an [`AssetCollection`] that can be loaded from an [`AssetCollectionEntry`].
+pub trait AssetCollectionField: Sized + Send {
+    /// Loads this field using the given `entry`. `field` is the name of the field, and `extension` is the
+    /// extension assets must have to be included (if any).
+    fn load_field<'a>(
+        context: &'a AssetCollectionContext,
+        field: &'static str,
+        entry: Option<AssetCollectionEntry>,
+        extension: Option<&'static str>,
+    ) -> BoxedFuture<'a, Result<Self, AssetCollectionError>>;
+}
+
+/// The path(s) of a single field in an [`AssetCollection`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum AssetCollectionEntry {
+    /// The path of an asset, or of a folder for fields that collect a folder.
+    Path(String),
+    /// A list of asset paths.
+    Paths(Vec<String>),
+}
+
+/// A RON manifest that defines the paths of the fields of an [`AssetCollection`], overriding any paths provided in
+/// `#[asset(..)]` attributes. Fields missing from the manifest use their default paths.
+///
+/// ```ron
+/// (
+///     assets: {
+///         "background": "levels/1/background.png",
+///         "enemies": ["enemies/slime.png", "enemies/ghost.png"],
+///         "tiles": "levels/1/tiles",
+///     },
+/// )
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct AssetCollectionManifest {
+    /// The path(s) of each field, keyed by field name.
+    pub assets: HashMap<String, AssetCollectionEntry>,
+}
+
+/// Provides access to the [`AssetServer`] (and the optional [`AssetCollectionManifest`]) while loading an [`AssetCollection`].
+pub struct AssetCollectionContext<'a, 'ctx> {
+    server: AssetServer,
+    /// Set when the collection is loaded by an [`AssetCollectionLoader`], which tracks the collection's dependencies.
+    load_context: Option<Mutex<&'a mut LoadContext<'ctx>>>,
+    manifest: Option<AssetCollectionManifest>,
+}
+
+impl<'a, 'ctx> AssetCollectionContext<'a, 'ctx> {
+    pub(crate) fn new(server: &AssetServer) -> Self {
+        Self {
+            server: server.clone(),
+            load_context: None,
+            manifest: None,
+        }
+    }
+
+    pub(crate) fn from_load_context(
+        load_context: &'a mut LoadContext<'ctx>,
+        manifest: AssetCollectionManifest,
+    ) -> Self {
+        Self {
+            server: load_context.asset_server.clone(),
+            load_context: Some(Mutex::new(load_context)),
+            manifest: Some(manifest),
+        }
+    }
+
+    /// Returns the manifest entry for `field` if it exists. Otherwise returns `default`.
+    pub fn entry(
+        &self,
+        field: &str,
+        default: Option<AssetCollectionEntry>,
+    ) -> Option<AssetCollectionEntry> {
+        self.manifest
+            .as_ref()
+            .and_then(|manifest| manifest.assets.get(field).cloned())
+            .or(default)
+    }
+
+    /// Retrieves a handle for the asset at the given path. See [`LoadContext::load`].
+    pub fn load<'b, A: Asset>(&self, path: impl Into<AssetPath<'b>>) -> Handle<A> {
+        match &self.load_context {
+            Some(load_context) => load_context.lock().load(path),
+            None => self.server.load(path),
+        }
+    }
+
+    /// Recursively lists the assets in the folder at the given `path`. If `extension` is set, only assets with that
+    /// extension are returned. Like [`AssetServer::load_folder`], `.meta` files and files without a registered
+    /// [`AssetLoader`] are skipped.
+    pub async fn read_folder<'b>(
+        &self,
+        path: impl Into<AssetPath<'b>>,
+        extension: Option<&str>,
+    ) -> Result<Vec<AssetPath<'static>>, AssetCollectionError> {
+        fn read_folder<'a>(
+            path: &'a Path,
+            reader: &'a dyn AssetReader,
+            paths: &'a mut Vec<PathBuf>,
+        ) -> BoxedFuture<'a, Result<(), AssetReaderError>> {
+            Box::pin(async move {
+                let mut path_stream = reader.read_directory(path).await?;
+                while let Some(child_path) = path_stream.next().await {
+                    if reader.is_directory(&child_path).await? {
+                        read_folder(&child_path, reader, paths).await?;
+                    } else {
+                        paths.push(child_path);
+                    }
+                }
+                Ok(())
+            })
+        }
+
+        let path = path.into().into_owned();
+        let source = self.server.get_source(path.source())?;
+        let reader = match self.server.mode() {
+            AssetServerMode::Unprocessed => source.reader(),
+            AssetServerMode::Processed => source.processed_reader()?,
+        };
+        let mut paths = Vec::new();
+        read_folder(path.path(), reader, &mut paths).await?;
+        paths.sort();
+        let mut asset_paths = Vec::new();
+        for child_path in paths {
+            let Some(child_path_str) = child_path.to_str() else {
+                continue;
+            };
+            if child_path_str.ends_with(".meta")
+                || extension
+                    .is_some_and(|extension| !child_path_str.ends_with(&format!(".{extension}")))
+            {
+                continue;
+            }
+            let asset_path = AssetPath::from(child_path).with_source(path.source().clone_owned());
+            if self.server.get_path_asset_loader(&asset_path).await.is_ok() {
+                asset_paths.push(asset_path);
+            }
+        }
+        Ok(asset_paths)
+    }
+}
+
+impl<A: Asset> AssetCollectionField for Handle<A> {
+    fn load_field<'a>(
+        context: &'a AssetCollectionContext,
+        field: &'static str,
+        entry: Option<AssetCollectionEntry>,
+        _extension: Option<&'static str>,
+    ) -> BoxedFuture<'a, Result<Self, AssetCollectionError>> {
+        Box::pin(async move {
+            match entry {
+                Some(AssetCollectionEntry::Path(path)) => Ok(context.load(path)),
+                Some(AssetCollectionEntry::Paths(_)) => Err(AssetCollectionError::InvalidEntry {
+                    field,
+                    expected: "a single path",
+                }),
+                None => Err(AssetCollectionError::MissingPath(field)),
+            }
+        })
+    }
+}
+
+impl<A: Asset> AssetCollectionField for Vec<Handle<A>> {
+    fn load_field<'a>(
+        context: &'a AssetCollectionContext,
+        field: &'static str,
+        entry: Option<AssetCollectionEntry>,
+        extension: Option<&'static str>,
+    ) -> BoxedFuture<'a, Result<Self, AssetCollectionError>> {
+        Box::pin(async move {
+            match entry {
+                Some(AssetCollectionEntry::Path(folder)) => Ok(context
+                    .read_folder(folder, extension)
+                    .await?
+                    .into_iter()
+                    .map(|path| context.load(path))
+                    .collect()),
+                Some(AssetCollectionEntry::Paths(paths)) => {
+                    Ok(paths.into_iter().map(|path| context.load(path)).collect())
+                }
+                None => Err(AssetCollectionError::MissingPath(field)),
+            }
+        })
+    }
+}
+
+impl<A: Asset> AssetCollectionField for HashMap<String, Handle<A>> {
+    fn load_field<'a>(
+        context: &'a AssetCollectionContext,
+        field: &'static str,
+        entry: Option<AssetCollectionEntry>,
+        extension: Option<&'static str>,
+    ) -> BoxedFuture<'a, Result<Self, AssetCollectionError>> {
+        Box::pin(async move {
+            match entry {
+                Some(AssetCollectionEntry::Path(folder)) => {
+                    let folder = AssetPath::parse(&folder).into_owned();
+                    Ok(context
+                        .read_folder(folder.clone(), extension)
+                        .await?
+                        .into_iter()
+                        .map(|path| {
+                            let key = path
+                                .path()
+                                .strip_prefix(folder.path())
+                                .unwrap_or(path.path())
+                                .to_string_lossy()
+                                .replace('\\', "/");
+                            (key, context.load(path))
+                        })
+                        .collect())
+                }
+                Some(AssetCollectionEntry::Paths(_)) => Err(AssetCollectionError::InvalidEntry {
+                    field,
+                    expected: "a folder path",
+                }),
+                None => Err(AssetCollectionError::MissingPath(field)),
+            }
+        })
+    }
+}
+
+/// An optional field, which is [`None`] if no path is provided for it.
+impl<T: AssetCollectionField> AssetCollectionField for Option<T> {
+    fn load_field<'a>(
+        context: &'a AssetCollectionContext,
+        field: &'static str,
+        entry: Option<AssetCollectionEntry>,
+        extension: Option<&'static str>,
+    ) -> BoxedFuture<'a, Result<Self, AssetCollectionError>> {
+        Box::pin(async move {
+            match entry {
+                Some(entry) => Ok(Some(
+                    T::load_field(context, field, Some(entry), extension).await?,
+                )),
+                None => Ok(None),
+            }
+        })
+    }
+}
+
+/// An error that occurs while loading an [`AssetCollection`].
+#[derive(Error, Debug)]
+pub enum AssetCollectionError {
+    #[error("no path was provided for the asset collection field `{0}`")]
+    MissingPath(&'static str),
+    #[error("the asset collection field `{field}` expects {expected}")]
+    InvalidEntry {
+        field: &'static str,
+        expected: &'static str,
+    },
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    #[error(transparent)]
+    MissingProcessedAssetReader(#[from] MissingProcessedAssetReaderError),
+    #[error(transparent)]
+    AssetReaderError(#[from] AssetReaderError),
+}
+
+/// Loads an [`AssetCollection`] of type `C` from an [`AssetCollectionManifest`] RON file.
+///
+/// Each collection type needs its own manifest extension, as loaders are selected by extension:
+///
+/// ```ignore
+/// app.init_asset_collection::<LevelAssets>()
+///     .register_asset_loader(AssetCollectionLoader::<LevelAssets>::new(&["level.ron"]));
+/// ```
+pub struct AssetCollectionLoader<C> {
+    extensions: Vec<&'static str>,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C> AssetCollectionLoader<C> {
+    /// Creates a new [`AssetCollectionLoader`] for manifest files with the given `extensions`.
+    pub fn new(extensions: &[&'static str]) -> Self {
+        Self {
+            extensions: extensions.to_vec(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// An error that occurs while loading an [`AssetCollectionManifest`].
+#[derive(Error, Debug)]
+pub enum AssetCollectionLoaderError {
+    #[error("Could not read the asset collection manifest: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse the asset collection manifest: {0}")]
+    RonSpannedError(#[from] ron::error::SpannedError),
+    #[error(transparent)]
+    AssetCollectionError(#[from] AssetCollectionError),
+}
+
+impl<C: AssetCollection> AssetLoader for AssetCollectionLoader<C> {
+    type Asset = C;
+    type Settings = ();
+    type Error = AssetCollectionLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let manifest: AssetCollectionManifest = ron::de::from_bytes(&bytes)?;
+            let context = AssetCollectionContext::from_load_context(load_context, manifest);
+            Ok(C::load_collection(&context).await?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &self.extensions
+    }
+}
@@ -8,6 +8,12 @@ pub enum AssetEvent<A: Asset> {
     /// Emitted whenever an [`Asset`] is added.
     Added { id: AssetId<A> },
     /// Emitted whenever an [`Asset`] value is modified.
+    ///
+    /// When a file is reloaded, this is only sent for the labeled assets it contains whose value changed if their type
+    /// is registered with [`AssetApp::register_asset_partial_eq`], which Bevy does for its built-in asset types such
+    /// as meshes, images, materials and animation clips. Other types send it for every labeled asset.
+    ///
+    /// [`AssetApp::register_asset_partial_eq`]: crate::AssetApp::register_asset_partial_eq
     Modified { id: AssetId<A> },
     /// Emitted whenever an [`Asset`] is removed.
     Removed { id: AssetId<A> },
@@ -6,12 +6,13 @@ pub mod saver;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        Asset, AssetApp, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer, Assets, Handle,
-        UntypedHandle,
+        Asset, AssetApp, AssetCollection, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer,
+        Assets, Handle, UntypedHandle,
     };
 }
 
 mod assets;
+mod collection;
 mod event;
 mod folder;
 mod handle;
@@ -22,7 +23,8 @@ mod reflect;
 mod server;
 
 pub use assets::*;
-pub use bevy_asset_macros::Asset;
+pub use bevy_asset_macros::{Asset, AssetCollection};
+pub use collection::*;
 pub use event::*;
 pub use folder::*;
 pub use futures_lite::{AsyncReadExt, AsyncWriteExt};
@@ -40,7 +42,9 @@ pub use ron;
 
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
-    processor::{AssetProcessor, Process},
+    meta::Settings,
+    processor::{AssetProcessor, Process, ProcessedAssetCache},
+    saver::AssetSaver,
 };
 use bevy_app::{App, First, MainScheduleOrder, Plugin, PostUpdate};
 use bevy_ecs::{
@@ -49,9 +53,10 @@ use bevy_ecs::{
     system::Resource,
     world::FromWorld,
 };
-use bevy_log::error;
+use bevy_log::{error, warn};
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath};
-use bevy_utils::HashSet;
+use bevy_utils::{HashMap, HashSet};
+use serde::de::DeserializeOwned;
 use std::{any::TypeId, sync::Arc};
 
 #[cfg(all(feature = "file_watcher", not(feature = "multi-threaded")))]
@@ -213,6 +218,7 @@ impl Plugin for AssetPlugin {
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
+            .add_event::<AssetSaveEvent>()
             .configure_sets(
                 UpdateAssets,
                 TrackAssets.after(handle_internal_asset_events),
@@ -267,6 +273,14 @@ impl<A: Asset> VisitAssetDependencies for Vec<Handle<A>> {
     }
 }
 
+impl<A: Asset> VisitAssetDependencies for HashMap<String, Handle<A>> {
+    fn visit_dependencies(&self, visit: &mut impl FnMut(UntypedAssetId)) {
+        for dependency in self.values() {
+            visit(dependency.id().untyped());
+        }
+    }
+}
+
 impl VisitAssetDependencies for Vec<UntypedHandle> {
     fn visit_dependencies(&self, visit: &mut impl FnMut(UntypedAssetId)) {
         for dependency in self {
@@ -279,6 +293,8 @@ impl VisitAssetDependencies for Vec<UntypedHandle> {
 pub trait AssetApp {
     /// Registers the given `loader` in the [`App`]'s [`AssetServer`].
     fn register_asset_loader<L: AssetLoader>(&mut self, loader: L) -> &mut Self;
+    /// Registers the given `saver` in the [`App`]'s [`AssetServer`], so it can be used with [`AssetServer::save`].
+    fn register_asset_saver<S: AssetSaver>(&mut self, saver: S) -> &mut Self;
     /// Registers the given `processor` in the [`App`]'s [`AssetProcessor`].
     fn register_asset_processor<P: Process>(&mut self, processor: P) -> &mut Self;
     /// Registers the given [`AssetSourceBuilder`] with the given `id`.
@@ -292,6 +308,34 @@ pub trait AssetApp {
     ) -> &mut Self;
     /// Sets the default asset processor for the given `extension`.
     fn set_default_asset_processor<P: Process>(&mut self, extension: &str) -> &mut Self;
+    /// Sets the [`ProcessedAssetCache`] used by the [`App`]'s [`AssetProcessor`] to share processed assets.
+    ///
+    /// This does nothing (and logs a warning) if the [`App`] does not have an [`AssetProcessor`].
+    fn set_processed_asset_cache(&mut self, cache: impl ProcessedAssetCache) -> &mut Self;
+    /// Registers a `migrate` function that upgrades the settings of the [`AssetLoader`] `L` stored in `.meta` files with
+    /// `from_version` (deserialized as `From`) to the settings of version `from_version + 1`.
+    /// See [`AssetLoader::SETTINGS_VERSION`].
+    fn register_asset_loader_settings_migration<L: AssetLoader, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        From: Settings + DeserializeOwned,
+        To: Settings;
+    /// Registers a `migrate` function that upgrades the settings of the [`Process`] `P` stored in `.meta` files with
+    /// `from_version` (deserialized as `From`) to the settings of version `from_version + 1`.
+    /// See [`Process::SETTINGS_VERSION`].
+    ///
+    /// This does nothing (and logs a warning) if the [`App`] does not have an [`AssetProcessor`].
+    fn register_asset_processor_settings_migration<P: Process, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        From: Settings + DeserializeOwned,
+        To: Settings;
     /// Initializes the given loader in the [`App`]'s [`AssetServer`].
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self;
     /// Initializes the given [`Asset`] in the [`App`] by:
@@ -302,6 +346,9 @@ pub trait AssetApp {
     /// mutable access to this resource this causes a conflict, but they rarely actually
     /// modify the same underlying asset.
     fn init_asset<A: Asset>(&mut self) -> &mut Self;
+    /// Enables [`Assets::set_partial_eq`] for the asset type `A`, so that reloading an asset without changing its value
+    /// does not replace it or send [`AssetEvent::Modified`]. `A` must be initialized with [`AssetApp::init_asset`] first.
+    fn register_asset_partial_eq<A: Asset + PartialEq>(&mut self) -> &mut Self;
     /// Registers the asset type `T` using `[App::register]`,
     /// and adds [`ReflectAsset`] type data to `T` and [`ReflectHandle`] type data to [`Handle<T>`] in the type registry.
     ///
@@ -320,6 +367,11 @@ impl AssetApp for App {
         self
     }
 
+    fn register_asset_saver<S: AssetSaver>(&mut self, saver: S) -> &mut Self {
+        self.world.resource::<AssetServer>().register_saver(saver);
+        self
+    }
+
     fn register_asset_processor<P: Process>(&mut self, processor: P) -> &mut Self {
         if let Some(asset_processor) = self.world.get_resource::<AssetProcessor>() {
             asset_processor.register_processor(processor);
@@ -354,6 +406,51 @@ impl AssetApp for App {
         self
     }
 
+    fn set_processed_asset_cache(&mut self, cache: impl ProcessedAssetCache) -> &mut Self {
+        if let Some(asset_processor) = self.world.get_resource::<AssetProcessor>() {
+            asset_processor.set_cache(cache);
+        } else {
+            warn!("Ignoring the processed asset cache because there is no `AssetProcessor`. Enable the `asset_processor` feature and set `AssetPlugin::mode` to `AssetMode::Processed` to use it.");
+        }
+        self
+    }
+
+    fn register_asset_loader_settings_migration<L: AssetLoader, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        From: Settings + DeserializeOwned,
+        To: Settings,
+    {
+        self.world
+            .resource::<AssetServer>()
+            .register_loader_settings_migration::<L, From, To>(from_version, migrate);
+        self
+    }
+
+    fn register_asset_processor_settings_migration<P: Process, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        From: Settings + DeserializeOwned,
+        To: Settings,
+    {
+        if let Some(asset_processor) = self.world.get_resource::<AssetProcessor>() {
+            asset_processor
+                .register_processor_settings_migration::<P, From, To>(from_version, migrate);
+        } else {
+            warn!(
+                "Ignoring the settings migration for the asset processor {} because there is no `AssetProcessor`.",
+                std::any::type_name::<P>()
+            );
+        }
+        self
+    }
+
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self {
         let loader = L::from_world(&mut self.world);
         self.register_asset_loader(loader)
@@ -383,6 +480,11 @@ impl AssetApp for App {
             .add_systems(UpdateAssets, Assets::<A>::track_assets.in_set(TrackAssets))
     }
 
+    fn register_asset_partial_eq<A: Asset + PartialEq>(&mut self) -> &mut Self {
+        self.world.resource_mut::<Assets<A>>().set_partial_eq();
+        self
+    }
+
     fn register_asset_reflect<A>(&mut self) -> &mut Self
     where
         A: Asset + Reflect + FromReflect + GetTypeRegistration,
@@ -429,13 +531,16 @@ mod tests {
         folder::LoadedFolder,
         handle::Handle,
         io::{
+            file::FileAssetWriter,
             gated::{GateOpener, GatedReader},
             memory::{Dir, MemoryAssetReader},
-            AssetSource, AssetSourceId, Reader,
+            AssetSource, AssetSourceEvent, AssetSourceId, AssetWatcher, Reader, Writer,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetPath, AssetPlugin, AssetServer, Assets,
-        DependencyLoadState, LoadState, RecursiveDependencyLoadState,
+        saver::{AssetSaver, SavedAsset},
+        Asset, AssetApp, AssetCollection, AssetCollectionLoader, AssetEvent, AssetId, AssetPath,
+        AssetPlugin, AssetSaveEvent, AssetServer, Assets, DependencyLoadState, LoadState,
+        RecursiveDependencyLoadState,
     };
     use bevy_app::{App, Update};
     use bevy_core::TaskPoolPlugin;
@@ -446,13 +551,13 @@ mod tests {
     };
     use bevy_log::LogPlugin;
     use bevy_reflect::TypePath;
-    use bevy_utils::BoxedFuture;
-    use futures_lite::AsyncReadExt;
+    use bevy_utils::{BoxedFuture, HashMap};
+    use futures_lite::{AsyncReadExt, AsyncWriteExt};
     use serde::{Deserialize, Serialize};
     use std::path::Path;
     use thiserror::Error;
 
-    #[derive(Asset, TypePath, Debug)]
+    #[derive(Asset, TypePath, Debug, Clone)]
     pub struct CoolText {
         text: String,
         embedded: String,
@@ -462,7 +567,7 @@ mod tests {
         sub_texts: Vec<Handle<SubText>>,
     }
 
-    #[derive(Asset, TypePath, Debug)]
+    #[derive(Asset, TypePath, Debug, PartialEq)]
     pub struct SubText {
         text: String,
     }
@@ -896,6 +1001,156 @@ mod tests {
         assert_eq!(events.0, expected_events);
     }
 
+    #[test]
+    fn reload_skips_unchanged_labeled_assets() {
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = |sub_texts: &str| {
+            format!(
+                r#"(
+    text: "a",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [{sub_texts}],
+)"#
+            )
+        };
+        dir.insert_asset_text(Path::new(a_path), &a_ron(r#""hello", "world""#));
+
+        #[derive(Resource, Default)]
+        struct SubTextEvents(Vec<AssetEvent<SubText>>);
+
+        let (mut app, gate_opener) = test_app(dir.clone());
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_partial_eq::<SubText>()
+            .init_resource::<SubTextEvents>()
+            .register_asset_loader(CoolTextLoader)
+            .add_systems(
+                Update,
+                |mut reader: EventReader<AssetEvent<SubText>>,
+                 mut events: ResMut<SubTextEvents>| {
+                    events.0.extend(reader.read().cloned());
+                },
+            );
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load(a_path);
+        gate_opener.open(a_path);
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, handle.id())?;
+            Some(())
+        });
+        let sub_texts = get::<CoolText>(&app.world, handle.id())
+            .unwrap()
+            .sub_texts
+            .clone();
+        let hello_id = sub_texts[0].id();
+        app.world.resource_mut::<SubTextEvents>().0.clear();
+
+        // "hello" stays the same, "world" is replaced by "there"
+        dir.insert_asset_text(Path::new(a_path), &a_ron(r#""hello", "there""#));
+        asset_server.reload(a_path);
+        gate_opener.open(a_path);
+        run_app_until(&mut app, |world| {
+            let a_text = get::<CoolText>(world, handle.id())?;
+            (a_text.sub_texts.len() == 2 && a_text.sub_texts[1] != sub_texts[1]).then_some(())
+        });
+        app.update();
+
+        let a_text = get::<CoolText>(&app.world, handle.id()).unwrap();
+        assert_eq!(
+            a_text.sub_texts[0].id(),
+            hello_id,
+            "labeled asset ids are preserved across reloads"
+        );
+        let there_id = a_text.sub_texts[1].id();
+        let events = &app.world.resource::<SubTextEvents>().0;
+        assert!(events.iter().any(|event| event.is_added(there_id)));
+        assert!(
+            !events.iter().any(|event| event.is_modified(hello_id)),
+            "unchanged labeled assets should not be modified"
+        );
+    }
+
+    #[test]
+    fn reload_dependants_of_labeled_assets() {
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = |text: &str| {
+            format!(
+                r#"(
+    text: "{text}",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: ["hello"],
+)"#
+            )
+        };
+        let b_path = "b.cool.ron";
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: [],
+    embedded_dependencies: ["a.cool.ron#hello"],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new(a_path), &a_ron("a"));
+        dir.insert_asset_text(Path::new(b_path), b_ron);
+
+        struct TestWatcher;
+        impl AssetWatcher for TestWatcher {}
+
+        let mut app = App::new();
+        let (gated_memory_reader, gate_opener) =
+            GatedReader::new(MemoryAssetReader { root: dir.clone() });
+        let (event_sender, event_receiver) = crossbeam_channel::unbounded();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(gated_memory_reader.clone()))
+                .with_watcher(move |sender| {
+                    event_sender.send(sender).unwrap();
+                    Some(Box::new(TestWatcher))
+                }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin {
+                watch_for_changes_override: Some(true),
+                ..Default::default()
+            },
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+        let source_events = event_receiver.try_recv().unwrap();
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let b: Handle<CoolText> = asset_server.load(b_path);
+        gate_opener.open(b_path);
+        gate_opener.open(a_path);
+        run_app_until(&mut app, |world| {
+            let b_text = get::<CoolText>(world, b.id())?;
+            assert_eq!(b_text.embedded, "a");
+            Some(())
+        });
+
+        // b depends on a labeled asset in a.cool.ron, so changing that file must reload b
+        dir.insert_asset_text(Path::new(a_path), &a_ron("changed"));
+        source_events
+            .send(AssetSourceEvent::ModifiedAsset(a_path.into()))
+            .unwrap();
+        gate_opener.open(b_path);
+        // a.cool.ron is read by both its own reload and b's loader
+        gate_opener.open(a_path);
+        gate_opener.open(a_path);
+        run_app_until(&mut app, |world| {
+            let b_text = get::<CoolText>(world, b.id())?;
+            (b_text.embedded == "changed").then_some(())
+        });
+    }
+
     #[test]
     fn failure_load_states() {
         let dir = Dir::default();
@@ -1210,6 +1465,234 @@ mod tests {
         });
     }
 
+    #[derive(AssetCollection, TypePath)]
+    struct CoolTextCollection {
+        #[asset(path = "a.cool.ron")]
+        single: Handle<CoolText>,
+        #[asset(paths("a.cool.ron", "b.cool.ron"))]
+        list: Vec<Handle<CoolText>>,
+        #[asset(path = "folder", extension = "cool.ron")]
+        folder: HashMap<String, Handle<CoolText>>,
+        #[asset]
+        from_manifest: Option<Handle<CoolText>>,
+        not_loaded: Vec<Handle<CoolText>>,
+    }
+
+    #[test]
+    fn load_asset_collection() {
+        let dir = Dir::default();
+        let cool_ron = |text: &str| {
+            format!(
+                "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+            )
+        };
+        dir.insert_asset_text(Path::new("a.cool.ron"), &cool_ron("a"));
+        dir.insert_asset_text(Path::new("b.cool.ron"), &cool_ron("b"));
+        dir.insert_asset_text(Path::new("folder/x.cool.ron"), &cool_ron("x"));
+        dir.insert_asset_text(Path::new("folder/nested/y.cool.ron"), &cool_ron("y"));
+        dir.insert_asset_text(Path::new("folder/ignored.txt"), "ignored");
+        dir.insert_asset_text(
+            Path::new("folder/x.cool.ron.meta"),
+            &format!(
+                r#"(meta_format_version: "1.0", asset: Load(loader: "{}", settings: ()))"#,
+                std::any::type_name::<CoolTextLoader>()
+            ),
+        );
+        dir.insert_asset_text(
+            Path::new("level.collection.ron"),
+            r#"(assets: { "list": ["b.cool.ron"], "from_manifest": "folder/x.cool.ron" })"#,
+        );
+        // `list` has no extension filter, so this includes every loadable asset in the folder
+        dir.insert_asset_text(
+            Path::new("unfiltered.collection.ron"),
+            r#"(assets: { "list": "folder" })"#,
+        );
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .init_asset::<CoolTextCollection>()
+        .register_asset_loader(CoolTextLoader)
+        .register_asset_loader(AssetCollectionLoader::<CoolTextCollection>::new(&[
+            "collection.ron",
+        ]));
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let from_paths = asset_server.load_collection::<CoolTextCollection>();
+        let from_manifest: Handle<CoolTextCollection> = asset_server.load("level.collection.ron");
+        let unfiltered: Handle<CoolTextCollection> = asset_server.load("unfiltered.collection.ron");
+
+        run_app_until(&mut app, |_| {
+            (asset_server.is_loaded_with_dependencies(&from_paths)
+                && asset_server.is_loaded_with_dependencies(&from_manifest)
+                && asset_server.is_loaded_with_dependencies(&unfiltered))
+            .then_some(())
+        });
+
+        let texts = app.world.resource::<Assets<CoolText>>();
+        let collections = app.world.resource::<Assets<CoolTextCollection>>();
+        let text = |handle: &Handle<CoolText>| texts.get(handle).unwrap().text.as_str();
+
+        let collection = collections.get(&from_paths).unwrap();
+        assert_eq!(text(&collection.single), "a");
+        assert_eq!(
+            collection.list.iter().map(text).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        let mut folder = collection
+            .folder
+            .iter()
+            .map(|(key, handle)| (key.as_str(), text(handle)))
+            .collect::<Vec<_>>();
+        folder.sort();
+        assert_eq!(folder, [("nested/y.cool.ron", "y"), ("x.cool.ron", "x")]);
+        assert!(collection.from_manifest.is_none());
+        assert!(collection.not_loaded.is_empty());
+
+        let collection = collections.get(&from_manifest).unwrap();
+        assert_eq!(text(&collection.single), "a");
+        assert_eq!(collection.list.iter().map(text).collect::<Vec<_>>(), ["b"]);
+        assert_eq!(collection.folder.len(), 2);
+        assert_eq!(text(collection.from_manifest.as_ref().unwrap()), "x");
+
+        // `.meta` files and files without a loader are skipped
+        let collection = collections.get(&unfiltered).unwrap();
+        assert_eq!(
+            collection.list.iter().map(text).collect::<Vec<_>>(),
+            ["y", "x"]
+        );
+    }
+
+    struct CoolTextSaver;
+
+    impl AssetSaver for CoolTextSaver {
+        type Asset = CoolText;
+        type Settings = ();
+        type OutputLoader = CoolTextLoader;
+        type Error = ron::Error;
+
+        fn save<'a>(
+            &'a self,
+            writer: &'a mut Writer,
+            asset: SavedAsset<'a, Self::Asset>,
+            _settings: &'a Self::Settings,
+        ) -> BoxedFuture<'a, Result<(), Self::Error>> {
+            Box::pin(async move {
+                let ron = CoolTextRon {
+                    text: asset.text.clone(),
+                    dependencies: Vec::new(),
+                    embedded_dependencies: Vec::new(),
+                    sub_texts: Vec::new(),
+                };
+                let bytes = ron::ser::to_string(&ron)?.into_bytes();
+                writer.write_all(&bytes).await?;
+                Ok(())
+            })
+        }
+    }
+
+    #[test]
+    fn save_as_binds_path() {
+        let root =
+            std::env::temp_dir().join(format!("bevy_asset_save_as_test_{}", std::process::id()));
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("labeled.cool.ron"),
+            r#"(
+    text: "labeled",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: ["sub"],
+)"#,
+        );
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() }))
+                .with_writer({
+                    let root = root.clone();
+                    move || Some(Box::new(FileAssetWriter::new(&root)))
+                }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader)
+        .register_asset_saver(CoolTextSaver);
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let handle = asset_server.add(CoolText {
+            text: "saved".to_string(),
+            embedded: String::new(),
+            dependencies: Vec::new(),
+            sub_texts: Vec::new(),
+        });
+        let unsaved = app.world.resource_mut::<Assets<CoolText>>().add(CoolText {
+            text: "unmanaged".to_string(),
+            embedded: String::new(),
+            dependencies: Vec::new(),
+            sub_texts: Vec::new(),
+        });
+        let labeled: Handle<CoolText> = asset_server.load("labeled.cool.ron");
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, labeled.id()).map(|_| ())
+        });
+        asset_server.save_as::<CoolTextSaver>(&handle, "a.cool.ron", ());
+        asset_server.save_as::<CoolTextSaver>(&unsaved, "b.cool.ron", ());
+        asset_server.save::<CoolTextSaver>(&labeled, "c.cool.ron", ());
+
+        let mut reader = ManualEventReader::<AssetSaveEvent>::default();
+        let mut events = Vec::new();
+        run_app_until(&mut app, |world| {
+            events.extend(
+                reader
+                    .read(world.resource::<Events<AssetSaveEvent>>())
+                    .map(|event| match event {
+                        AssetSaveEvent::Saved { id, path } => (*id, path.clone(), true),
+                        AssetSaveEvent::Failed { id, path, .. } => (*id, path.clone(), false),
+                    }),
+            );
+            (events.len() == 3).then_some(())
+        });
+
+        assert!(events.contains(&(handle.id().untyped(), "a.cool.ron".into(), true)));
+        assert!(events.contains(&(unsaved.id().untyped(), "b.cool.ron".into(), false)));
+        assert!(events.contains(&(labeled.id().untyped(), "c.cool.ron".into(), false)));
+        assert_eq!(
+            asset_server.get_path(&handle),
+            Some(AssetPath::from("a.cool.ron"))
+        );
+        assert_eq!(
+            asset_server.get_handle::<CoolText>("a.cool.ron"),
+            Some(handle.clone())
+        );
+
+        let saved: CoolTextRon =
+            ron::de::from_bytes(&std::fs::read(root.join("a.cool.ron")).unwrap()).unwrap();
+        assert_eq!(saved.text, "saved");
+        let meta = std::fs::read_to_string(root.join("a.cool.ron.meta")).unwrap();
+        assert!(meta.contains(std::any::type_name::<CoolTextLoader>()));
+        assert!(!root.join("b.cool.ron").exists());
+        assert!(!root.join("c.cool.ron").exists());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
@@ -2,7 +2,7 @@ use crate::{
     io::{AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader},
     meta::{
         loader_settings_meta_transform, AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal,
-        Settings,
+        Settings, SettingsMigrations,
     },
     path::AssetPath,
     Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, LoadedUntypedAsset,
@@ -29,6 +29,12 @@ pub trait AssetLoader: Send + Sync + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The type of [error](`std::error::Error`) which could be encountered by this loader.
     type Error: Into<Box<dyn std::error::Error + Send + Sync + 'static>>;
+    /// The version of [`AssetLoader::Settings`]. Increment this when making a breaking change to the settings type,
+    /// and register a migration from the previous version with [`AssetApp::register_asset_loader_settings_migration`]
+    /// so that existing `.meta` files keep working.
+    ///
+    /// [`AssetApp::register_asset_loader_settings_migration`]: crate::AssetApp::register_asset_loader_settings_migration
+    const SETTINGS_VERSION: u32 = 0;
     /// Asynchronously loads [`AssetLoader::Asset`] (and any other labeled assets) from the bytes provided by [`Reader`].
     fn load<'a>(
         &'a self,
@@ -57,7 +63,12 @@ pub trait ErasedAssetLoader: Send + Sync + 'static {
     /// Returns a list of extensions supported by this asset loader, without the preceding dot.
     fn extensions(&self) -> &[&str];
     /// Deserializes metadata from the input `meta` bytes into the appropriate type (erased as [`Box<dyn AssetMetaDyn>`]).
-    fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
+    /// Settings from older versions of the loader are upgraded using `migrations`.
+    fn deserialize_meta(
+        &self,
+        meta: &[u8],
+        migrations: &SettingsMigrations,
+    ) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default meta value for the [`AssetLoader`] (erased as [`Box<dyn AssetMetaDyn>`]).
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
     /// Returns the type name of the [`AssetLoader`].
@@ -101,8 +112,12 @@ where
         <L as AssetLoader>::extensions(self)
     }
 
-    fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError> {
-        let meta = AssetMeta::<L, ()>::deserialize(meta)?;
+    fn deserialize_meta(
+        &self,
+        meta: &[u8],
+        migrations: &SettingsMigrations,
+    ) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError> {
+        let meta = AssetMeta::<L, ()>::deserialize_with_migrations(meta, migrations)?;
         Ok(Box::new(meta))
     }
 
@@ -237,7 +252,9 @@ impl_downcast!(AssetContainer);
 
 impl<A: Asset> AssetContainer for A {
     fn insert(self: Box<Self>, id: UntypedAssetId, world: &mut World) {
-        world.resource_mut::<Assets<A>>().insert(id.typed(), *self);
+        world
+            .resource_mut::<Assets<A>>()
+            .insert_loaded(id.typed(), *self);
     }
 
     fn asset_type_name(&self) -> &'static str {
@@ -260,16 +277,20 @@ pub enum DeserializeMetaError {
     DeserializeSettings(#[from] SpannedError),
     #[error("Failed to deserialize minimal asset meta: {0:?}")]
     DeserializeMinimal(SpannedError),
+    #[error("The settings of '{type_name}' cannot be upgraded from version {version} because no migration is registered for it")]
+    MissingSettingsMigration { type_name: String, version: u32 },
+    #[error("The settings migration of '{type_name}' from version {version} received settings of an unexpected type")]
+    SettingsMigrationTypeMismatch { type_name: String, version: u32 },
 }
 
 /// A context that provides access to assets in [`AssetLoader`]s, tracks dependencies, and collects asset load state.
 /// Any asset state accessed by [`LoadContext`] will be tracked and stored for use in dependency events and asset preprocessing.
 pub struct LoadContext<'a> {
-    asset_server: &'a AssetServer,
-    should_load_dependencies: bool,
+    pub(crate) asset_server: &'a AssetServer,
+    pub(crate) should_load_dependencies: bool,
     populate_hashes: bool,
     asset_path: AssetPath<'static>,
-    dependencies: HashSet<UntypedAssetId>,
+    pub(crate) dependencies: HashSet<UntypedAssetId>,
     /// Direct dependencies used by this loader.
     loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
     labeled_assets: HashMap<CowArc<'static, str>, LabeledAsset>,
@@ -1,9 +1,10 @@
 use crate::{self as bevy_asset, DeserializeMetaError, VisitAssetDependencies};
 use crate::{loader::AssetLoader, processor::Process, Asset, AssetPath};
 use bevy_log::error;
+use bevy_utils::HashMap;
 use downcast_rs::{impl_downcast, Downcast};
 use ron::ser::PrettyConfig;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 pub const META_FORMAT_VERSION: &str = "1.0";
 pub type MetaTransform = Box<dyn Fn(&mut dyn AssetMetaDyn) + Send + Sync>;
@@ -23,15 +24,29 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
     /// [`AssetProcessor`]: crate::processor::AssetProcessor
     #[serde(skip_serializing_if = "Option::is_none")]
     pub processed_info: Option<ProcessedInfo>,
+    /// The version of the loader or processor settings stored in `asset`. See [`AssetLoader::SETTINGS_VERSION`]
+    /// and [`Process::SETTINGS_VERSION`]. Metas without a version are treated as version `0`.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub settings_version: u32,
     /// How to handle this asset in the asset system. See [`AssetAction`].
     pub asset: AssetAction<L::Settings, P::Settings>,
 }
 
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
 impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
     pub fn new(asset: AssetAction<L::Settings, P::Settings>) -> Self {
+        let settings_version = match &asset {
+            AssetAction::Load { .. } => L::SETTINGS_VERSION,
+            AssetAction::Process { .. } => P::SETTINGS_VERSION,
+            AssetAction::Ignore => 0,
+        };
         Self {
             meta_format_version: META_FORMAT_VERSION.to_string(),
             processed_info: None,
+            settings_version,
             asset,
         }
     }
@@ -40,6 +55,149 @@ impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
     pub fn deserialize(bytes: &[u8]) -> Result<Self, DeserializeMetaError> {
         Ok(ron::de::from_bytes(bytes)?)
     }
+
+    /// Deserializes the given serialized byte representation of the asset meta. If its settings were written by
+    /// an older version of the loader or processor, they are upgraded to the current version using the registered
+    /// `migrations`.
+    pub fn deserialize_with_migrations(
+        bytes: &[u8],
+        migrations: &SettingsMigrations,
+    ) -> Result<Self, DeserializeMetaError> {
+        let header: AssetMetaHeader =
+            ron::de::from_bytes(bytes).map_err(DeserializeMetaError::DeserializeMinimal)?;
+        let (type_name, current_version) = match &header.asset {
+            AssetActionMinimal::Load { .. } => (std::any::type_name::<L>(), L::SETTINGS_VERSION),
+            AssetActionMinimal::Process { .. } => (std::any::type_name::<P>(), P::SETTINGS_VERSION),
+            AssetActionMinimal::Ignore => return Self::deserialize(bytes),
+        };
+        if header.settings_version >= current_version {
+            return Self::deserialize(bytes);
+        }
+
+        let settings =
+            migrations.migrate(type_name, header.settings_version, current_version, bytes)?;
+        let mismatch = || DeserializeMetaError::SettingsMigrationTypeMismatch {
+            type_name: type_name.to_string(),
+            version: current_version,
+        };
+        let asset = match header.asset {
+            AssetActionMinimal::Load { loader } => AssetAction::Load {
+                loader,
+                settings: *settings.downcast::<L::Settings>().map_err(|_| mismatch())?,
+            },
+            AssetActionMinimal::Process { processor } => AssetAction::Process {
+                processor,
+                settings: *settings.downcast::<P::Settings>().map_err(|_| mismatch())?,
+            },
+            AssetActionMinimal::Ignore => unreachable!(),
+        };
+        Ok(Self {
+            meta_format_version: header.meta_format_version,
+            processed_info: header.processed_info,
+            settings_version: current_version,
+            asset,
+        })
+    }
+}
+
+/// The parts of an [`AssetMeta`] that can be read without knowing its settings types.
+#[derive(Deserialize)]
+struct AssetMetaHeader {
+    meta_format_version: String,
+    #[serde(default)]
+    processed_info: Option<ProcessedInfo>,
+    #[serde(default)]
+    settings_version: u32,
+    asset: AssetActionMinimal,
+}
+
+/// The parts of an [`AssetMeta`] needed to read settings of type `S` (from either the loader or the processor).
+#[derive(Deserialize)]
+struct AssetMetaSettings<S> {
+    asset: AssetAction<S, S>,
+}
+
+/// A single step in a chain of settings migrations. See [`SettingsMigrations`].
+struct SettingsMigration {
+    deserialize: fn(&[u8]) -> Result<Box<dyn Settings>, DeserializeMetaError>,
+    migrate: Box<dyn Fn(Box<dyn Settings>) -> Option<Box<dyn Settings>> + Send + Sync>,
+}
+
+/// Registered functions that upgrade [`AssetLoader`] and [`Process`] settings stored in older `.meta` files
+/// to the current [`AssetLoader::SETTINGS_VERSION`] / [`Process::SETTINGS_VERSION`].
+///
+/// Each migration upgrades settings from one version to the next. When a `.meta` file with settings version `N`
+/// is read, the settings are deserialized using the type registered for version `N`, then passed through every
+/// migration from `N` up to the current version.
+#[derive(Default)]
+pub struct SettingsMigrations {
+    migrations: HashMap<&'static str, HashMap<u32, SettingsMigration>>,
+}
+
+impl SettingsMigrations {
+    /// Registers a `migrate` function that upgrades settings of type `From`, stored with `from_version`, to the
+    /// settings type `To` of version `from_version + 1`, for the loader or processor with the given `type_name`.
+    pub fn register<From, To>(
+        &mut self,
+        type_name: &'static str,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        From: Settings + DeserializeOwned,
+        To: Settings,
+    {
+        self.migrations.entry(type_name).or_default().insert(
+            from_version,
+            SettingsMigration {
+                deserialize: deserialize_settings::<From>,
+                migrate: Box::new(move |settings| {
+                    let settings = settings.downcast::<From>().ok()?;
+                    Some(Box::new(migrate(*settings)))
+                }),
+            },
+        );
+    }
+
+    /// Deserializes the settings in `meta_bytes` (stored with `from_version`) and upgrades them to `to_version`.
+    fn migrate(
+        &self,
+        type_name: &str,
+        from_version: u32,
+        to_version: u32,
+        meta_bytes: &[u8],
+    ) -> Result<Box<dyn Settings>, DeserializeMetaError> {
+        let get = |version| {
+            self.migrations
+                .get(type_name)
+                .and_then(|migrations| migrations.get(&version))
+                .ok_or_else(|| DeserializeMetaError::MissingSettingsMigration {
+                    type_name: type_name.to_string(),
+                    version,
+                })
+        };
+        let mut settings = (get(from_version)?.deserialize)(meta_bytes)?;
+        for version in from_version..to_version {
+            settings = (get(version)?.migrate)(settings).ok_or_else(|| {
+                DeserializeMetaError::SettingsMigrationTypeMismatch {
+                    type_name: type_name.to_string(),
+                    version,
+                }
+            })?;
+        }
+        Ok(settings)
+    }
+}
+
+fn deserialize_settings<S: Settings + DeserializeOwned>(
+    meta_bytes: &[u8],
+) -> Result<Box<dyn Settings>, DeserializeMetaError> {
+    let meta: AssetMetaSettings<S> = ron::de::from_bytes(meta_bytes)?;
+    match meta.asset {
+        AssetAction::Load { settings, .. } | AssetAction::Process { settings, .. } => {
+            Ok(Box::new(settings))
+        }
+        AssetAction::Ignore => Ok(Box::new(())),
+    }
 }
 
 /// Configures how an asset source file should be handled by the asset system.
@@ -93,6 +251,9 @@ pub struct ProcessDependencyInfo {
 // using a type registry.
 #[derive(Serialize, Deserialize)]
 pub struct AssetMetaMinimal {
+    /// See [`AssetMeta::settings_version`].
+    #[serde(default)]
+    pub settings_version: u32,
     pub asset: AssetActionMinimal,
 }
 
@@ -125,6 +286,8 @@ pub trait AssetMetaDyn: Downcast + Send + Sync {
     fn processed_info(&self) -> &Option<ProcessedInfo>;
     /// Returns a mutable reference to the [`ProcessedInfo`] if it exists.
     fn processed_info_mut(&mut self) -> &mut Option<ProcessedInfo>;
+    /// Returns the version of the settings stored in this meta. See [`AssetMeta::settings_version`].
+    fn settings_version(&self) -> u32;
 }
 
 impl<L: AssetLoader, P: Process> AssetMetaDyn for AssetMeta<L, P> {
@@ -153,6 +316,9 @@ impl<L: AssetLoader, P: Process> AssetMetaDyn for AssetMeta<L, P> {
     fn processed_info_mut(&mut self) -> &mut Option<ProcessedInfo> {
         &mut self.processed_info
     }
+    fn settings_version(&self) -> u32 {
+        self.settings_version
+    }
 }
 
 impl_downcast!(AssetMetaDyn);
@@ -247,3 +413,88 @@ pub(crate) fn get_full_asset_hash(
     }
     *hasher.finalize().as_bytes()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{io::Reader, LoadContext};
+    use bevy_utils::BoxedFuture;
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct SettingsV0 {
+        width: u32,
+    }
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct SettingsV1 {
+        size: u32,
+    }
+
+    struct VersionedLoader;
+
+    impl AssetLoader for VersionedLoader {
+        type Asset = ();
+        type Settings = SettingsV1;
+        type Error = std::io::Error;
+        const SETTINGS_VERSION: u32 = 1;
+
+        fn load<'a>(
+            &'a self,
+            _reader: &'a mut Reader,
+            _settings: &'a Self::Settings,
+            _load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+            unreachable!();
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &[]
+        }
+    }
+
+    #[test]
+    fn migrate_loader_settings() {
+        let old_meta = r#"(
+            meta_format_version: "1.0",
+            asset: Load(
+                loader: "VersionedLoader",
+                settings: (width: 4),
+            ),
+        )"#;
+
+        let migrations = SettingsMigrations::default();
+        assert!(matches!(
+            AssetMeta::<VersionedLoader, ()>::deserialize_with_migrations(
+                old_meta.as_bytes(),
+                &migrations
+            ),
+            Err(DeserializeMetaError::MissingSettingsMigration { version: 0, .. })
+        ));
+
+        let mut migrations = SettingsMigrations::default();
+        migrations.register(
+            std::any::type_name::<VersionedLoader>(),
+            0,
+            |old: SettingsV0| SettingsV1 { size: old.width },
+        );
+        let meta = AssetMeta::<VersionedLoader, ()>::deserialize_with_migrations(
+            old_meta.as_bytes(),
+            &migrations,
+        )
+        .unwrap();
+        assert_eq!(meta.settings_version, 1);
+        let AssetAction::Load { loader, settings } = &meta.asset else {
+            panic!("expected a load action");
+        };
+        assert_eq!(loader, "VersionedLoader");
+        assert_eq!(settings.size, 4);
+
+        // Current metas are read as-is
+        let migrated = AssetMeta::<VersionedLoader, ()>::deserialize_with_migrations(
+            &AssetMetaDyn::serialize(&meta),
+            &SettingsMigrations::default(),
+        )
+        .unwrap();
+        assert_eq!(migrated.settings_version, 1);
+    }
+}
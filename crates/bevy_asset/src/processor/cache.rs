@@ -0,0 +1,208 @@
+use crate::meta::AssetHash;
+use bevy_utils::{BoxedFuture, Uuid};
+use futures_lite::AsyncWriteExt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The processed output of a single asset, as stored in a [`ProcessedAssetCache`].
+#[derive(Debug, Clone)]
+pub struct CachedProcessedAsset {
+    /// The processed asset bytes.
+    pub asset_bytes: Vec<u8>,
+    /// The processed asset's serialized [`AssetMeta`], including its [`ProcessedInfo`].
+    ///
+    /// [`AssetMeta`]: crate::meta::AssetMeta
+    /// [`ProcessedInfo`]: crate::meta::ProcessedInfo
+    pub meta_bytes: Vec<u8>,
+}
+
+/// A shared store of processed assets that lets the [`AssetProcessor`] skip processing assets that were
+/// already processed elsewhere, such as on another developer's machine or on a CI runner.
+///
+/// Entries are keyed by [`get_processed_asset_cache_key`], which combines the hash of the asset's source bytes
+/// and meta (including its processor settings) with the [`Process::VERSION`] of the processor.
+/// Entries additionally record the full hash of every "process dependency" used to produce them. The
+/// [`AssetProcessor`] only uses an entry if those dependency hashes match the current ones, which means
+/// an entry is only ever used for a matching [`ProcessedInfo::full_hash`].
+///
+/// [`AssetProcessor`]: super::AssetProcessor
+/// [`Process::VERSION`]: super::Process::VERSION
+/// [`ProcessedInfo::full_hash`]: crate::meta::ProcessedInfo::full_hash
+pub trait ProcessedAssetCache: Send + Sync + 'static {
+    /// Returns the cached processed asset for the given `key`, if it exists.
+    fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>>;
+
+    /// Stores the given processed `asset` for the given `key`, replacing any existing entry.
+    fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>>;
+}
+
+/// An error that occurs when reading from or writing to a [`ProcessedAssetCache`].
+#[derive(Error, Debug)]
+pub enum ProcessedAssetCacheError {
+    #[error("Encountered an io error while accessing the processed asset cache: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Computes the key used to look up an asset in a [`ProcessedAssetCache`], from the asset's
+/// [`ProcessedInfo::hash`] and the [`Process::VERSION`] of the processor used.
+///
+/// NOTE: changing the hashing logic here invalidates every existing [`ProcessedAssetCache`] entry.
+///
+/// [`ProcessedInfo::hash`]: crate::meta::ProcessedInfo::hash
+/// [`Process::VERSION`]: super::Process::VERSION
+pub fn get_processed_asset_cache_key(asset_hash: AssetHash, processor_version: u32) -> AssetHash {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&asset_hash);
+    hasher.update(&processor_version.to_le_bytes());
+    *hasher.finalize().as_bytes()
+}
+
+/// A [`ProcessedAssetCache`] backed by a local directory. The directory can live on a shared or synced drive
+/// to share processed assets between machines.
+///
+/// Each entry is stored as a `{key}.asset` / `{key}.meta` file pair, where `key` is the hex-encoded cache key.
+pub struct LocalProcessedAssetCache {
+    root: PathBuf,
+}
+
+impl LocalProcessedAssetCache {
+    /// Creates a new [`LocalProcessedAssetCache`] that stores entries in the given `root` directory.
+    /// The directory will be created when the first entry is written.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The directory entries are stored in.
+    pub fn root(&self) -> &PathBuf {
+        &self.root
+    }
+
+    fn entry_paths(&self, key: &AssetHash) -> (PathBuf, PathBuf) {
+        let name = blake3::Hash::from(*key).to_hex();
+        (
+            self.root.join(format!("{name}.asset")),
+            self.root.join(format!("{name}.meta")),
+        )
+    }
+}
+
+/// Writes `bytes` to a temporary file next to `path` and then renames it into place.
+///
+/// The temporary file name is unique per file and per write, so concurrent writers (including writers on other
+/// machines sharing the cache directory) never write into the same temporary file.
+async fn write_file_atomic(path: &Path, bytes: &[u8]) -> Result<(), std::io::Error> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(format!(".tmp.{}", Uuid::new_v4().simple()));
+    let temp_path = path.with_file_name(temp_name);
+    let mut file = async_fs::File::create(&temp_path).await?;
+    file.write_all(bytes).await?;
+    file.flush().await?;
+    file.sync_all().await?;
+    async_fs::rename(&temp_path, path).await
+}
+
+impl ProcessedAssetCache for LocalProcessedAssetCache {
+    fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>> {
+        Box::pin(async move {
+            let (asset_path, meta_path) = self.entry_paths(key);
+            // The meta is written last, so its presence marks a complete entry
+            let meta_bytes = match async_fs::read(&meta_path).await {
+                Ok(bytes) => bytes,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+            let asset_bytes = match async_fs::read(&asset_path).await {
+                Ok(bytes) => bytes,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+            Ok(Some(CachedProcessedAsset {
+                asset_bytes,
+                meta_bytes,
+            }))
+        })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>> {
+        Box::pin(async move {
+            async_fs::create_dir_all(&self.root).await?;
+            let (asset_path, meta_path) = self.entry_paths(key);
+            write_file_atomic(&asset_path, &asset.asset_bytes).await?;
+            write_file_atomic(&meta_path, &asset.meta_bytes).await?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_cache_round_trip() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_asset_processed_cache_test_{}",
+            std::process::id()
+        ));
+        let cache = LocalProcessedAssetCache::new(&root);
+        let key = get_processed_asset_cache_key([7; 32], 1);
+        assert_ne!(key, get_processed_asset_cache_key([7; 32], 2));
+
+        bevy_tasks::block_on(async {
+            assert!(cache.get(&key).await.unwrap().is_none());
+            let asset = CachedProcessedAsset {
+                asset_bytes: b"processed".to_vec(),
+                meta_bytes: b"meta".to_vec(),
+            };
+            cache.put(&key, &asset).await.unwrap();
+            let cached = cache.get(&key).await.unwrap().unwrap();
+            assert_eq!(cached.asset_bytes, asset.asset_bytes);
+            assert_eq!(cached.meta_bytes, asset.meta_bytes);
+        });
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn local_cache_concurrent_writes_do_not_mix_files() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_asset_processed_cache_concurrent_test_{}",
+            std::process::id()
+        ));
+        let cache = LocalProcessedAssetCache::new(&root);
+        let key = get_processed_asset_cache_key([3; 32], 1);
+        let asset = CachedProcessedAsset {
+            asset_bytes: b"asset".repeat(4096),
+            meta_bytes: b"meta".to_vec(),
+        };
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| bevy_tasks::block_on(cache.put(&key, &asset)).unwrap());
+            }
+        });
+
+        let cached = bevy_tasks::block_on(cache.get(&key)).unwrap().unwrap();
+        assert_eq!(cached.asset_bytes, asset.asset_bytes);
+        assert_eq!(cached.meta_bytes, asset.meta_bytes);
+
+        // No temporary files are left behind
+        let entries = std::fs::read_dir(&root).unwrap().count();
+        assert_eq!(entries, 2);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,6 +1,8 @@
+mod cache;
 mod log;
 mod process;
 
+pub use cache::*;
 pub use log::*;
 pub use process::*;
 
@@ -11,7 +13,7 @@ use crate::{
     },
     meta::{
         get_asset_hash, get_full_asset_hash, AssetAction, AssetActionMinimal, AssetHash, AssetMeta,
-        AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal,
+        AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal, Settings,
     },
     AssetLoadError, AssetMetaCheck, AssetPath, AssetServer, AssetServerMode, DeserializeMetaError,
     MissingAssetLoaderForExtensionError,
@@ -23,6 +25,7 @@ use bevy_utils::{BoxedFuture, HashMap, HashSet};
 use futures_io::ErrorKind;
 use futures_lite::{AsyncReadExt, AsyncWriteExt, StreamExt};
 use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
 use std::{
     collections::VecDeque,
     path::{Path, PathBuf},
@@ -44,6 +47,9 @@ use thiserror::Error;
 /// A [`ProcessorTransactionLog`] is produced, which uses "write-ahead logging" to make the [`AssetProcessor`] crash and failure resistant. If a failed/unfinished
 /// transaction from a previous run is detected, the affected asset(s) will be re-processed.
 ///
+/// If a [`ProcessedAssetCache`] is set with [`AssetProcessor::set_cache`], processed results are shared through it, which allows
+/// assets processed on one machine to be reused on another without processing them again.
+///
 /// [`AssetProcessor`] can be cloned. It is backed by an [`Arc`] so clones will share state. Clones can be freely used in parallel.
 #[derive(Resource, Clone)]
 pub struct AssetProcessor {
@@ -57,6 +63,7 @@ pub struct AssetProcessorData {
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<String, &'static str>>,
+    cache: RwLock<Option<Arc<dyn ProcessedAssetCache>>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -182,6 +189,30 @@ impl AssetProcessor {
         debug!("Processing finished in {:?}", end_time - start_time);
     }
 
+    /// Processes all assets once (see [`AssetProcessor::process_assets`]), without listening for changes afterwards,
+    /// and returns a [`ProcessSummary`] of the results. This is intended for build pipelines and CI, which can use
+    /// [`ProcessSummary::exit_code`] to fail when an asset could not be processed.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi-threaded"))]
+    pub fn process_assets_headless(&self) -> ProcessSummary {
+        self.process_assets();
+        bevy_tasks::block_on(self.summary())
+    }
+
+    /// Returns a [`ProcessSummary`] of the current processing status of every known asset.
+    pub async fn summary(&self) -> ProcessSummary {
+        let infos = self.data.asset_infos.read().await;
+        let mut summary = ProcessSummary::default();
+        for (path, info) in &infos.infos {
+            match info.status {
+                Some(ProcessStatus::Processed) => summary.processed += 1,
+                Some(ProcessStatus::Failed) => summary.failed.push(path.clone()),
+                Some(ProcessStatus::NonExistent) | None => {}
+            }
+        }
+        summary.failed.sort_by_key(|path| path.to_string());
+        summary
+    }
+
     /// Listens for changes to assets in the source [`AssetSource`] and update state accordingly.
     // PERF: parallelize change event processing
     pub async fn listen_for_source_change_events(&self) {
@@ -465,6 +496,20 @@ impl AssetProcessor {
         process_plans.insert(std::any::type_name::<P>(), Arc::new(processor));
     }
 
+    /// Registers a `migrate` function that upgrades the [`Process::Settings`] of `P` stored in `.meta` files with
+    /// `from_version` (deserialized as `From`) to the settings of version `from_version + 1`. See [`Process::SETTINGS_VERSION`].
+    pub fn register_processor_settings_migration<P: Process, From, To>(
+        &self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        From: Settings + DeserializeOwned,
+        To: Settings,
+    {
+        self.server
+            .register_settings_migration(std::any::type_name::<P>(), from_version, migrate);
+    }
+
     /// Set the default processor for the given `extension`. Make sure `P` is registered with [`AssetProcessor::register_processor`].
     pub fn set_default_processor<P: Process>(&self, extension: &str) {
         let mut default_processors = self.data.default_processors.write();
@@ -478,6 +523,16 @@ impl AssetProcessor {
         self.data.processors.read().get(key).cloned()
     }
 
+    /// Sets the [`ProcessedAssetCache`] used to share processed assets, replacing the current one.
+    pub fn set_cache(&self, cache: impl ProcessedAssetCache) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
+    /// Returns the current [`ProcessedAssetCache`], if one is set.
+    pub fn cache(&self) -> Option<Arc<dyn ProcessedAssetCache>> {
+        self.data.cache.read().clone()
+    }
+
     /// Returns the processor with the given `processor_type_name`, if it exists.
     pub fn get_processor(&self, processor_type_name: &str) -> Option<Arc<dyn ErasedProcessor>> {
         let processors = self.data.processors.read();
@@ -685,21 +740,25 @@ impl AssetProcessor {
         let mut byte_reader = reader.read(path).await.map_err(reader_err)?;
 
         let (mut source_meta, meta_bytes, processor) = match reader.read_meta_bytes(path).await {
-            Ok(meta_bytes) => {
+            Ok(mut meta_bytes) => {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
                 let (meta, processor) = match minimal.asset {
                     AssetActionMinimal::Load { loader } => {
                         let loader = server.get_asset_loader_with_type_name(&loader).await?;
-                        let meta = loader.deserialize_meta(&meta_bytes)?;
+                        let meta = server.with_settings_migrations(|migrations| {
+                            loader.deserialize_meta(&meta_bytes, migrations)
+                        })?;
                         (meta, None)
                     }
                     AssetActionMinimal::Process { processor } => {
                         let processor = self
                             .get_processor(&processor)
                             .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
-                        let meta = processor.deserialize_meta(&meta_bytes)?;
+                        let meta = server.with_settings_migrations(|migrations| {
+                            processor.deserialize_meta(&meta_bytes, migrations)
+                        })?;
                         (meta, Some(processor))
                     }
                     AssetActionMinimal::Ignore => {
@@ -708,6 +767,17 @@ impl AssetProcessor {
                         (meta, None)
                     }
                 };
+                if meta.settings_version() != minimal.settings_version {
+                    // The settings were migrated from an older version. Rewrite the meta in place so that it
+                    // reflects the current settings format.
+                    debug!("Rewriting migrated meta for {asset_path}");
+                    meta_bytes = meta.serialize();
+                    source
+                        .writer()?
+                        .write_meta_bytes(path, &meta_bytes)
+                        .await
+                        .map_err(writer_err)?;
+                }
                 (meta, meta_bytes, processor)
             }
             Err(AssetReaderError::NotFound(_path)) => {
@@ -802,6 +872,29 @@ impl AssetProcessor {
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
         if let Some(processor) = processor {
+            let cache = self.cache().map(|cache| {
+                let key = get_processed_asset_cache_key(new_hash, processor.version());
+                (cache, key)
+            });
+            if let Some((cache, key)) = &cache {
+                if let Some((cached, processed_info)) = self
+                    .get_cached_processed_asset(&**cache, key, asset_path, new_hash)
+                    .await
+                {
+                    debug!("Using cached processed asset for {asset_path}");
+                    processed_writer
+                        .write_bytes(path, &cached.asset_bytes)
+                        .await
+                        .map_err(writer_err)?;
+                    processed_writer
+                        .write_meta_bytes(path, &cached.meta_bytes)
+                        .await
+                        .map_err(writer_err)?;
+                    self.log_end_processing(asset_path).await;
+                    return Ok(ProcessResult::Processed(processed_info));
+                }
+            }
+
             let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
             let mut processed_meta = {
                 let mut context =
@@ -833,6 +926,11 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+
+            if let Some((cache, key)) = &cache {
+                self.put_cached_processed_asset(&**cache, key, source, asset_path, meta_bytes)
+                    .await;
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -850,6 +948,85 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Returns the processed asset stored in `cache` for `key`, if it exists and was processed from the same
+    /// source asset and process dependencies as the current ones.
+    async fn get_cached_processed_asset(
+        &self,
+        cache: &dyn ProcessedAssetCache,
+        key: &AssetHash,
+        asset_path: &AssetPath<'static>,
+        asset_hash: AssetHash,
+    ) -> Option<(CachedProcessedAsset, ProcessedInfo)> {
+        let cached = match cache.get(key).await {
+            Ok(cached) => cached?,
+            Err(err) => {
+                warn!("Failed to read {asset_path} from the processed asset cache: {err}");
+                return None;
+            }
+        };
+        let processed_info = match ron::de::from_bytes::<ProcessedInfoMinimal>(&cached.meta_bytes) {
+            Ok(ProcessedInfoMinimal {
+                processed_info: Some(processed_info),
+            }) if processed_info.hash == asset_hash => processed_info,
+            _ => {
+                warn!("Ignoring invalid processed asset cache entry for {asset_path}");
+                return None;
+            }
+        };
+        for dependency in &processed_info.process_dependencies {
+            // The dependency must finish processing before its current hash can be compared to the cached one
+            self.data
+                .wait_until_processed(dependency.path.clone())
+                .await;
+            let infos = self.data.asset_infos.read().await;
+            let live_hash = infos
+                .get(&dependency.path)
+                .and_then(|i| i.processed_info.as_ref())
+                .map(|i| i.full_hash);
+            if live_hash != Some(dependency.full_hash) {
+                trace!(
+                    "Cached processed asset for {asset_path} is stale because {} changed",
+                    dependency.path
+                );
+                return None;
+            }
+        }
+        Some((cached, processed_info))
+    }
+
+    /// Stores the freshly processed asset at `asset_path` in `cache`. Failures are logged, but do not fail processing.
+    async fn put_cached_processed_asset(
+        &self,
+        cache: &dyn ProcessedAssetCache,
+        key: &AssetHash,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+        meta_bytes: Vec<u8>,
+    ) {
+        let mut asset_bytes = Vec::new();
+        let read_result = match source.processed_reader() {
+            Ok(reader) => match reader.read(asset_path.path()).await {
+                Ok(mut reader) => reader
+                    .read_to_end(&mut asset_bytes)
+                    .await
+                    .map_err(|err| err.to_string()),
+                Err(err) => Err(err.to_string()),
+            },
+            Err(err) => Err(err.to_string()),
+        };
+        if let Err(err) = read_result {
+            warn!("Failed to read processed {asset_path} to store it in the processed asset cache: {err}");
+            return;
+        }
+        let cached = CachedProcessedAsset {
+            asset_bytes,
+            meta_bytes,
+        };
+        if let Err(err) = cache.put(key, &cached).await {
+            warn!("Failed to store {asset_path} in the processed asset cache: {err}");
+        }
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
         if let Err(err) = ProcessorTransactionLog::validate().await {
             let state_is_valid = match err {
@@ -956,6 +1133,7 @@ impl AssetProcessorData {
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            cache: Default::default(),
         }
     }
 
@@ -1021,6 +1199,31 @@ pub enum ProcessResult {
     SkippedNotChanged,
 }
 
+/// A summary of the results of processing assets, returned by [`AssetProcessor::summary`].
+#[derive(Debug, Default, Clone)]
+pub struct ProcessSummary {
+    /// The number of assets that were processed successfully (or were already up to date).
+    pub processed: usize,
+    /// The paths of assets that failed to process.
+    pub failed: Vec<AssetPath<'static>>,
+}
+
+impl ProcessSummary {
+    /// Returns `true` if no assets failed to process.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Returns a process exit code for this summary: `0` if every asset was processed successfully and `1` otherwise.
+    pub fn exit_code(&self) -> i32 {
+        if self.is_success() {
+            0
+        } else {
+            1
+        }
+    }
+}
+
 /// The final status of processing an asset
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ProcessStatus {
@@ -1317,3 +1520,246 @@ pub enum InitializeError {
     #[error("Failed to validate asset log: {0}")]
     ValidateLogError(ValidateLogError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_asset,
+        io::{
+            file::{FileAssetReader, FileAssetWriter},
+            AssetSourceBuilder, Reader, Writer,
+        },
+        Asset, AssetLoader, LoadContext,
+    };
+    use bevy_reflect::TypePath;
+    use serde::{Deserialize, Serialize};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Asset, TypePath)]
+    struct Text;
+
+    #[derive(Default)]
+    struct TextLoader;
+
+    impl AssetLoader for TextLoader {
+        type Asset = Text;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        fn load<'a>(
+            &'a self,
+            _reader: &'a mut Reader,
+            _settings: &'a Self::Settings,
+            _load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+            Box::pin(async move { Ok(Text) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    /// Uppercases text assets and counts how many times it actually ran.
+    #[derive(Default)]
+    struct UppercaseProcess {
+        runs: Arc<AtomicUsize>,
+    }
+
+    impl Process for UppercaseProcess {
+        type Settings = ();
+        type OutputLoader = TextLoader;
+
+        fn process<'a>(
+            &'a self,
+            context: &'a mut ProcessContext,
+            _meta: AssetMeta<(), Self>,
+            writer: &'a mut Writer,
+        ) -> BoxedFuture<'a, Result<(), ProcessError>> {
+            Box::pin(async move {
+                self.runs.fetch_add(1, Ordering::SeqCst);
+                let uppercase = context.asset_bytes().to_ascii_uppercase();
+                writer
+                    .write_all(&uppercase)
+                    .await
+                    .map_err(|err| ProcessError::AssetSaveError(Box::new(err)))?;
+                Ok(())
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct RepeatSettingsV0 {
+        times: u32,
+    }
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct RepeatSettingsV1 {
+        count: usize,
+    }
+
+    /// Repeats text assets. Its settings were renamed in version 1.
+    struct RepeatProcess;
+
+    impl Process for RepeatProcess {
+        type Settings = RepeatSettingsV1;
+        const SETTINGS_VERSION: u32 = 1;
+        type OutputLoader = TextLoader;
+
+        fn process<'a>(
+            &'a self,
+            context: &'a mut ProcessContext,
+            meta: AssetMeta<(), Self>,
+            writer: &'a mut Writer,
+        ) -> BoxedFuture<'a, Result<(), ProcessError>> {
+            Box::pin(async move {
+                let AssetAction::Process { settings, .. } = &meta.asset else {
+                    return Err(ProcessError::WrongMetaType);
+                };
+                let count = settings.count;
+                writer
+                    .write_all(&context.asset_bytes().repeat(count))
+                    .await
+                    .map_err(|err| ProcessError::AssetSaveError(Box::new(err)))?;
+                Ok(())
+            })
+        }
+    }
+
+    /// A temporary directory for a single test, removed when dropped.
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "bevy_asset_processor_{name}_{}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn test_processor(source: &Path, processed: &Path) -> AssetProcessor {
+        let (source, processed) = (source.to_owned(), processed.to_owned());
+        let mut builders = AssetSourceBuilders::default();
+        builders.insert(
+            AssetSourceId::Default,
+            AssetSourceBuilder::default()
+                .with_reader({
+                    let source = source.clone();
+                    move || Box::new(FileAssetReader::new(source.clone()))
+                })
+                .with_writer(move || Some(Box::new(FileAssetWriter::new(source.clone()))))
+                .with_processed_reader({
+                    let processed = processed.clone();
+                    move || Box::new(FileAssetReader::new(processed.clone()))
+                })
+                .with_processed_writer(move || {
+                    Some(Box::new(FileAssetWriter::new(processed.clone())))
+                }),
+        );
+        AssetProcessor::new(&mut builders)
+    }
+
+    async fn process(
+        processor: &AssetProcessor,
+        log_path: PathBuf,
+        path: &'static str,
+    ) -> ProcessResult {
+        *processor.data.log.write().await =
+            Some(ProcessorTransactionLog::new_at(log_path).await.unwrap());
+        let source = processor.get_source(AssetSourceId::Default).unwrap();
+        processor
+            .process_asset_internal(source, &AssetPath::from(path))
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn second_processing_run_uses_cache() {
+        let dir = TestDir::new("cache");
+        let source = dir.0.join("source");
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::write(source.join("a.txt"), "hello").unwrap();
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let run_processor = |processed: &str| {
+            let processor = test_processor(&source, &dir.0.join(processed));
+            processor.register_processor(UppercaseProcess { runs: runs.clone() });
+            processor.set_default_processor::<UppercaseProcess>("txt");
+            processor.set_cache(LocalProcessedAssetCache::new(dir.0.join("cache")));
+            let result = bevy_tasks::block_on(process(
+                &processor,
+                dir.0.join(format!("{processed}.log")),
+                "a.txt",
+            ));
+            assert!(matches!(result, ProcessResult::Processed(_)));
+            std::fs::read(dir.0.join(processed).join("a.txt")).unwrap()
+        };
+
+        assert_eq!(run_processor("processed_1"), b"HELLO");
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        // A fresh processor writing to a fresh destination gets the result from the cache
+        assert_eq!(run_processor("processed_2"), b"HELLO");
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            std::fs::read(dir.0.join("processed_1/a.txt.meta")).unwrap(),
+            std::fs::read(dir.0.join("processed_2/a.txt.meta")).unwrap()
+        );
+    }
+
+    #[test]
+    fn migrated_meta_is_rewritten() {
+        let dir = TestDir::new("migration");
+        let source = dir.0.join("source");
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::write(source.join("a.txt"), "ab").unwrap();
+        let old_meta = format!(
+            r#"(
+                meta_format_version: "1.0",
+                asset: Process(
+                    processor: "{}",
+                    settings: (times: 3),
+                ),
+            )"#,
+            std::any::type_name::<RepeatProcess>()
+        );
+        std::fs::write(source.join("a.txt.meta"), old_meta).unwrap();
+
+        let processor = test_processor(&source, &dir.0.join("processed"));
+        processor.register_processor(RepeatProcess);
+        processor.register_processor_settings_migration::<RepeatProcess, _, _>(
+            0,
+            |old: RepeatSettingsV0| RepeatSettingsV1 {
+                count: old.times as usize,
+            },
+        );
+        let result = bevy_tasks::block_on(process(&processor, dir.0.join("log"), "a.txt"));
+        assert!(matches!(result, ProcessResult::Processed(_)));
+        assert_eq!(
+            std::fs::read(dir.0.join("processed/a.txt")).unwrap(),
+            b"ababab"
+        );
+
+        // The source meta now uses the current settings format, so it no longer needs the migration
+        let meta = AssetMeta::<(), RepeatProcess>::deserialize(
+            &std::fs::read(source.join("a.txt.meta")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(meta.settings_version, RepeatProcess::SETTINGS_VERSION);
+        let AssetAction::Process { settings, .. } = &meta.asset else {
+            panic!("expected a process action");
+        };
+        assert_eq!(settings.count, 3);
+    }
+}
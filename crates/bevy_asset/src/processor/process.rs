@@ -3,7 +3,10 @@ use crate::{
         AssetReaderError, AssetWriterError, MissingAssetWriterError,
         MissingProcessedAssetReaderError, MissingProcessedAssetWriterError, Writer,
     },
-    meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings},
+    meta::{
+        AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings,
+        SettingsMigrations,
+    },
     processor::AssetProcessor,
     saver::{AssetSaver, SavedAsset},
     AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset,
@@ -22,8 +25,19 @@ use thiserror::Error;
 pub trait Process: Send + Sync + Sized + 'static {
     /// The configuration / settings used to process the asset. This will be stored in the [`AssetMeta`] and is user-configurable per-asset.
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
+    /// The version of [`Process::Settings`]. Increment this when making a breaking change to the settings type,
+    /// and register a migration from the previous version with [`AssetApp::register_asset_processor_settings_migration`]
+    /// so that existing `.meta` files keep working. The [`AssetProcessor`] rewrites migrated `.meta` files in place.
+    ///
+    /// [`AssetApp::register_asset_processor_settings_migration`]: crate::AssetApp::register_asset_processor_settings_migration
+    const SETTINGS_VERSION: u32 = 0;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of this processor's output. Increment this whenever a change to the processor produces different
+    /// output for the same input bytes and settings, which invalidates results stored in a [`ProcessedAssetCache`].
+    ///
+    /// [`ProcessedAssetCache`]: crate::processor::ProcessedAssetCache
+    const VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process<'a>(
@@ -155,10 +169,16 @@ pub trait ErasedProcessor: Send + Sync {
         writer: &'a mut Writer,
     ) -> BoxedFuture<'a, Result<Box<dyn AssetMetaDyn>, ProcessError>>;
     /// Deserialized `meta` as type-erased [`AssetMeta`], operating under the assumption that it matches the meta
-    /// for the underlying [`Process`] impl.
-    fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
+    /// for the underlying [`Process`] impl. Settings from older versions of the processor are upgraded using `migrations`.
+    fn deserialize_meta(
+        &self,
+        meta: &[u8],
+        migrations: &SettingsMigrations,
+    ) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`Process::VERSION`] of the underlying [`Process`] impl.
+    fn version(&self) -> u32;
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -182,8 +202,12 @@ impl<P: Process> ErasedProcessor for P {
         })
     }
 
-    fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError> {
-        let meta: AssetMeta<(), P> = ron::de::from_bytes(meta)?;
+    fn deserialize_meta(
+        &self,
+        meta: &[u8],
+        migrations: &SettingsMigrations,
+    ) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError> {
+        let meta = AssetMeta::<(), P>::deserialize_with_migrations(meta, migrations)?;
         Ok(Box::new(meta))
     }
 
@@ -193,6 +217,10 @@ impl<P: Process> ErasedProcessor for P {
             settings: P::Settings::default(),
         }))
     }
+
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
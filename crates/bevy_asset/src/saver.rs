@@ -79,6 +79,16 @@ impl<'a, A: Asset> Deref for SavedAsset<'a, A> {
 }
 
 impl<'a, A: Asset> SavedAsset<'a, A> {
+    pub(crate) fn new(
+        value: &'a A,
+        labeled_assets: &'a HashMap<CowArc<'static, str>, LabeledAsset>,
+    ) -> Self {
+        SavedAsset {
+            value,
+            labeled_assets,
+        }
+    }
+
     /// Creates a new [`SavedAsset`] from `asset` if its internal value matches `A`.
     pub fn from_loaded(asset: &'a ErasedLoadedAsset) -> Option<Self> {
         let value = asset.value.downcast_ref::<A>()?;
@@ -1,8 +1,8 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
-    Asset, AssetHandleProvider, AssetPath, DependencyLoadState, ErasedLoadedAsset, Handle,
-    InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle, UntypedAssetId,
-    UntypedHandle,
+    Asset, AssetHandleProvider, AssetPath, AssetSaveError, DependencyLoadState, ErasedLoadedAsset,
+    Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
+    UntypedAssetId, UntypedHandle,
 };
 use bevy_ecs::world::World;
 use bevy_log::warn;
@@ -67,6 +67,7 @@ pub(crate) struct AssetInfos {
     /// This should only be set at startup.
     pub(crate) watching_for_changes: bool,
     /// Tracks assets that depend on the "key" asset path inside their asset loaders ("loader dependencies")
+    /// The "key" has its label removed, as a change to the source asset affects all of its labeled assets.
     /// This should only be set when watching for changes to avoid unnecessary work.
     pub(crate) loader_dependants: HashMap<AssetPath<'static>, HashSet<AssetPath<'static>>>,
     /// Tracks living labeled assets for a given source asset.
@@ -256,6 +257,37 @@ impl AssetInfos {
         }
     }
 
+    /// Binds `path` to the existing asset `id`, replacing any path previously bound to it.
+    pub(crate) fn bind_path(
+        &mut self,
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+    ) -> Result<(), AssetSaveError> {
+        match self.path_to_id.get(&path) {
+            Some(existing) if *existing == id => return Ok(()),
+            Some(_) => return Err(AssetSaveError::PathInUse(path)),
+            None => {}
+        }
+        let info = self
+            .infos
+            .get_mut(&id)
+            .ok_or(AssetSaveError::UnmanagedAsset(id))?;
+        if let Some(old_path) = info.path.take() {
+            if self.watching_for_changes {
+                Self::remove_dependants_and_labels(
+                    info,
+                    &mut self.loader_dependants,
+                    &old_path,
+                    &mut self.living_labeled_assets,
+                );
+            }
+            self.path_to_id.remove(&old_path);
+        }
+        info.path = Some(path.clone());
+        self.path_to_id.insert(path, id);
+        Ok(())
+    }
+
     pub(crate) fn get(&self, id: UntypedAssetId) -> Option<&AssetInfo> {
         self.infos.get(&id)
     }
@@ -279,6 +311,13 @@ impl AssetInfos {
         Some(UntypedHandle::Strong(strong_handle))
     }
 
+    /// Returns `true` if any labeled asset of the asset at `path` is managed by the server.
+    pub(crate) fn has_labeled_assets(&self, path: &AssetPath) -> bool {
+        self.path_to_id
+            .keys()
+            .any(|labeled| labeled.label().is_some() && labeled.without_label() == *path)
+    }
+
     /// Returns `true` if the asset this path points to is still alive
     pub(crate) fn is_path_alive<'a>(&self, path: impl Into<AssetPath<'a>>) -> bool {
         let path = path.into();
@@ -403,7 +442,7 @@ impl AssetInfos {
                     for loader_dependency in loaded_asset.loader_dependencies.keys() {
                         let dependants = self
                             .loader_dependants
-                            .entry(loader_dependency.clone())
+                            .entry(loader_dependency.without_label().into_owned())
                             .or_default();
                         dependants.insert(asset_path.clone());
                     }
@@ -561,7 +600,9 @@ impl AssetInfos {
         living_labeled_assets: &mut HashMap<AssetPath<'static>, HashSet<String>>,
     ) {
         for loader_dependency in info.loader_dependencies.keys() {
-            if let Some(dependants) = loader_dependants.get_mut(loader_dependency) {
+            if let Some(dependants) =
+                loader_dependants.get_mut(&loader_dependency.without_label().into_owned())
+            {
                 dependants.remove(path);
             }
         }
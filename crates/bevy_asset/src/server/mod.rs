@@ -1,30 +1,37 @@
 mod info;
 
 use crate::{
+    collection::{AssetCollection, AssetCollectionContext},
     folder::LoadedFolder,
     io::{
         AssetReader, AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
-        MissingAssetSourceError, MissingProcessedAssetReaderError, Reader,
+        AssetWriterError, MissingAssetSourceError, MissingAssetWriterError,
+        MissingProcessedAssetReaderError, Reader,
     },
     loader::{AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
     meta::{
-        loader_settings_meta_transform, AssetActionMinimal, AssetMetaDyn, AssetMetaMinimal,
-        MetaTransform, Settings,
+        loader_settings_meta_transform, AssetAction, AssetActionMinimal, AssetMeta, AssetMetaDyn,
+        AssetMetaMinimal, MetaTransform, Settings, SettingsMigrations,
     },
     path::AssetPath,
+    saver::{AssetSaver, SavedAsset},
     Asset, AssetEvent, AssetHandleProvider, AssetId, AssetMetaCheck, Assets, DeserializeMetaError,
     ErasedLoadedAsset, Handle, LoadedUntypedAsset, UntypedAssetId, UntypedHandle,
 };
 use bevy_ecs::prelude::*;
 use bevy_log::{error, info, warn};
 use bevy_tasks::IoTaskPool;
-use bevy_utils::{CowArc, HashMap, HashSet};
+use bevy_utils::{BoxedFuture, CowArc, HashMap, HashSet};
 use crossbeam_channel::{Receiver, Sender};
 use futures_lite::StreamExt;
 use info::*;
 use parking_lot::RwLock;
 use std::path::PathBuf;
-use std::{any::TypeId, path::Path, sync::Arc};
+use std::{
+    any::{Any, TypeId},
+    path::Path,
+    sync::Arc,
+};
 use thiserror::Error;
 
 /// Loads and tracks the state of [`Asset`] values from a configured [`AssetReader`]. This can be used to kick off new asset loads and
@@ -52,6 +59,10 @@ pub(crate) struct AssetServerData {
     pub(crate) loaders: Arc<RwLock<AssetLoaders>>,
     asset_event_sender: Sender<InternalAssetEvent>,
     asset_event_receiver: Receiver<InternalAssetEvent>,
+    /// Registered [`AssetSaver`]s, keyed by the [`TypeId`] of the saver.
+    savers: RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+    save_request_sender: Sender<SaveRequest>,
+    save_request_receiver: Receiver<SaveRequest>,
     sources: AssetSources,
     mode: AssetServerMode,
     meta_check: AssetMetaCheck,
@@ -104,6 +115,7 @@ impl AssetServer {
         watching_for_changes: bool,
     ) -> Self {
         let (asset_event_sender, asset_event_receiver) = crossbeam_channel::unbounded();
+        let (save_request_sender, save_request_receiver) = crossbeam_channel::unbounded();
         let mut infos = AssetInfos::default();
         infos.watching_for_changes = watching_for_changes;
         Self {
@@ -113,6 +125,9 @@ impl AssetServer {
                 meta_check,
                 asset_event_sender,
                 asset_event_receiver,
+                savers: Default::default(),
+                save_request_sender,
+                save_request_receiver,
                 loaders,
                 infos: RwLock::new(infos),
             }),
@@ -165,6 +180,14 @@ impl AssetServer {
         }
     }
 
+    /// Registers a new [`AssetSaver`]. [`AssetSaver`]s must be registered before they can be used by [`AssetServer::save`].
+    pub fn register_saver<S: AssetSaver>(&self, saver: S) {
+        self.data
+            .savers
+            .write()
+            .insert(TypeId::of::<S>(), Arc::new(saver));
+    }
+
     /// Registers a new [`Asset`] type. [`Asset`] types must be registered before assets of that type can be loaded.
     pub fn register_asset<A: Asset>(&self, assets: &Assets<A>) {
         self.register_handle_provider(assets.get_handle_provider());
@@ -180,6 +203,43 @@ impl AssetServer {
             .insert(TypeId::of::<A>(), sender::<A>);
     }
 
+    /// Registers a `migrate` function that upgrades the [`AssetLoader::Settings`] of `L` stored in `.meta` files with
+    /// `from_version` (deserialized as `From`) to the settings of version `from_version + 1`. See [`AssetLoader::SETTINGS_VERSION`].
+    pub fn register_loader_settings_migration<L: AssetLoader, From, To>(
+        &self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        From: Settings + serde::de::DeserializeOwned,
+        To: Settings,
+    {
+        self.register_settings_migration(std::any::type_name::<L>(), from_version, migrate);
+    }
+
+    pub(crate) fn register_settings_migration<From, To>(
+        &self,
+        type_name: &'static str,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        From: Settings + serde::de::DeserializeOwned,
+        To: Settings,
+    {
+        self.data
+            .loaders
+            .write()
+            .settings_migrations
+            .register(type_name, from_version, migrate);
+    }
+
+    /// Runs `f` with the registered [`SettingsMigrations`].
+    pub(crate) fn with_settings_migrations<T>(
+        &self,
+        f: impl FnOnce(&SettingsMigrations) -> T,
+    ) -> T {
+        f(&self.data.loaders.read().settings_migrations)
+    }
+
     pub(crate) fn register_handle_provider(&self, handle_provider: AssetHandleProvider) {
         let mut infos = self.data.infos.write();
         infos
@@ -528,6 +588,12 @@ impl AssetServer {
     }
 
     /// Kicks off a reload of the asset stored at the given path. This will only reload the asset if it currently loaded.
+    ///
+    /// Labeled assets keep their [`AssetId`] across reloads. Labeled assets that did not change are skipped if their
+    /// type is registered with [`AssetApp::register_asset_partial_eq`], like Bevy's built-in asset types. Otherwise,
+    /// all of them are replaced and send [`AssetEvent::Modified`].
+    ///
+    /// [`AssetApp::register_asset_partial_eq`]: crate::AssetApp::register_asset_partial_eq
     pub fn reload<'a>(&self, path: impl Into<AssetPath<'a>>) {
         let server = self.clone();
         let path = path.into().into_owned();
@@ -588,6 +654,146 @@ impl AssetServer {
         handle
     }
 
+    /// Saves the current value of the asset with the given `handle` to `path`, using the registered [`AssetSaver`] `S`
+    /// (see [`AssetServer::register_saver`]) and the given `settings`. The asset is written using the [`AssetWriter`]
+    /// of the path's [`AssetSource`], alongside a `.meta` file that configures [`AssetSaver::OutputLoader`] to load it.
+    ///
+    /// Saving happens in the background: the asset is cloned the next time [`handle_internal_asset_events`] runs,
+    /// then serialized and written on the [`IoTaskPool`]. The outcome is reported as an [`AssetSaveEvent`].
+    ///
+    /// Labeled assets can't be passed to the saver, so saving an asset that has labeled assets fails with
+    /// [`AssetSaveError::LabeledAssets`].
+    ///
+    /// [`AssetWriter`]: crate::io::AssetWriter
+    pub fn save<'a, S: AssetSaver>(
+        &self,
+        handle: &Handle<S::Asset>,
+        path: impl Into<AssetPath<'a>>,
+        settings: S::Settings,
+    ) where
+        S::Asset: Clone,
+    {
+        self.save_internal::<S>(handle, path.into().into_owned(), settings, false);
+    }
+
+    /// Saves the asset with the given `handle` to `path`, just like [`AssetServer::save`]. Once the asset is saved, `path` is
+    /// bound to the asset, which means it will be returned by [`AssetServer::get_path`], future loads of `path` will return
+    /// the existing asset, and it will be reloaded when `path` changes (if the server is watching for changes).
+    ///
+    /// This requires the asset to be managed by the [`AssetServer`], such as assets that were loaded or added with
+    /// [`AssetServer::add`]. Note that [`Handle::path`] is not updated for existing handles.
+    pub fn save_as<'a, S: AssetSaver>(
+        &self,
+        handle: &Handle<S::Asset>,
+        path: impl Into<AssetPath<'a>>,
+        settings: S::Settings,
+    ) where
+        S::Asset: Clone,
+    {
+        self.save_internal::<S>(handle, path.into().into_owned(), settings, true);
+    }
+
+    fn save_internal<S: AssetSaver>(
+        &self,
+        handle: &Handle<S::Asset>,
+        path: AssetPath<'static>,
+        settings: S::Settings,
+        bind_path: bool,
+    ) where
+        S::Asset: Clone,
+    {
+        let handle = handle.clone().untyped();
+        if bind_path && !self.data.infos.read().contains_key(handle.id()) {
+            self.send_asset_event(InternalAssetEvent::Saved {
+                result: Err(AssetSaveError::UnmanagedAsset(handle.id())),
+                handle,
+                path,
+                bind_path,
+            });
+            return;
+        }
+
+        let saver = self.data.savers.read().get(&TypeId::of::<S>()).cloned();
+        let Some(saver) = saver.and_then(|saver| saver.downcast::<S>().ok()) else {
+            self.send_asset_event(InternalAssetEvent::Saved {
+                result: Err(AssetSaveError::MissingAssetSaver(std::any::type_name::<S>())),
+                handle,
+                path,
+                bind_path,
+            });
+            return;
+        };
+
+        let id = handle.id().typed::<S::Asset>();
+        let prepare = move |world: &World| -> Result<SaveFuture, AssetSaveError> {
+            let asset = world
+                .get_resource::<Assets<S::Asset>>()
+                .and_then(|assets| assets.get(id))
+                .ok_or(AssetSaveError::MissingAsset(id.untyped()))?
+                .clone();
+            Ok(Box::pin(async move {
+                let labeled_assets = HashMap::default();
+                let mut bytes = Vec::new();
+                let loader_settings = saver
+                    .save(
+                        &mut bytes,
+                        SavedAsset::new(&asset, &labeled_assets),
+                        &settings,
+                    )
+                    .await
+                    .map_err(|error| AssetSaveError::AssetSaverError {
+                        saver: std::any::type_name::<S>(),
+                        error: error.into(),
+                    })?;
+                let meta = AssetMeta::<S::OutputLoader, ()>::new(AssetAction::Load {
+                    loader: std::any::type_name::<S::OutputLoader>().to_string(),
+                    settings: loader_settings,
+                });
+                Ok((bytes, AssetMetaDyn::serialize(&meta)))
+            }))
+        };
+
+        self.data
+            .save_request_sender
+            .send(SaveRequest {
+                handle,
+                path,
+                bind_path,
+                prepare: Box::new(prepare),
+            })
+            .unwrap();
+    }
+
+    /// Serializes and writes the asset of a [`SaveRequest`] on the [`IoTaskPool`].
+    fn write_saved_asset(
+        &self,
+        handle: UntypedHandle,
+        path: AssetPath<'static>,
+        bind_path: bool,
+        save: SaveFuture,
+    ) {
+        let server = self.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let result = async {
+                    let (bytes, meta_bytes) = save.await?;
+                    let source = server.get_source(path.source())?;
+                    let writer = source.writer()?;
+                    writer.write_bytes(path.path(), &bytes).await?;
+                    writer.write_meta_bytes(path.path(), &meta_bytes).await?;
+                    Ok(())
+                }
+                .await;
+                server.send_asset_event(InternalAssetEvent::Saved {
+                    handle,
+                    path,
+                    bind_path,
+                    result,
+                });
+            })
+            .detach();
+    }
+
     /// Loads all assets from the specified folder recursively. The [`LoadedFolder`] asset (when it loads) will
     /// contain handles to all assets in the folder. You can wait for all assets to load by checking the [`LoadedFolder`]'s
     /// [`RecursiveDependencyLoadState`].
@@ -617,6 +823,44 @@ impl AssetServer {
         handle
     }
 
+    /// Loads the [`AssetCollection`] `C` using the default paths of its fields. The returned handle only reaches
+    /// [`RecursiveDependencyLoadState::Loaded`] once every asset in the collection is loaded.
+    ///
+    /// To load a collection from a manifest file instead, register an [`AssetCollectionLoader`] and use [`AssetServer::load`].
+    ///
+    /// [`AssetCollectionLoader`]: crate::AssetCollectionLoader
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_collection<C: AssetCollection>(&self) -> Handle<C> {
+        let handle = self
+            .data
+            .infos
+            .write()
+            .create_loading_handle_untyped(TypeId::of::<C>(), std::any::type_name::<C>())
+            .typed_debug_checked();
+        let id = handle.id().untyped();
+        let server = self.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let context = AssetCollectionContext::new(&server);
+                match C::load_collection(&context).await {
+                    Ok(collection) => server.send_asset_event(InternalAssetEvent::Loaded {
+                        id,
+                        loaded_asset: LoadedAsset::new_with_dependencies(collection, None).into(),
+                    }),
+                    Err(err) => {
+                        error!(
+                            "Failed to load asset collection {}: {err}",
+                            std::any::type_name::<C>()
+                        );
+                        server.send_asset_event(InternalAssetEvent::Failed { id });
+                    }
+                }
+            })
+            .detach();
+
+        handle
+    }
+
     pub(crate) fn load_folder_internal(&self, id: UntypedAssetId, path: AssetPath) {
         fn load_folder<'a>(
             source: AssetSourceId<'static>,
@@ -891,12 +1135,14 @@ impl AssetServer {
                         }
                     };
                     let loader = self.get_asset_loader_with_type_name(&loader_name).await?;
-                    let meta = loader.deserialize_meta(&meta_bytes).map_err(|e| {
-                        AssetLoadError::DeserializeMeta {
+                    let meta = self
+                        .with_settings_migrations(|migrations| {
+                            loader.deserialize_meta(&meta_bytes, migrations)
+                        })
+                        .map_err(|e| AssetLoadError::DeserializeMeta {
                             path: asset_path.clone_owned(),
                             error: Box::new(e),
-                        }
-                    })?;
+                        })?;
 
                     Ok((meta, loader, reader))
                 }
@@ -940,6 +1186,38 @@ impl AssetServer {
 /// A system that manages internal [`AssetServer`] events, such as finalizing asset loads.
 pub fn handle_internal_asset_events(world: &mut World) {
     world.resource_scope(|world, server: Mut<AssetServer>| {
+        for request in server.data.save_request_receiver.try_iter() {
+            let SaveRequest {
+                handle,
+                path,
+                bind_path,
+                prepare,
+            } = request;
+            // Labeled assets only exist in their own `Assets` collections once loaded, so they
+            // can't be handed to the saver and would be missing from the saved file.
+            let labeled = {
+                let infos = server.data.infos.read();
+                infos
+                    .get(handle.id())
+                    .and_then(|info| info.path.clone())
+                    .filter(|asset_path| infos.has_labeled_assets(asset_path))
+            };
+            let result = match labeled {
+                Some(asset_path) => Err(AssetSaveError::LabeledAssets(asset_path)),
+                None => prepare(world),
+            };
+            match result {
+                Ok(save) => server.write_saved_asset(handle, path, bind_path, save),
+                Err(error) => {
+                    world.send_event(AssetSaveEvent::Failed {
+                        id: handle.id(),
+                        path,
+                        error,
+                    });
+                }
+            }
+        }
+
         let mut infos = server.data.infos.write();
         for event in server.data.asset_event_receiver.try_iter() {
             match event {
@@ -959,6 +1237,25 @@ pub fn handle_internal_asset_events(world: &mut World) {
                     sender(world, id);
                 }
                 InternalAssetEvent::Failed { id } => infos.process_asset_fail(id),
+                InternalAssetEvent::Saved {
+                    handle,
+                    path,
+                    bind_path,
+                    result,
+                } => {
+                    let id = handle.id();
+                    let result = result.and_then(|_| {
+                        if bind_path {
+                            infos.bind_path(id, path.clone())
+                        } else {
+                            Ok(())
+                        }
+                    });
+                    match result {
+                        Ok(()) => world.send_event(AssetSaveEvent::Saved { id, path }),
+                        Err(error) => world.send_event(AssetSaveEvent::Failed { id, path, error }),
+                    };
+                }
             }
         }
 
@@ -967,9 +1264,12 @@ pub fn handle_internal_asset_events(world: &mut World) {
             infos: &AssetInfos,
             paths_to_reload: &mut HashSet<AssetPath<'static>>,
         ) {
-            if let Some(dependants) = infos.loader_dependants.get(asset_path) {
-                for dependant in dependants {
-                    paths_to_reload.insert(dependant.to_owned());
+            // Loader dependants are tracked by source asset, which includes dependants of its labeled assets
+            let Some(dependants) = infos.loader_dependants.get(&asset_path.without_label()) else {
+                return;
+            };
+            for dependant in dependants {
+                if paths_to_reload.insert(dependant.to_owned()) {
                     queue_ancestors(dependant, infos, paths_to_reload);
                 }
             }
@@ -1043,6 +1343,8 @@ pub(crate) struct AssetLoaders {
     extension_to_index: HashMap<String, usize>,
     type_name_to_index: HashMap<&'static str, usize>,
     preregistered_loaders: HashMap<&'static str, usize>,
+    /// Settings migrations for registered [`AssetLoader`]s and asset processors.
+    settings_migrations: SettingsMigrations,
 }
 
 #[derive(Clone)]
@@ -1067,6 +1369,41 @@ pub(crate) enum InternalAssetEvent {
     Failed {
         id: UntypedAssetId,
     },
+    Saved {
+        handle: UntypedHandle,
+        path: AssetPath<'static>,
+        bind_path: bool,
+        result: Result<(), AssetSaveError>,
+    },
+}
+
+/// A request to save an asset, queued by [`AssetServer::save`] and handled by [`handle_internal_asset_events`].
+struct SaveRequest {
+    /// Keeps the asset alive until it has been saved.
+    handle: UntypedHandle,
+    path: AssetPath<'static>,
+    bind_path: bool,
+    /// Clones the asset out of the [`World`] and returns the future serializing it.
+    prepare: Box<dyn FnOnce(&World) -> Result<SaveFuture, AssetSaveError> + Send + Sync>,
+}
+
+/// Serializes an asset to its asset and meta bytes.
+type SaveFuture = BoxedFuture<'static, Result<(Vec<u8>, Vec<u8>), AssetSaveError>>;
+
+/// Sent when an asset save started with [`AssetServer::save`] or [`AssetServer::save_as`] finishes.
+#[derive(Event, Debug)]
+pub enum AssetSaveEvent {
+    /// The asset was written to `path`.
+    Saved {
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+    },
+    /// The asset could not be saved to `path`.
+    Failed {
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+        error: AssetSaveError,
+    },
 }
 
 /// The load state of an asset.
@@ -1157,6 +1494,32 @@ pub enum AssetLoadError {
     },
 }
 
+/// An error that occurs while saving an asset with [`AssetServer::save`] or [`AssetServer::save_as`].
+#[derive(Error, Debug)]
+pub enum AssetSaveError {
+    #[error("no `AssetSaver` registered with the name '{0}'")]
+    MissingAssetSaver(&'static str),
+    #[error("asset {0:?} does not exist")]
+    MissingAsset(UntypedAssetId),
+    #[error("asset {0:?} is not managed by the `AssetServer`, so a path cannot be bound to it")]
+    UnmanagedAsset(UntypedAssetId),
+    #[error("path '{0}' is already bound to a different asset")]
+    PathInUse(AssetPath<'static>),
+    #[error("asset '{0}' has labeled assets, which cannot be saved")]
+    LabeledAssets(AssetPath<'static>),
+    #[error("failed to save asset with `{saver}`: {error}")]
+    AssetSaverError {
+        saver: &'static str,
+        error: Box<dyn std::error::Error + Send + Sync + 'static>,
+    },
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    #[error(transparent)]
+    MissingAssetWriter(#[from] MissingAssetWriterError),
+    #[error(transparent)]
+    AssetWriterError(#[from] AssetWriterError),
+}
+
 /// An error that occurs when an [`AssetLoader`] is not registered for a given extension.
 #[derive(Error, Debug)]
 #[error("no `AssetLoader` found{}", format_missing_asset_ext(.extensions))]
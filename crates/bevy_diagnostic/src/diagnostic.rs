@@ -1,24 +1,94 @@
 use bevy_app::App;
 use bevy_ecs::system::{Deferred, Res, Resource, SystemBuffer, SystemParam};
-use bevy_log::warn;
-use bevy_utils::{Duration, Instant, StableHashMap, Uuid};
-use std::{borrow::Cow, collections::VecDeque};
+use bevy_utils::{Duration, Instant, StableHashMap};
+use std::{borrow::Cow, collections::VecDeque, fmt};
+
+/// Unique path of a [`Diagnostic`], made of components separated by `/`, such as
+/// `render/frame_time`.
+///
+/// Paths group related diagnostics, and let exporters and filters select them by prefix.
+///
+/// A path can't be empty, start or end with a `/`, or contain empty components.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DiagnosticPath {
+    path: Cow<'static, str>,
+}
+
+impl DiagnosticPath {
+    /// The separator between the components of a path.
+    pub const SEPARATOR: char = '/';
 
-use crate::MAX_DIAGNOSTIC_NAME_WIDTH;
+    /// Creates a path from a static string, in a `const` context.
+    ///
+    /// Unlike [`new`](Self::new), the path isn't validated.
+    pub const fn const_new(path: &'static str) -> DiagnosticPath {
+        DiagnosticPath {
+            path: Cow::Borrowed(path),
+        }
+    }
+
+    /// Creates a path from a string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is empty, starts or ends with a `/`, or contains an empty component.
+    pub fn new(path: impl Into<Cow<'static, str>>) -> DiagnosticPath {
+        let path = path.into();
+        assert!(!path.is_empty(), "diagnostic path can't be empty");
+        assert!(
+            !path.starts_with(Self::SEPARATOR),
+            "diagnostic path can't start with `{}`: {path:?}",
+            Self::SEPARATOR,
+        );
+        assert!(
+            !path.ends_with(Self::SEPARATOR),
+            "diagnostic path can't end with `{}`: {path:?}",
+            Self::SEPARATOR,
+        );
+        assert!(
+            !path.split(Self::SEPARATOR).any(str::is_empty),
+            "diagnostic path can't contain empty components: {path:?}",
+        );
+        DiagnosticPath { path }
+    }
+
+    /// Creates a path by joining `components` with `/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting path is invalid, see [`new`](Self::new).
+    pub fn from_components<'a>(components: impl IntoIterator<Item = &'a str>) -> DiagnosticPath {
+        let components = components.into_iter().collect::<Vec<_>>();
+        DiagnosticPath::new(components.join("/"))
+    }
+
+    /// Returns the full path.
+    pub fn as_str(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the components of the path.
+    pub fn components(&self) -> impl Iterator<Item = &str> + '_ {
+        self.path.split(Self::SEPARATOR)
+    }
 
-/// Unique identifier for a [`Diagnostic`].
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
-pub struct DiagnosticId(pub Uuid);
+    /// Returns whether this path is `prefix`, or is nested under it.
+    pub fn starts_with(&self, prefix: &DiagnosticPath) -> bool {
+        self.path
+            .strip_prefix(prefix.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(Self::SEPARATOR))
+    }
+}
 
-impl DiagnosticId {
-    pub const fn from_u128(value: u128) -> Self {
-        DiagnosticId(Uuid::from_u128(value))
+impl From<DiagnosticPath> for String {
+    fn from(path: DiagnosticPath) -> Self {
+        path.path.into()
     }
 }
 
-impl Default for DiagnosticId {
-    fn default() -> Self {
-        DiagnosticId(Uuid::new_v4())
+impl fmt::Display for DiagnosticPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.path.fmt(f)
     }
 }
 
@@ -33,8 +103,7 @@ pub struct DiagnosticMeasurement {
 /// Diagnostic examples: frames per second, CPU usage, network latency
 #[derive(Debug)]
 pub struct Diagnostic {
-    pub id: DiagnosticId,
-    pub name: Cow<'static, str>,
+    path: DiagnosticPath,
     pub suffix: Cow<'static, str>,
     history: VecDeque<DiagnosticMeasurement>,
     sum: f64,
@@ -71,27 +140,16 @@ impl Diagnostic {
         self.history.push_back(measurement);
     }
 
-    /// Create a new diagnostic with the given ID, name and maximum history.
-    pub fn new(
-        id: DiagnosticId,
-        name: impl Into<Cow<'static, str>>,
-        max_history_length: usize,
-    ) -> Diagnostic {
-        let name = name.into();
-        if name.chars().count() > MAX_DIAGNOSTIC_NAME_WIDTH {
-            // This could be a false positive due to a unicode width being shorter
-            warn!(
-                "Diagnostic {:?} has name longer than {} characters, and so might overflow in the LogDiagnosticsPlugin\
-                Consider using a shorter name.",
-                name, MAX_DIAGNOSTIC_NAME_WIDTH
-            );
-        }
+    /// The maximum history length of a new diagnostic.
+    pub const DEFAULT_MAX_HISTORY_LENGTH: usize = 120;
+
+    /// Create a new diagnostic with the given path.
+    pub fn new(path: DiagnosticPath) -> Diagnostic {
         Diagnostic {
-            id,
-            name,
+            path,
             suffix: Cow::Borrowed(""),
-            history: VecDeque::with_capacity(max_history_length),
-            max_history_length,
+            history: VecDeque::with_capacity(Self::DEFAULT_MAX_HISTORY_LENGTH),
+            max_history_length: Self::DEFAULT_MAX_HISTORY_LENGTH,
             sum: 0.0,
             ema: 0.0,
             ema_smoothing_factor: 2.0 / 21.0,
@@ -99,6 +157,19 @@ impl Diagnostic {
         }
     }
 
+    /// Set the maximum number of measurements kept in the history.
+    #[must_use]
+    pub fn with_max_history_length(mut self, max_history_length: usize) -> Self {
+        self.max_history_length = max_history_length;
+        self.history.reserve(max_history_length);
+        self
+    }
+
+    /// Get the path of this diagnostic.
+    pub fn path(&self) -> &DiagnosticPath {
+        &self.path
+    }
+
     /// Add a suffix to use when logging the value, can be used to show a unit.
     #[must_use]
     pub fn with_suffix(mut self, suffix: impl Into<Cow<'static, str>>) -> Self {
@@ -156,6 +227,64 @@ impl Diagnostic {
         }
     }
 
+    /// Return the value below which `percentile` percent of the values of the history fall,
+    /// interpolating linearly between the closest values.
+    ///
+    /// `percentile` is clamped to `0.0..=100.0`, and [`NaN`](f64::NAN) values are ignored.
+    pub fn percentile(&self, percentile: f64) -> Option<f64> {
+        let values = self.sorted_values();
+        let rank = percentile.clamp(0.0, 100.0) / 100.0 * (values.len().checked_sub(1)? as f64);
+        let (lower, upper) = (values[rank.floor() as usize], values[rank.ceil() as usize]);
+        Some(lower + (upper - lower) * rank.fract())
+    }
+
+    /// Return the smallest value of the history.
+    pub fn min(&self) -> Option<f64> {
+        self.values()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .reduce(f64::min)
+    }
+
+    /// Return the largest value of the history.
+    pub fn max(&self) -> Option<f64> {
+        self.values()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .reduce(f64::max)
+    }
+
+    /// Return a [`DiagnosticHistogram`] of the values of the history, split into `bins` bins of
+    /// the same width between the smallest and largest values.
+    ///
+    /// Returns `None` if the history is empty or `bins` is 0.
+    pub fn histogram(&self, bins: usize) -> Option<DiagnosticHistogram> {
+        if bins == 0 {
+            return None;
+        }
+        let (min, max) = (self.min()?, self.max()?);
+        let mut counts = vec![0; bins];
+        for value in self.values().filter(|value| !value.is_nan()) {
+            let bin = if max > min {
+                ((value - min) / (max - min) * bins as f64) as usize
+            } else {
+                0
+            };
+            counts[bin.min(bins - 1)] += 1;
+        }
+        Some(DiagnosticHistogram { min, max, counts })
+    }
+
+    fn sorted_values(&self) -> Vec<f64> {
+        let mut values = self
+            .values()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .collect::<Vec<_>>();
+        values.sort_by(f64::total_cmp);
+        values
+    }
+
     /// Return the number of elements for this diagnostic.
     pub fn history_len(&self) -> usize {
         self.history.len()
@@ -195,12 +324,39 @@ impl Diagnostic {
     }
 }
 
+/// The distribution of the values of a [`Diagnostic`], see [`Diagnostic::histogram`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticHistogram {
+    /// The smallest value, the start of the first bin.
+    pub min: f64,
+    /// The largest value, the end of the last bin.
+    pub max: f64,
+    /// The number of values in each bin. The last bin includes [`max`](Self::max).
+    pub counts: Vec<usize>,
+}
+
+impl DiagnosticHistogram {
+    /// Return the width of each bin.
+    pub fn bin_width(&self) -> f64 {
+        (self.max - self.min) / self.counts.len() as f64
+    }
+
+    /// Return the start, end and count of each bin.
+    pub fn bins(&self) -> impl Iterator<Item = (f64, f64, usize)> + '_ {
+        let width = self.bin_width();
+        self.counts.iter().enumerate().map(move |(index, count)| {
+            let start = self.min + width * index as f64;
+            (start, start + width, *count)
+        })
+    }
+}
+
 /// A collection of [`Diagnostic`]s.
 #[derive(Debug, Default, Resource)]
 pub struct DiagnosticsStore {
     // This uses a [`StableHashMap`] to ensure that the iteration order is deterministic between
     // runs when all diagnostics are inserted in the same order.
-    diagnostics: StableHashMap<DiagnosticId, Diagnostic>,
+    diagnostics: StableHashMap<DiagnosticPath, Diagnostic>,
 }
 
 impl DiagnosticsStore {
@@ -208,21 +364,21 @@ impl DiagnosticsStore {
     ///
     /// If possible, prefer calling [`App::register_diagnostic`].
     pub fn add(&mut self, diagnostic: Diagnostic) {
-        self.diagnostics.insert(diagnostic.id, diagnostic);
+        self.diagnostics.insert(diagnostic.path.clone(), diagnostic);
     }
 
-    pub fn get(&self, id: DiagnosticId) -> Option<&Diagnostic> {
-        self.diagnostics.get(&id)
+    pub fn get(&self, path: &DiagnosticPath) -> Option<&Diagnostic> {
+        self.diagnostics.get(path)
     }
 
-    pub fn get_mut(&mut self, id: DiagnosticId) -> Option<&mut Diagnostic> {
-        self.diagnostics.get_mut(&id)
+    pub fn get_mut(&mut self, path: &DiagnosticPath) -> Option<&mut Diagnostic> {
+        self.diagnostics.get_mut(path)
     }
 
     /// Get the latest [`DiagnosticMeasurement`] from an enabled [`Diagnostic`].
-    pub fn get_measurement(&self, id: DiagnosticId) -> Option<&DiagnosticMeasurement> {
+    pub fn get_measurement(&self, path: &DiagnosticPath) -> Option<&DiagnosticMeasurement> {
         self.diagnostics
-            .get(&id)
+            .get(path)
             .filter(|diagnostic| diagnostic.is_enabled)
             .and_then(|diagnostic| diagnostic.measurement())
     }
@@ -232,6 +388,15 @@ impl DiagnosticsStore {
         self.diagnostics.values()
     }
 
+    /// Return an iterator over the [`Diagnostic`]s whose path is `prefix` or is nested under it.
+    pub fn iter_prefix<'a>(
+        &'a self,
+        prefix: &'a DiagnosticPath,
+    ) -> impl Iterator<Item = &'a Diagnostic> + 'a {
+        self.iter()
+            .filter(move |diagnostic| diagnostic.path.starts_with(prefix))
+    }
+
     /// Return an iterator over all [`Diagnostic`]s, by mutable reference.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Diagnostic> {
         self.diagnostics.values_mut()
@@ -249,13 +414,13 @@ impl<'w, 's> Diagnostics<'w, 's> {
     /// Add a measurement to an enabled [`Diagnostic`]. The measurement is passed as a function so that
     /// it will be evaluated only if the [`Diagnostic`] is enabled. This can be useful if the value is
     /// costly to calculate.
-    pub fn add_measurement<F>(&mut self, id: DiagnosticId, value: F)
+    pub fn add_measurement<F>(&mut self, path: &DiagnosticPath, value: F)
     where
         F: FnOnce() -> f64,
     {
         if self
             .store
-            .get(id)
+            .get(path)
             .filter(|diagnostic| diagnostic.is_enabled)
             .is_some()
         {
@@ -263,13 +428,13 @@ impl<'w, 's> Diagnostics<'w, 's> {
                 time: Instant::now(),
                 value: value(),
             };
-            self.queue.0.insert(id, measurement);
+            self.queue.0.insert(path.clone(), measurement);
         }
     }
 }
 
 #[derive(Default)]
-struct DiagnosticsBuffer(StableHashMap<DiagnosticId, DiagnosticMeasurement>);
+struct DiagnosticsBuffer(StableHashMap<DiagnosticPath, DiagnosticMeasurement>);
 
 impl SystemBuffer for DiagnosticsBuffer {
     fn apply(
@@ -278,8 +443,8 @@ impl SystemBuffer for DiagnosticsBuffer {
         world: &mut bevy_ecs::world::World,
     ) {
         let mut diagnostics = world.resource_mut::<DiagnosticsStore>();
-        for (id, measurement) in self.0.drain() {
-            if let Some(diagnostic) = diagnostics.get_mut(id) {
+        for (path, measurement) in self.0.drain() {
+            if let Some(diagnostic) = diagnostics.get_mut(&path) {
                 diagnostic.add_measurement(measurement);
             }
         }
@@ -298,12 +463,12 @@ impl RegisterDiagnostic for App {
     ///
     /// ```
     /// use bevy_app::App;
-    /// use bevy_diagnostic::{Diagnostic, DiagnosticsPlugin, DiagnosticId, RegisterDiagnostic};
+    /// use bevy_diagnostic::{Diagnostic, DiagnosticsPlugin, DiagnosticPath, RegisterDiagnostic};
     ///
-    /// const UNIQUE_DIAG_ID: DiagnosticId = DiagnosticId::from_u128(42);
+    /// const UNIQUE_DIAG_PATH: DiagnosticPath = DiagnosticPath::const_new("foo/bar");
     ///
     /// App::new()
-    ///     .register_diagnostic(Diagnostic::new(UNIQUE_DIAG_ID, "example", 10))
+    ///     .register_diagnostic(Diagnostic::new(UNIQUE_DIAG_PATH).with_max_history_length(10))
     ///     .add_plugins(DiagnosticsPlugin)
     ///     .run();
     /// ```
@@ -315,3 +480,89 @@ impl RegisterDiagnostic for App {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Diagnostic, DiagnosticHistogram, DiagnosticMeasurement, DiagnosticPath};
+    use bevy_utils::Instant;
+
+    fn diagnostic(values: &[f64]) -> Diagnostic {
+        let mut diagnostic = Diagnostic::new(DiagnosticPath::const_new("test"));
+        for &value in values {
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time: Instant::now(),
+                value,
+            });
+        }
+        diagnostic
+    }
+
+    #[test]
+    fn path_components() {
+        let path = DiagnosticPath::new("render/frame_time");
+        assert_eq!(
+            vec!["render", "frame_time"],
+            path.components().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            path,
+            DiagnosticPath::from_components(["render", "frame_time"])
+        );
+        assert_eq!("render/frame_time", path.to_string());
+
+        assert!(path.starts_with(&DiagnosticPath::new("render")));
+        assert!(path.starts_with(&path));
+        assert!(!path.starts_with(&DiagnosticPath::new("rend")));
+    }
+
+    #[test]
+    #[should_panic = "diagnostic path can't contain empty components"]
+    fn path_with_empty_component() {
+        DiagnosticPath::new("render//frame_time");
+    }
+
+    #[test]
+    #[should_panic = "diagnostic path can't end with `/`"]
+    fn path_with_trailing_separator() {
+        DiagnosticPath::new("render/");
+    }
+
+    #[test]
+    fn percentiles() {
+        let diagnostic = diagnostic(&[4.0, 1.0, 3.0, 2.0, 5.0]);
+        assert_eq!(Some(1.0), diagnostic.min());
+        assert_eq!(Some(5.0), diagnostic.max());
+        assert_eq!(Some(1.0), diagnostic.percentile(0.0));
+        assert_eq!(Some(3.0), diagnostic.percentile(50.0));
+        assert_eq!(Some(3.5), diagnostic.percentile(62.5));
+        assert_eq!(Some(5.0), diagnostic.percentile(100.0));
+        assert_eq!(None, self::diagnostic(&[]).percentile(50.0));
+    }
+
+    #[test]
+    fn histogram() {
+        let diagnostic = diagnostic(&[0.0, 1.0, 2.5, 3.0, 4.0]);
+        let histogram = diagnostic.histogram(4).unwrap();
+        assert_eq!(
+            DiagnosticHistogram {
+                min: 0.0,
+                max: 4.0,
+                counts: vec![1, 1, 1, 2],
+            },
+            histogram
+        );
+        assert_eq!(
+            vec![(0.0, 1.0, 1), (1.0, 2.0, 1), (2.0, 3.0, 1), (3.0, 4.0, 2)],
+            histogram.bins().collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![3],
+            self::diagnostic(&[2.0, 2.0, 2.0])
+                .histogram(1)
+                .unwrap()
+                .counts
+        );
+        assert_eq!(None, diagnostic.histogram(0));
+    }
+}
@@ -1,9 +1,9 @@
 use bevy_app::prelude::*;
 use bevy_ecs::entity::Entities;
 
-use crate::{Diagnostic, DiagnosticId, Diagnostics, RegisterDiagnostic};
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
 
-/// Adds "entity count" diagnostic to an App.
+/// Adds "entity count" diagnostic to an App, grouped under the `entity_count` path.
 ///
 /// # See also
 ///
@@ -13,16 +13,15 @@ pub struct EntityCountDiagnosticsPlugin;
 
 impl Plugin for EntityCountDiagnosticsPlugin {
     fn build(&self, app: &mut App) {
-        app.register_diagnostic(Diagnostic::new(Self::ENTITY_COUNT, "entity_count", 20))
+        app.register_diagnostic(Diagnostic::new(Self::ENTITY_COUNT).with_max_history_length(20))
             .add_systems(Update, Self::diagnostic_system);
     }
 }
 
 impl EntityCountDiagnosticsPlugin {
-    pub const ENTITY_COUNT: DiagnosticId =
-        DiagnosticId::from_u128(187513512115068938494459732780662867798);
+    pub const ENTITY_COUNT: DiagnosticPath = DiagnosticPath::const_new("entity_count/entities");
 
     pub fn diagnostic_system(mut diagnostics: Diagnostics, entities: &Entities) {
-        diagnostics.add_measurement(Self::ENTITY_COUNT, || entities.len() as f64);
+        diagnostics.add_measurement(&Self::ENTITY_COUNT, || entities.len() as f64);
     }
 }
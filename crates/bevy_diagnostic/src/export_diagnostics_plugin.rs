@@ -0,0 +1,315 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use super::{Diagnostic, DiagnosticPath, DiagnosticsStore};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_log::error;
+
+/// An App Plugin that writes diagnostics to a file every few frames, to track performance
+/// across runs, for example in CI.
+///
+/// Each export writes one record per enabled diagnostic, with its latest value and statistics
+/// over its history, see [`DiagnosticsExportFormat`]. The file is overwritten when the app starts.
+pub struct ExportDiagnosticsPlugin {
+    /// The file to write diagnostics to.
+    pub path: PathBuf,
+    pub format: DiagnosticsExportFormat,
+    /// How many frames to wait between exports.
+    pub frame_interval: u32,
+    /// Only export the diagnostics with these paths, or nested under them.
+    pub filter: Option<Vec<DiagnosticPath>>,
+}
+
+impl Default for ExportDiagnosticsPlugin {
+    fn default() -> Self {
+        ExportDiagnosticsPlugin {
+            path: PathBuf::from("diagnostics.csv"),
+            format: DiagnosticsExportFormat::Csv,
+            frame_interval: 60,
+            filter: None,
+        }
+    }
+}
+
+/// The file formats of the [`ExportDiagnosticsPlugin`].
+///
+/// Both formats write a record per diagnostic with the fields `frame`, `path`, `value`,
+/// `average`, `smoothed`, `min`, `p50`, `p95`, `p99` and `max`. Missing values are left empty in
+/// CSV, and are `null` in JSON lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagnosticsExportFormat {
+    /// Comma-separated values, starting with a header line.
+    #[default]
+    Csv,
+    /// A JSON object per line.
+    JsonLines,
+}
+
+/// The fields of an exported record, after `frame` and `path`.
+const FIELDS: [&str; 8] = [
+    "value", "average", "smoothed", "min", "p50", "p95", "p99", "max",
+];
+
+impl DiagnosticsExportFormat {
+    /// Writes the beginning of a new file, if the format has one.
+    pub fn write_header(&self, writer: &mut impl Write) -> io::Result<()> {
+        match self {
+            DiagnosticsExportFormat::Csv => writeln!(writer, "frame,path,{}", FIELDS.join(",")),
+            DiagnosticsExportFormat::JsonLines => Ok(()),
+        }
+    }
+
+    /// Writes the record of `diagnostic` for `frame`.
+    pub fn write_diagnostic(
+        &self,
+        writer: &mut impl Write,
+        frame: u32,
+        diagnostic: &Diagnostic,
+    ) -> io::Result<()> {
+        let values = [
+            diagnostic.value(),
+            diagnostic.average(),
+            diagnostic.smoothed(),
+            diagnostic.min(),
+            diagnostic.percentile(50.0),
+            diagnostic.percentile(95.0),
+            diagnostic.percentile(99.0),
+            diagnostic.max(),
+        ];
+        match self {
+            DiagnosticsExportFormat::Csv => {
+                // Paths can't contain quotes or line breaks in practice, but commas are quoted.
+                let path = diagnostic.path().as_str();
+                if path.contains([',', '"']) {
+                    write!(writer, "{frame},\"{}\"", path.replace('"', "\"\""))?;
+                } else {
+                    write!(writer, "{frame},{path}")?;
+                }
+                for value in values {
+                    match value {
+                        Some(value) => write!(writer, ",{value}")?,
+                        None => write!(writer, ",")?,
+                    }
+                }
+                writeln!(writer)
+            }
+            DiagnosticsExportFormat::JsonLines => {
+                write!(writer, "{{\"frame\":{frame},\"path\":")?;
+                write_json_string(writer, diagnostic.path().as_str())?;
+                for (field, value) in FIELDS.into_iter().zip(values) {
+                    match value.filter(|value| value.is_finite()) {
+                        Some(value) => write!(writer, ",\"{field}\":{value}")?,
+                        None => write!(writer, ",\"{field}\":null")?,
+                    }
+                }
+                writeln!(writer, "}}")
+            }
+        }
+    }
+}
+
+fn write_json_string(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    write!(writer, "\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => write!(writer, "\\\"")?,
+            '\\' => write!(writer, "\\\\")?,
+            c if c.is_control() => write!(writer, "\\u{:04x}", c as u32)?,
+            c => write!(writer, "{c}")?,
+        }
+    }
+    write!(writer, "\"")
+}
+
+/// State used by the [`ExportDiagnosticsPlugin`]
+#[derive(Resource)]
+struct ExportDiagnosticsState {
+    writer: Option<BufWriter<File>>,
+    path: PathBuf,
+    format: DiagnosticsExportFormat,
+    frame_interval: u32,
+    filter: Option<Vec<DiagnosticPath>>,
+    frame: u32,
+    frames_since_export: u32,
+}
+
+impl Plugin for ExportDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let writer = File::create(&self.path)
+            .map(BufWriter::new)
+            .and_then(|mut writer| {
+                self.format.write_header(&mut writer)?;
+                Ok(writer)
+            })
+            .map_err(|err| {
+                error!(
+                    "Failed to create diagnostics export file {}: {err}",
+                    self.path.display()
+                );
+            })
+            .ok();
+
+        app.insert_resource(ExportDiagnosticsState {
+            writer,
+            path: self.path.clone(),
+            format: self.format,
+            frame_interval: self.frame_interval.max(1),
+            filter: self.filter.clone(),
+            frame: 0,
+            frames_since_export: 0,
+        })
+        .add_systems(PostUpdate, Self::export_diagnostics_system);
+    }
+}
+
+impl ExportDiagnosticsPlugin {
+    fn export_diagnostics_system(
+        mut state: ResMut<ExportDiagnosticsState>,
+        diagnostics: Res<DiagnosticsStore>,
+    ) {
+        state.frame += 1;
+        state.frames_since_export += 1;
+        if state.frames_since_export < state.frame_interval {
+            return;
+        }
+        state.frames_since_export = 0;
+
+        let ExportDiagnosticsState {
+            writer: Some(writer),
+            format,
+            filter,
+            frame,
+            ..
+        } = &mut *state
+        else {
+            return;
+        };
+        let result = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.is_enabled)
+            .filter(|diagnostic| match filter {
+                Some(filter) => filter
+                    .iter()
+                    .any(|path| diagnostic.path().starts_with(path)),
+                None => true,
+            })
+            .try_for_each(|diagnostic| format.write_diagnostic(writer, *frame, diagnostic))
+            .and_then(|()| writer.flush());
+
+        if let Err(err) = result {
+            error!(
+                "Failed to export diagnostics to {}, stopping: {err}",
+                state.path.display()
+            );
+            state.writer = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiagnosticsExportFormat, ExportDiagnosticsPlugin};
+    use crate::{
+        Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+    };
+    use bevy_app::App;
+    use bevy_utils::{Duration, Instant};
+
+    const LATENCY: DiagnosticPath = DiagnosticPath::const_new("net/latency");
+    const EMPTY: DiagnosticPath = DiagnosticPath::const_new("net/empty");
+
+    /// Measurements are a second apart, so the smoothed value is the latest value.
+    fn diagnostic(path: DiagnosticPath, values: &[f64]) -> Diagnostic {
+        let start = Instant::now();
+        let mut diagnostic = Diagnostic::new(path);
+        for (seconds, &value) in values.iter().enumerate() {
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time: start + Duration::from_secs(seconds as u64),
+                value,
+            });
+        }
+        diagnostic
+    }
+
+    #[test]
+    fn write_csv() {
+        let mut csv = Vec::new();
+        let format = DiagnosticsExportFormat::Csv;
+        format.write_header(&mut csv).unwrap();
+        format
+            .write_diagnostic(&mut csv, 3, &diagnostic(LATENCY, &[4.0, 2.0]))
+            .unwrap();
+        format
+            .write_diagnostic(&mut csv, 3, &diagnostic(EMPTY, &[]))
+            .unwrap();
+        assert_eq!(
+            "frame,path,value,average,smoothed,min,p50,p95,p99,max\n\
+             3,net/latency,2,3,2,2,3,3.9,3.98,4\n\
+             3,net/empty,,,,,,,,\n",
+            String::from_utf8(csv).unwrap()
+        );
+    }
+
+    #[test]
+    fn write_json_lines() {
+        let mut json = Vec::new();
+        let format = DiagnosticsExportFormat::JsonLines;
+        format.write_header(&mut json).unwrap();
+        format
+            .write_diagnostic(&mut json, 1, &diagnostic(LATENCY, &[4.0]))
+            .unwrap();
+        format
+            .write_diagnostic(&mut json, 1, &diagnostic(EMPTY, &[]))
+            .unwrap();
+        assert_eq!(
+            "{\"frame\":1,\"path\":\"net/latency\",\"value\":4,\"average\":4,\"smoothed\":4,\"min\":4,\"p50\":4,\"p95\":4,\"p99\":4,\"max\":4}\n\
+             {\"frame\":1,\"path\":\"net/empty\",\"value\":null,\"average\":null,\"smoothed\":null,\"min\":null,\"p50\":null,\"p95\":null,\"p99\":null,\"max\":null}\n",
+            String::from_utf8(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn export_every_interval() {
+        let path = std::env::temp_dir().join(format!(
+            "bevy_export_diagnostics_{}.csv",
+            std::process::id()
+        ));
+        let mut app = App::new();
+        app.register_diagnostic(Diagnostic::new(LATENCY))
+            .register_diagnostic(Diagnostic::new(DiagnosticPath::const_new("other")))
+            .add_plugins(ExportDiagnosticsPlugin {
+                path: path.clone(),
+                frame_interval: 2,
+                filter: Some(vec![DiagnosticPath::new("net")]),
+                ..Default::default()
+            });
+
+        for value in 1..=4 {
+            let mut store = app.world.resource_mut::<DiagnosticsStore>();
+            store
+                .get_mut(&LATENCY)
+                .unwrap()
+                .add_measurement(DiagnosticMeasurement {
+                    time: Instant::now(),
+                    value: value as f64,
+                });
+            app.update();
+        }
+
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let frames = csv
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').take(3).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![vec!["2", "net/latency", "2"], vec!["4", "net/latency", "4"]],
+            frames
+        );
+    }
+}
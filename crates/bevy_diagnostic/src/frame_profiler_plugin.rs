@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_log::{error, info, FrameProfiler, ProfilerCapture};
+
+/// Records the timings of systems, schedules and render graph nodes with the [`FrameProfiler`],
+/// and saves captures of the last frames to the Chrome trace format.
+///
+/// The profiler is installed by the [`LogPlugin`](bevy_log::LogPlugin), and only receives spans
+/// when Bevy is built with the `trace` feature. It can be toggled and captured at runtime by
+/// sending a [`FrameProfilerEvent`], e.g. from a system reading the keyboard, or through the
+/// [`FrameProfiler`] resource.
+///
+/// Captures are written to [`capture_path`](Self::capture_path), which can be opened in
+/// `chrome://tracing` or [Perfetto](https://ui.perfetto.dev), and the slowest systems of the
+/// capture are logged.
+pub struct FrameProfilerPlugin {
+    /// The number of frames kept by the profiler.
+    pub max_frames: usize,
+    /// Whether the profiler records from the start of the app.
+    pub enabled: bool,
+    /// The file captures are written to.
+    pub capture_path: PathBuf,
+}
+
+impl Default for FrameProfilerPlugin {
+    fn default() -> Self {
+        FrameProfilerPlugin {
+            max_frames: FrameProfiler::DEFAULT_MAX_FRAMES,
+            enabled: true,
+            capture_path: PathBuf::from("frame_profile.json"),
+        }
+    }
+}
+
+/// Controls the [`FrameProfiler`] of the [`FrameProfilerPlugin`].
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameProfilerEvent {
+    /// Enables or disables the profiler.
+    Toggle,
+    /// Saves a capture of the last frames to the
+    /// [`capture_path`](FrameProfilerPlugin::capture_path).
+    Capture,
+}
+
+/// Settings of the [`FrameProfilerPlugin`].
+#[derive(Resource)]
+struct FrameProfilerSettings {
+    capture_path: PathBuf,
+}
+
+impl Plugin for FrameProfilerPlugin {
+    fn build(&self, app: &mut App) {
+        let profiler = app
+            .world
+            .get_resource_or_insert_with(FrameProfiler::default);
+        profiler.set_max_frames(self.max_frames);
+        profiler.set_enabled(self.enabled);
+
+        app.add_event::<FrameProfilerEvent>()
+            .insert_resource(FrameProfilerSettings {
+                capture_path: self.capture_path.clone(),
+            })
+            .add_systems(Last, Self::profiler_events_system);
+    }
+}
+
+impl FrameProfilerPlugin {
+    /// The number of systems logged after saving a capture.
+    pub const LOGGED_SYSTEMS: usize = 10;
+
+    fn profiler_events_system(
+        profiler: Res<FrameProfiler>,
+        settings: Res<FrameProfilerSettings>,
+        mut events: EventReader<FrameProfilerEvent>,
+    ) {
+        for event in events.read() {
+            match event {
+                FrameProfilerEvent::Toggle => {
+                    let enabled = !profiler.is_enabled();
+                    profiler.set_enabled(enabled);
+                    info!(
+                        "Frame profiler {}",
+                        if enabled { "enabled" } else { "disabled" }
+                    );
+                }
+                FrameProfilerEvent::Capture => {
+                    let capture = profiler.capture();
+                    match capture.write_chrome_trace(&settings.capture_path) {
+                        Ok(()) => Self::log_capture(&capture, &settings.capture_path),
+                        Err(err) => error!(
+                            "Failed to write frame profile to {}: {err}",
+                            settings.capture_path.display()
+                        ),
+                    }
+                }
+            }
+        }
+    }
+
+    fn log_capture(capture: &ProfilerCapture, path: &std::path::Path) {
+        info!(
+            "Saved a frame profile of {} frames to {}",
+            capture.frames.len(),
+            path.display()
+        );
+        for summary in capture
+            .system_summaries()
+            .into_iter()
+            .take(Self::LOGGED_SYSTEMS)
+        {
+            info!(
+                target: "bevy diagnostic",
+                "{name}: min {min:.3?}, avg {avg:.3?}, max {max:.3?} ({count} runs)",
+                name = summary.name,
+                min = summary.min,
+                avg = summary.avg,
+                max = summary.max,
+                count = summary.count,
+            );
+        }
+    }
+}
@@ -1,14 +1,23 @@
 mod diagnostic;
 mod entity_count_diagnostics_plugin;
+mod export_diagnostics_plugin;
+mod frame_profiler_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
+mod memory_diagnostics_plugin;
 mod system_information_diagnostics_plugin;
 
 use bevy_app::prelude::*;
 pub use diagnostic::*;
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
+pub use export_diagnostics_plugin::{DiagnosticsExportFormat, ExportDiagnosticsPlugin};
+pub use frame_profiler_plugin::{FrameProfilerEvent, FrameProfilerPlugin};
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
+pub use memory_diagnostics_plugin::{
+    AllocationCounts, MemoryDiagnosticsPlugin, SystemAllocationStats, SystemAllocations,
+    TrackingAllocator,
+};
 pub use system_information_diagnostics_plugin::SystemInformationDiagnosticsPlugin;
 
 /// Adds core diagnostics resources to an App.
@@ -23,7 +32,3 @@ impl Plugin for DiagnosticsPlugin {
         );
     }
 }
-
-/// The width which diagnostic names will be printed as
-/// Plugin names should not be longer than this value
-pub const MAX_DIAGNOSTIC_NAME_WIDTH: usize = 32;
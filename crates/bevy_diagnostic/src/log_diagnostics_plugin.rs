@@ -1,4 +1,4 @@
-use super::{Diagnostic, DiagnosticId, DiagnosticsStore};
+use super::{Diagnostic, DiagnosticPath, DiagnosticsStore};
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_log::{debug, info};
@@ -15,14 +15,14 @@ use bevy_utils::Duration;
 pub struct LogDiagnosticsPlugin {
     pub debug: bool,
     pub wait_duration: Duration,
-    pub filter: Option<Vec<DiagnosticId>>,
+    pub filter: Option<Vec<DiagnosticPath>>,
 }
 
 /// State used by the [`LogDiagnosticsPlugin`]
 #[derive(Resource)]
 struct LogDiagnosticsState {
     timer: Timer,
-    filter: Option<Vec<DiagnosticId>>,
+    filter: Option<Vec<DiagnosticPath>>,
 }
 
 impl Default for LogDiagnosticsPlugin {
@@ -51,14 +51,15 @@ impl Plugin for LogDiagnosticsPlugin {
 }
 
 impl LogDiagnosticsPlugin {
-    pub fn filtered(filter: Vec<DiagnosticId>) -> Self {
+    /// Only logs the diagnostics whose path is in `filter` or nested under one of its paths.
+    pub fn filtered(filter: Vec<DiagnosticPath>) -> Self {
         LogDiagnosticsPlugin {
             filter: Some(filter),
             ..Default::default()
         }
     }
 
-    fn log_diagnostic(diagnostic: &Diagnostic) {
+    fn log_diagnostic(path_width: usize, diagnostic: &Diagnostic) {
         if let Some(value) = diagnostic.smoothed() {
             if diagnostic.get_max_history_length() > 1 {
                 if let Some(average) = diagnostic.average() {
@@ -68,46 +69,57 @@ impl LogDiagnosticsPlugin {
                         // so we reserve two columns for it; however,
                         // Do not reserve columns for the suffix in the average
                         // The ) hugging the value is more aesthetically pleasing
-                        "{name:<name_width$}: {value:>11.6}{suffix:2} (avg {average:>.6}{suffix:})",
-                        name = diagnostic.name,
+                        "{path:<path_width$}: {value:>11.6}{suffix:2} (avg {average:>.6}{suffix:})",
+                        path = diagnostic.path(),
                         suffix = diagnostic.suffix,
-                        name_width = crate::MAX_DIAGNOSTIC_NAME_WIDTH,
                     );
                     return;
                 }
             }
             info!(
                 target: "bevy diagnostic",
-                "{name:<name_width$}: {value:>.6}{suffix:}",
-                name = diagnostic.name,
+                "{path:<path_width$}: {value:>.6}{suffix:}",
+                path = diagnostic.path(),
                 suffix = diagnostic.suffix,
-                name_width = crate::MAX_DIAGNOSTIC_NAME_WIDTH,
             );
         }
     }
 
+    fn for_each_diagnostic(
+        state: &LogDiagnosticsState,
+        diagnostics: &DiagnosticsStore,
+        mut callback: impl FnMut(&Diagnostic),
+    ) {
+        if let Some(filter) = &state.filter {
+            for path in filter {
+                for diagnostic in diagnostics.iter_prefix(path) {
+                    if diagnostic.is_enabled {
+                        callback(diagnostic);
+                    }
+                }
+            }
+        } else {
+            for diagnostic in diagnostics.iter() {
+                if diagnostic.is_enabled {
+                    callback(diagnostic);
+                }
+            }
+        }
+    }
+
     fn log_diagnostics_system(
         mut state: ResMut<LogDiagnosticsState>,
         time: Res<Time<Real>>,
         diagnostics: Res<DiagnosticsStore>,
     ) {
         if state.timer.tick(time.delta()).finished() {
-            if let Some(ref filter) = state.filter {
-                for diagnostic in filter.iter().flat_map(|id| {
-                    diagnostics
-                        .get(*id)
-                        .filter(|diagnostic| diagnostic.is_enabled)
-                }) {
-                    Self::log_diagnostic(diagnostic);
-                }
-            } else {
-                for diagnostic in diagnostics
-                    .iter()
-                    .filter(|diagnostic| diagnostic.is_enabled)
-                {
-                    Self::log_diagnostic(diagnostic);
-                }
-            }
+            let mut path_width = 0;
+            Self::for_each_diagnostic(&state, &diagnostics, |diagnostic| {
+                path_width = path_width.max(diagnostic.path().as_str().len());
+            });
+            Self::for_each_diagnostic(&state, &diagnostics, |diagnostic| {
+                Self::log_diagnostic(path_width, diagnostic);
+            });
         }
     }
 
@@ -117,22 +129,9 @@ impl LogDiagnosticsPlugin {
         diagnostics: Res<DiagnosticsStore>,
     ) {
         if state.timer.tick(time.delta()).finished() {
-            if let Some(ref filter) = state.filter {
-                for diagnostic in filter.iter().flat_map(|id| {
-                    diagnostics
-                        .get(*id)
-                        .filter(|diagnostic| diagnostic.is_enabled)
-                }) {
-                    debug!("{:#?}\n", diagnostic);
-                }
-            } else {
-                for diagnostic in diagnostics
-                    .iter()
-                    .filter(|diagnostic| diagnostic.is_enabled)
-                {
-                    debug!("{:#?}\n", diagnostic);
-                }
-            }
+            Self::for_each_diagnostic(&state, &diagnostics, |diagnostic| {
+                debug!("{:#?}\n", diagnostic);
+            });
         }
     }
 }
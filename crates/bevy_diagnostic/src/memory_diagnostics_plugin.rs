@@ -0,0 +1,488 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::{Cell, RefCell},
+    fmt,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+};
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_log::{
+    tracing_subscriber::{layer::Context, registry::LookupSpan, Layer},
+    BoxedLayer,
+};
+use bevy_utils::{
+    tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id},
+        Subscriber,
+    },
+    HashMap,
+};
+
+/// Adds memory diagnostics to an App: the resident memory of the process and, when the
+/// [`TrackingAllocator`] is the global allocator, the number of allocations and bytes allocated
+/// every frame and the memory currently allocated.
+///
+/// Allocations can also be attributed to the systems making them, see
+/// [`MemoryDiagnosticsPlugin::allocation_layer`].
+///
+/// The resident memory is only reported on the platforms supported by the
+/// [`SystemInformationDiagnosticsPlugin`](crate::SystemInformationDiagnosticsPlugin).
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct MemoryDiagnosticsPlugin;
+
+impl Plugin for MemoryDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::PROCESS_MEMORY).with_suffix("MiB"))
+            .register_diagnostic(Diagnostic::new(Self::ALLOCATIONS))
+            .register_diagnostic(Diagnostic::new(Self::ALLOCATED_BYTES).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::LIVE_MEMORY).with_suffix("MiB"))
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+impl MemoryDiagnosticsPlugin {
+    pub const PROCESS_MEMORY: DiagnosticPath = DiagnosticPath::const_new("memory/process");
+    pub const ALLOCATIONS: DiagnosticPath = DiagnosticPath::const_new("memory/allocations");
+    pub const ALLOCATED_BYTES: DiagnosticPath = DiagnosticPath::const_new("memory/allocated_bytes");
+    pub const LIVE_MEMORY: DiagnosticPath = DiagnosticPath::const_new("memory/live");
+
+    /// Returns a layer attributing allocations to the systems making them, in the
+    /// [`SystemAllocations`] resource, to use as the
+    /// [`LogPlugin::custom_layer`](bevy_log::LogPlugin::custom_layer).
+    ///
+    /// This needs the [`TrackingAllocator`] and Bevy's `trace` feature, which adds a span to each
+    /// system run.
+    ///
+    /// ```no_run
+    /// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+    /// # use bevy_diagnostic::{MemoryDiagnosticsPlugin, TrackingAllocator};
+    /// # use bevy_log::LogPlugin;
+    /// #[global_allocator]
+    /// static ALLOCATOR: TrackingAllocator = TrackingAllocator::new(std::alloc::System);
+    ///
+    /// fn main() {
+    ///     App::new()
+    ///         .add_plugins(DefaultPlugins.set(LogPlugin {
+    ///             custom_layer: MemoryDiagnosticsPlugin::allocation_layer,
+    ///             ..Default::default()
+    ///         }))
+    ///         .add_plugins(MemoryDiagnosticsPlugin)
+    ///         .run();
+    /// }
+    /// ```
+    pub fn allocation_layer(app: &mut App) -> Option<BoxedLayer> {
+        let allocations = SystemAllocations::default();
+        let layer = SystemAllocationsLayer {
+            current: allocations.current.clone(),
+        };
+        app.insert_resource(allocations);
+        Some(Box::new(layer))
+    }
+
+    fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        mut last_counts: Local<Option<AllocationCounts>>,
+        mut process: Local<internal::ProcessMemory>,
+        system_allocations: Option<ResMut<SystemAllocations>>,
+    ) {
+        if let Some(memory) = process.resident_memory() {
+            diagnostics.add_measurement(&Self::PROCESS_MEMORY, || memory as f64 * BYTES_TO_MIB);
+        }
+
+        if let Some(counts) = AllocationCounts::current() {
+            if let Some(last_counts) = *last_counts {
+                let allocations = counts.allocations.wrapping_sub(last_counts.allocations);
+                let bytes = counts
+                    .allocated_bytes
+                    .wrapping_sub(last_counts.allocated_bytes);
+                diagnostics.add_measurement(&Self::ALLOCATIONS, || allocations as f64);
+                diagnostics.add_measurement(&Self::ALLOCATED_BYTES, || bytes as f64);
+            }
+            diagnostics.add_measurement(&Self::LIVE_MEMORY, || {
+                counts.live_bytes() as f64 * BYTES_TO_MIB
+            });
+            *last_counts = Some(counts);
+        }
+
+        if let Some(mut system_allocations) = system_allocations {
+            system_allocations.end_frame();
+        }
+    }
+}
+
+const BYTES_TO_MIB: f64 = 1.0 / 1024.0 / 1024.0;
+
+static TRACKING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The allocations and bytes allocated by the current thread.
+    static THREAD_COUNTS: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+/// A global allocator counting the allocations made through it, for the
+/// [`MemoryDiagnosticsPlugin`].
+///
+/// It wraps another allocator, usually [`System`]:
+///
+/// ```
+/// # use bevy_diagnostic::TrackingAllocator;
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator::new(std::alloc::System);
+/// ```
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Counts the allocations made by `inner`.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+
+    fn record_allocation(size: usize) {
+        TRACKING.store(true, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        // The thread local may already be destroyed while the thread exits.
+        let _ = THREAD_COUNTS.try_with(|counts| {
+            let (allocations, bytes) = counts.get();
+            counts.set((allocations.wrapping_add(1), bytes.wrapping_add(size)));
+        });
+    }
+
+    fn record_deallocation(size: usize) {
+        DEALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: allocations are forwarded to `inner`, recording them doesn't allocate.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        Self::record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_deallocation(layout.size());
+            Self::record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// The allocations counted by the [`TrackingAllocator`] since the app started.
+///
+/// The counters wrap around on overflow, so differences between counts should use
+/// [`usize::wrapping_sub`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationCounts {
+    /// The number of allocations, including reallocations.
+    pub allocations: usize,
+    /// The number of bytes allocated.
+    pub allocated_bytes: usize,
+    /// The number of bytes deallocated.
+    pub deallocated_bytes: usize,
+}
+
+impl AllocationCounts {
+    /// Returns the current counts, or `None` if the [`TrackingAllocator`] isn't the global
+    /// allocator.
+    pub fn current() -> Option<Self> {
+        TRACKING.load(Ordering::Relaxed).then(|| AllocationCounts {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            deallocated_bytes: DEALLOCATED_BYTES.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Returns the number of bytes currently allocated.
+    pub fn live_bytes(&self) -> usize {
+        self.allocated_bytes.wrapping_sub(self.deallocated_bytes)
+    }
+}
+
+/// The allocations made by a system, see [`SystemAllocations`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemAllocationStats {
+    /// The number of allocations made in the last frame.
+    pub allocations: usize,
+    /// The number of bytes allocated in the last frame.
+    pub bytes: usize,
+    /// The number of consecutive frames, up to the last one, in which the system allocated.
+    pub consecutive_frames: u32,
+}
+
+/// The allocations made by each system in the last frame.
+///
+/// This resource is inserted by [`MemoryDiagnosticsPlugin::allocation_layer`] and updated by
+/// the [`MemoryDiagnosticsPlugin`]. Systems that didn't allocate in the last frame aren't listed.
+#[derive(Resource, Default)]
+pub struct SystemAllocations {
+    current: Arc<Mutex<HashMap<String, (usize, usize)>>>,
+    last_frame: HashMap<String, SystemAllocationStats>,
+}
+
+impl SystemAllocations {
+    /// Returns the allocations of the system named `name` in the last frame.
+    pub fn get(&self, name: &str) -> Option<&SystemAllocationStats> {
+        self.last_frame.get(name)
+    }
+
+    /// Returns the systems that allocated in the last frame, sorted by decreasing bytes
+    /// allocated.
+    pub fn iter(&self) -> Vec<(&str, &SystemAllocationStats)> {
+        let mut systems = self
+            .last_frame
+            .iter()
+            .map(|(name, stats)| (name.as_str(), stats))
+            .collect::<Vec<_>>();
+        systems.sort_by(|(a_name, a), (b_name, b)| b.bytes.cmp(&a.bytes).then(a_name.cmp(b_name)));
+        systems
+    }
+
+    /// Ends the current frame, replacing the allocations of the last one.
+    pub fn end_frame(&mut self) {
+        let current =
+            std::mem::take(&mut *self.current.lock().unwrap_or_else(PoisonError::into_inner));
+        let last_frame = current
+            .into_iter()
+            .map(|(name, (allocations, bytes))| {
+                let consecutive_frames = self
+                    .last_frame
+                    .get(&name)
+                    .map_or(0, |stats| stats.consecutive_frames);
+                let stats = SystemAllocationStats {
+                    allocations,
+                    bytes,
+                    consecutive_frames: consecutive_frames + 1,
+                };
+                (name, stats)
+            })
+            .collect();
+        self.last_frame = last_frame;
+    }
+}
+
+/// The layer returned by [`MemoryDiagnosticsPlugin::allocation_layer`].
+struct SystemAllocationsLayer {
+    current: Arc<Mutex<HashMap<String, (usize, usize)>>>,
+}
+
+/// The name of a system span.
+struct SystemName(String);
+
+struct NameVisitor(Option<String>);
+
+impl Visit for NameVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "name" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "name" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+thread_local! {
+    /// The thread counts when the system spans being entered on this thread were entered.
+    static ENTERED_COUNTS: RefCell<Vec<(usize, usize)>> = const { RefCell::new(Vec::new()) };
+}
+
+impl<S> Layer<S> for SystemAllocationsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if attrs.metadata().name() != "system" {
+            return;
+        }
+        let mut visitor = NameVisitor(None);
+        attrs.record(&mut visitor);
+        if let (Some(name), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(SystemName(name));
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if ctx.metadata(id).map(|metadata| metadata.name()) != Some("system") {
+            return;
+        }
+        ENTERED_COUNTS.with(|entered| {
+            let mut entered = entered.borrow_mut();
+            // Pushing may allocate, so the counts are read after it.
+            entered.push((0, 0));
+            if let Some(counts) = entered.last_mut() {
+                *counts = THREAD_COUNTS.with(Cell::get);
+            }
+        });
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if ctx.metadata(id).map(|metadata| metadata.name()) != Some("system") {
+            return;
+        }
+        let (allocations, bytes) = THREAD_COUNTS.with(Cell::get);
+        let Some((entered_allocations, entered_bytes)) =
+            ENTERED_COUNTS.with(|entered| entered.borrow_mut().pop())
+        else {
+            return;
+        };
+        let allocations = allocations.wrapping_sub(entered_allocations);
+        if allocations == 0 {
+            return;
+        }
+        let bytes = bytes.wrapping_sub(entered_bytes);
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(SystemName(name)) = extensions.get::<SystemName>() else {
+            return;
+        };
+        let mut current = self.current.lock().unwrap_or_else(PoisonError::into_inner);
+        let counts = current.entry(name.clone()).or_default();
+        counts.0 += allocations;
+        counts.1 += bytes;
+    }
+}
+
+// NOTE: sysinfo fails to compile when using bevy dynamic or on iOS and does nothing on wasm
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "windows",
+        target_os = "android",
+        target_os = "macos"
+    ),
+    not(feature = "dynamic_linking")
+))]
+mod internal {
+    use sysinfo::{Pid, ProcessRefreshKind, System};
+
+    /// Reads the memory of the current process.
+    #[derive(Default)]
+    pub(crate) struct ProcessMemory(Option<(System, Pid)>);
+
+    impl ProcessMemory {
+        /// Returns the resident memory of the process, in bytes.
+        pub(crate) fn resident_memory(&mut self) -> Option<u64> {
+            if self.0.is_none() {
+                self.0 = Some((System::new(), sysinfo::get_current_pid().ok()?));
+            }
+            let (system, pid) = self.0.as_mut()?;
+            system.refresh_process_specifics(*pid, ProcessRefreshKind::new());
+            system.process(*pid).map(sysinfo::Process::memory)
+        }
+    }
+}
+
+#[cfg(not(all(
+    any(
+        target_os = "linux",
+        target_os = "windows",
+        target_os = "android",
+        target_os = "macos"
+    ),
+    not(feature = "dynamic_linking")
+)))]
+mod internal {
+    /// Reads the memory of the current process.
+    #[derive(Default)]
+    pub(crate) struct ProcessMemory;
+
+    impl ProcessMemory {
+        pub(crate) fn resident_memory(&mut self) -> Option<u64> {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryDiagnosticsPlugin, SystemAllocations, TRACKING};
+    use bevy_app::App;
+    use bevy_utils::tracing::{self, Subscriber};
+    use std::sync::atomic::Ordering;
+
+    fn subscriber(app: &mut App) -> impl Subscriber {
+        use bevy_log::tracing_subscriber::{layer::SubscriberExt, Registry};
+        Registry::default().with(MemoryDiagnosticsPlugin::allocation_layer(app))
+    }
+
+    #[test]
+    fn attribute_allocations_to_systems() {
+        // The tests don't use the tracking allocator, so allocations are recorded directly.
+        let record = |size| super::TrackingAllocator::<()>::record_allocation(size);
+        let mut app = App::new();
+        let subscriber = subscriber(&mut app);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let allocating = tracing::info_span!("system", name = "allocating");
+            let idle = tracing::info_span!("system", name = "idle");
+            for frame in 0..3 {
+                allocating.in_scope(|| {
+                    record(16);
+                    record(48);
+                });
+                if frame < 2 {
+                    idle.in_scope(|| record(8));
+                } else {
+                    idle.in_scope(|| {});
+                }
+                app.world.resource_mut::<SystemAllocations>().end_frame();
+            }
+        });
+        assert!(TRACKING.load(Ordering::Relaxed));
+
+        let allocations = app.world.resource::<SystemAllocations>();
+        let systems = allocations
+            .iter()
+            .into_iter()
+            .map(|(name, stats)| {
+                (
+                    name,
+                    stats.allocations,
+                    stats.bytes,
+                    stats.consecutive_frames,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![("allocating", 2, 64, 3)], systems);
+        assert!(allocations.get("idle").is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::DiagnosticId;
+use crate::DiagnosticPath;
 use bevy_app::prelude::*;
 
 /// Adds a System Information Diagnostic, specifically `cpu_usage` (in %) and `mem_usage` (in %)
@@ -24,10 +24,8 @@ impl Plugin for SystemInformationDiagnosticsPlugin {
 }
 
 impl SystemInformationDiagnosticsPlugin {
-    pub const CPU_USAGE: DiagnosticId =
-        DiagnosticId::from_u128(78494871623549551581510633532637320956);
-    pub const MEM_USAGE: DiagnosticId =
-        DiagnosticId::from_u128(42846254859293759601295317811892519825);
+    pub const CPU_USAGE: DiagnosticPath = DiagnosticPath::const_new("system/cpu_usage");
+    pub const MEM_USAGE: DiagnosticPath = DiagnosticPath::const_new("system/mem_usage");
 }
 
 // NOTE: sysinfo fails to compile when using bevy dynamic or on iOS and does nothing on wasm
@@ -51,20 +49,14 @@ pub mod internal {
 
     pub(crate) fn setup_system(mut diagnostics: ResMut<DiagnosticsStore>) {
         diagnostics.add(
-            Diagnostic::new(
-                super::SystemInformationDiagnosticsPlugin::CPU_USAGE,
-                "cpu_usage",
-                20,
-            )
-            .with_suffix("%"),
+            Diagnostic::new(super::SystemInformationDiagnosticsPlugin::CPU_USAGE)
+                .with_max_history_length(20)
+                .with_suffix("%"),
         );
         diagnostics.add(
-            Diagnostic::new(
-                super::SystemInformationDiagnosticsPlugin::MEM_USAGE,
-                "mem_usage",
-                20,
-            )
-            .with_suffix("%"),
+            Diagnostic::new(super::SystemInformationDiagnosticsPlugin::MEM_USAGE)
+                .with_max_history_length(20)
+                .with_suffix("%"),
         );
     }
 
@@ -91,12 +83,14 @@ pub mod internal {
         let used_mem = sys.used_memory() as f64 / BYTES_TO_GIB;
         let current_used_mem = used_mem / total_mem * 100.0;
 
-        diagnostics.add_measurement(super::SystemInformationDiagnosticsPlugin::CPU_USAGE, || {
-            current_cpu_usage as f64
-        });
-        diagnostics.add_measurement(super::SystemInformationDiagnosticsPlugin::MEM_USAGE, || {
-            current_used_mem
-        });
+        diagnostics.add_measurement(
+            &super::SystemInformationDiagnosticsPlugin::CPU_USAGE,
+            || current_cpu_usage as f64,
+        );
+        diagnostics.add_measurement(
+            &super::SystemInformationDiagnosticsPlugin::MEM_USAGE,
+            || current_used_mem,
+        );
     }
 
     #[derive(Debug)]
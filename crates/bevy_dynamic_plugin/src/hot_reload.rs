@@ -0,0 +1,661 @@
+use std::{
+    any::TypeId,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use bevy_app::{AddedPlugins, App, Last, MainScheduleOrder, Plugin, PluginsState};
+use bevy_ecs::{
+    prelude::*,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    schedule::{InternedScheduleLabel, Schedules},
+};
+use bevy_reflect::{
+    serde::{ReflectSerializer, UntypedReflectDeserializer},
+    TypeRegistration, TypeRegistry,
+};
+use bevy_utils::{
+    tracing::{error, info, warn},
+    HashSet,
+};
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+
+use crate::{dynamically_load_plugin, DynamicPluginLoadError};
+
+/// Errors that can occur when hot reloading a dynamic plugin
+#[derive(Debug, Error)]
+pub enum HotReloadError {
+    #[error("cannot copy dynamic plugin library {0:?}: {1}")]
+    Copy(PathBuf, std::io::Error),
+    #[error(transparent)]
+    Load(#[from] DynamicPluginLoadError),
+}
+
+/// Dynamic plugins that are reloaded whenever their library is rebuilt.
+///
+/// Plugins are added with [`DynamicPluginExt::load_hot_reloadable_plugin`](crate::DynamicPluginExt::load_hot_reloadable_plugin).
+/// Every frame, in [`Last`], the modification time of each library is checked. Once a library
+/// hasn't changed for [`debounce`](Self::debounce), the plugin is reloaded:
+///
+/// 1. the new library is loaded, the old plugin is kept if that fails,
+/// 2. the reflected resources and components of the types registered by the old plugin are
+///    serialized through the [`TypeRegistry`], then removed from the world,
+/// 3. the systems of the old plugin are dropped and its types are unregistered,
+/// 4. the new plugin is built, then finished and cleaned up once it is
+///    [ready](Plugin::ready), as if it was added to the app,
+/// 5. the serialized resources and components are restored.
+///
+/// Only the resources and components registered with `#[reflect(Resource)]` and
+/// `#[reflect(Component)]` by the plugin itself are kept. Other state, such as `Local` system
+/// parameters or unreflected resources, is lost or kept as is.
+///
+/// The world caches the memory layout of a component or resource type the first time it is used,
+/// so changing the fields of a type that was already used breaks it until the app is restarted.
+/// The state of such a type isn't restored and an error is logged, the new plugin must not use it.
+/// Types whose layout didn't change are restored with
+/// [`Reflect::apply`](bevy_reflect::Reflect::apply).
+///
+/// The systems of a hot reloaded plugin are kept in their own schedules, which are run by a single
+/// system in the matching schedule of the app. They can be ordered among themselves, but not
+/// relative to the systems of the app.
+///
+/// Libraries are never unloaded, since values created by their code can outlive the plugin. Each
+/// reload copies the library to the temporary directory and loads the copy, leaving the original
+/// free to be overwritten by the next build. The previous copy is deleted once the new plugin
+/// replaced the old one.
+#[derive(Resource)]
+pub struct HotReloadedPlugins {
+    /// How long a library must stay unchanged before it is reloaded, so a library that is still
+    /// being written isn't loaded.
+    pub debounce: Duration,
+    plugins: Vec<HotReloadedPlugin>,
+    runners: HashSet<InternedScheduleLabel>,
+    /// The plugins added to the app, without the ones added by hot reloaded plugins. Plugins are
+    /// reloaded in an app knowing about them, so their dependencies are still checked.
+    app_plugins: AddedPlugins,
+}
+
+impl Default for HotReloadedPlugins {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(500),
+            plugins: Vec::new(),
+            runners: HashSet::default(),
+            app_plugins: AddedPlugins::default(),
+        }
+    }
+}
+
+impl HotReloadedPlugins {
+    /// Returns the paths of the hot reloaded plugin libraries, with the number of times each was
+    /// reloaded.
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, u32)> {
+        self.plugins
+            .iter()
+            .map(|plugin| (plugin.path.as_path(), plugin.reloads))
+    }
+
+    /// Records the plugins added to the app, without the ones added by hot reloaded plugins.
+    fn set_app_plugins(&mut self, added: AddedPlugins) {
+        self.app_plugins = self
+            .plugins
+            .iter()
+            .fold(added, |added, plugin| added.difference(&plugin.added));
+    }
+}
+
+struct HotReloadedPlugin {
+    path: PathBuf,
+    /// The copy of the library the plugin was loaded from.
+    copy: PathBuf,
+    modified: Option<SystemTime>,
+    changed_at: Option<Instant>,
+    reloads: u32,
+    plugin: Box<dyn Plugin>,
+    schedules: Schedules,
+    types: Vec<TypeId>,
+    /// The plugins added by the plugin.
+    added: AddedPlugins,
+}
+
+impl HotReloadedPlugin {
+    /// Runs `f` with the schedules of this plugin in place of the ones of the app, so the systems
+    /// it adds are kept apart, and records the types it registers.
+    fn capture(&mut self, app: &mut App, f: impl FnOnce(&dyn Plugin, &mut App)) {
+        let registered = registered_types(&app.world);
+        let app_schedules = std::mem::replace(
+            &mut *app.world.resource_mut::<Schedules>(),
+            std::mem::take(&mut self.schedules),
+        );
+
+        f(self.plugin.as_ref(), app);
+
+        self.schedules =
+            std::mem::replace(&mut *app.world.resource_mut::<Schedules>(), app_schedules);
+        self.types
+            .extend(registered_types(&app.world).difference(&registered));
+    }
+}
+
+/// Reloads the hot reloaded plugins, and finishes and cleans them up along with the other plugins
+/// of the app.
+struct HotReloadPlugin;
+
+impl Plugin for HotReloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HotReloadedPlugins>()
+            .add_systems(Last, reload_changed_plugins);
+    }
+
+    fn ready(&self, app: &App) -> bool {
+        app.world
+            .resource::<HotReloadedPlugins>()
+            .plugins
+            .iter()
+            .all(|plugin| plugin.plugin.ready(app))
+    }
+
+    fn finish(&self, app: &mut App) {
+        update_plugins(app, |plugin, app| plugin.finish(app));
+    }
+
+    fn cleanup(&self, app: &mut App) {
+        update_plugins(app, |plugin, app| plugin.cleanup(app));
+    }
+}
+
+/// Runs `f` on each hot reloaded plugin of the app, see [`HotReloadedPlugin::capture`].
+fn update_plugins(app: &mut App, f: impl Fn(&dyn Plugin, &mut App)) {
+    let mut plugins = std::mem::take(&mut app.world.resource_mut::<HotReloadedPlugins>().plugins);
+    for plugin in &mut plugins {
+        plugin.capture(app, &f);
+    }
+
+    let added = app.added_plugins();
+    app.world
+        .resource_scope(|world, mut hot_reloaded: Mut<HotReloadedPlugins>| {
+            for plugin in &plugins {
+                add_schedule_runners(world, &mut hot_reloaded, schedule_labels(&plugin.schedules));
+            }
+            hot_reloaded.plugins = plugins;
+            hot_reloaded.set_app_plugins(added);
+        });
+}
+
+/// The state of a hot reloaded plugin, serialized while its library is replaced.
+#[derive(Default)]
+struct SavedState {
+    resources: Vec<String>,
+    components: Vec<(Entity, String)>,
+}
+
+/// Loads the plugin at `path` and adds it to the [`HotReloadedPlugins`] of the app.
+///
+/// # Safety
+///
+/// Same as [`dynamically_load_plugin`].
+pub(crate) unsafe fn load_hot_reloadable_plugin(
+    app: &mut App,
+    path: PathBuf,
+) -> Result<(), HotReloadError> {
+    if !app.world.contains_resource::<HotReloadedPlugins>() {
+        app.add_plugins(HotReloadPlugin);
+    }
+
+    let modified = modified_time(&path);
+    let (plugin, copy) = load_library_copy(&path, 0)?;
+    add_hot_reloaded_plugin(app, path, copy, modified, plugin);
+    Ok(())
+}
+
+fn add_hot_reloaded_plugin(
+    app: &mut App,
+    path: PathBuf,
+    copy: PathBuf,
+    modified: Option<SystemTime>,
+    plugin: Box<dyn Plugin>,
+) {
+    // Reloaded plugins may add systems to any main schedule, including the one running when they
+    // are reloaded, so all of them get a runner upfront.
+    let labels = app.world.resource::<MainScheduleOrder>().labels.clone();
+    let added = app.added_plugins();
+    let mut plugin = HotReloadedPlugin {
+        path,
+        copy,
+        modified,
+        changed_at: None,
+        reloads: 0,
+        plugin,
+        schedules: Schedules::default(),
+        types: Vec::new(),
+        added: AddedPlugins::default(),
+    };
+    plugin.capture(app, |plugin, app| plugin.build(app));
+    plugin.added = app.added_plugins().difference(&added);
+
+    app.world
+        .resource_scope(|world, mut plugins: Mut<HotReloadedPlugins>| {
+            add_schedule_runners(world, &mut plugins, labels);
+            add_schedule_runners(world, &mut plugins, schedule_labels(&plugin.schedules));
+            plugins.plugins.push(plugin);
+            plugins.set_app_plugins(added);
+        });
+}
+
+/// Copies the library at `path` to the temporary directory and loads the copy, returning the
+/// plugin with the path of the copy.
+///
+/// # Safety
+///
+/// Same as [`dynamically_load_plugin`].
+unsafe fn load_library_copy(
+    path: &Path,
+    reloads: u32,
+) -> Result<(Box<dyn Plugin>, PathBuf), HotReloadError> {
+    let mut file_name = path.file_stem().unwrap_or_default().to_os_string();
+    file_name.push(format!("-{}-{reloads}", std::process::id()));
+    let mut copy = std::env::temp_dir().join(file_name);
+    if let Some(extension) = path.extension() {
+        copy.set_extension(extension);
+    }
+    std::fs::copy(path, &copy).map_err(|err| HotReloadError::Copy(path.to_path_buf(), err))?;
+
+    let (lib, plugin) = dynamically_load_plugin(&copy).map_err(|err| {
+        remove_library_copy(&copy);
+        err
+    })?;
+    std::mem::forget(lib); // Values created by the library may outlive the plugin
+    Ok((plugin, copy))
+}
+
+fn remove_library_copy(copy: &Path) {
+    if let Err(err) = std::fs::remove_file(copy) {
+        warn!("Failed to remove dynamic plugin library copy {copy:?}: {err}");
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn registered_types(world: &World) -> HashSet<TypeId> {
+    world
+        .get_resource::<AppTypeRegistry>()
+        .map_or_else(HashSet::default, |registry| {
+            registry
+                .read()
+                .iter()
+                .map(|registration| registration.type_id())
+                .collect()
+        })
+}
+
+fn schedule_labels(schedules: &Schedules) -> Vec<InternedScheduleLabel> {
+    schedules
+        .iter()
+        .map(|(_, schedule)| schedule.label())
+        .collect()
+}
+
+/// Adds a system running the schedules of the hot reloaded plugins to each of the app schedules
+/// in `labels` that doesn't have one yet.
+fn add_schedule_runners(
+    world: &mut World,
+    plugins: &mut HotReloadedPlugins,
+    labels: impl IntoIterator<Item = InternedScheduleLabel>,
+) {
+    let mut schedules = world.resource_mut::<Schedules>();
+    for label in labels {
+        if !plugins.runners.insert(label) {
+            continue;
+        }
+        let runner = run_hot_reloaded_schedules(label);
+        if let Some(schedule) = schedules.get_mut(label) {
+            schedule.add_systems(runner);
+        } else {
+            let mut schedule = Schedule::new(label);
+            schedule.add_systems(runner);
+            schedules.insert(schedule);
+        }
+    }
+}
+
+fn run_hot_reloaded_schedules(label: InternedScheduleLabel) -> impl FnMut(&mut World) {
+    move |world: &mut World| {
+        world.resource_scope(|world, mut plugins: Mut<HotReloadedPlugins>| {
+            for plugin in &mut plugins.plugins {
+                if let Some(schedule) = plugin.schedules.get_mut(label) {
+                    schedule.run(world);
+                }
+            }
+        });
+    }
+}
+
+/// Reloads the plugins whose library changed, once it has stayed unchanged for the debounce
+/// duration.
+fn reload_changed_plugins(world: &mut World) {
+    world.resource_scope(|world, mut plugins: Mut<HotReloadedPlugins>| {
+        let debounce = plugins.debounce;
+        for index in 0..plugins.plugins.len() {
+            let plugin = &mut plugins.plugins[index];
+            let modified = modified_time(&plugin.path);
+            if modified != plugin.modified {
+                plugin.modified = modified;
+                plugin.changed_at = Some(Instant::now());
+                continue;
+            }
+            match plugin.changed_at {
+                Some(changed_at) if changed_at.elapsed() >= debounce => {}
+                _ => continue,
+            }
+            plugin.changed_at = None;
+
+            // SAFETY: the library was loaded from the same path when the plugin was added, so it
+            // is trusted the same way.
+            match unsafe { load_library_copy(&plugin.path, plugin.reloads + 1) } {
+                Ok((new_plugin, copy)) => {
+                    replace_plugin(world, &mut plugins, index, new_plugin, copy);
+                    let plugin = &plugins.plugins[index];
+                    info!("Reloaded dynamic plugin {:?}", plugin.path);
+                }
+                Err(err) => error!("Failed to reload dynamic plugin: {err}"),
+            }
+        }
+    });
+}
+
+/// Replaces the plugin at `index` with `new_plugin`, loaded from the library `copy`, keeping the
+/// state of the reflected types registered by the old plugin.
+fn replace_plugin(
+    world: &mut World,
+    plugins: &mut HotReloadedPlugins,
+    index: usize,
+    new_plugin: Box<dyn Plugin>,
+    copy: PathBuf,
+) {
+    let plugin = &mut plugins.plugins[index];
+    let state = save_state(world, &plugin.types);
+
+    if let Some(registry) = world.get_resource::<AppTypeRegistry>() {
+        let mut registry = registry.write();
+        for type_id in &plugin.types {
+            registry.remove(*type_id);
+        }
+    }
+    plugin.plugin = new_plugin;
+    plugin.schedules = Schedules::default();
+    plugin.types.clear();
+
+    // Plugins are built against an `App`, so the world is moved for the build into an empty one
+    // that knows about the plugins of the app.
+    let mut app = App::empty();
+    app.insert_added_plugins(plugins.app_plugins.clone());
+    std::mem::swap(&mut app.world, world);
+    plugin.capture(&mut app, |plugin, app| {
+        plugin.build(app);
+        while app.plugins_state() == PluginsState::Adding || !plugin.ready(app) {
+            bevy_tasks::tick_global_task_pools_on_main_thread();
+        }
+        plugin.finish(app);
+        app.finish();
+        plugin.cleanup(app);
+        app.cleanup();
+    });
+    plugin.added = app.added_plugins().difference(&plugins.app_plugins);
+    std::mem::swap(&mut app.world, world);
+
+    let old_copy = std::mem::replace(&mut plugin.copy, copy);
+    plugin.reloads += 1;
+    let labels = schedule_labels(&plugin.schedules);
+    let types = plugin.types.clone();
+    add_schedule_runners(world, plugins, labels);
+    remove_library_copy(&old_copy);
+
+    restore_state(world, state, &types);
+}
+
+/// Serializes the reflected resources and components of `types`, and removes them from the world.
+fn save_state(world: &mut World, types: &[TypeId]) -> SavedState {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return SavedState::default();
+    };
+    let registry = registry.read();
+
+    let mut state = SavedState::default();
+    for registration in types.iter().filter_map(|type_id| registry.get(*type_id)) {
+        if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+            if let Some(resource) = reflect_resource.reflect(world) {
+                state.resources.extend(serialize(
+                    resource,
+                    &registry,
+                    registration.type_info().type_path(),
+                ));
+            }
+            reflect_resource.remove(world);
+        }
+
+        if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+            let entities = world
+                .iter_entities()
+                .filter_map(|entity| {
+                    let component = reflect_component.reflect(entity)?;
+                    let serialized =
+                        serialize(component, &registry, registration.type_info().type_path());
+                    Some((entity.id(), serialized))
+                })
+                .collect::<Vec<_>>();
+            for (entity, serialized) in entities {
+                reflect_component.remove(&mut world.entity_mut(entity));
+                state
+                    .components
+                    .extend(serialized.map(|serialized| (entity, serialized)));
+            }
+        }
+    }
+    state
+}
+
+fn serialize(
+    value: &dyn bevy_reflect::Reflect,
+    registry: &TypeRegistry,
+    type_path: &str,
+) -> Option<String> {
+    ron::to_string(&ReflectSerializer::new(value, registry))
+        .map_err(|err| warn!("Failed to serialize {type_path} for hot reloading: {err}"))
+        .ok()
+}
+
+/// Restores the resources and components saved by [`save_state`] with the `types` registered by
+/// the new plugin, skipping the types whose memory layout changed.
+fn restore_state(world: &mut World, state: SavedState, types: &[TypeId]) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    let registry = registry.read();
+
+    let changed = types
+        .iter()
+        .filter_map(|type_id| registry.get(*type_id))
+        .filter(|registration| layout_changed(world, registration))
+        .map(|registration| {
+            error!(
+                "The memory layout of {} changed, its state is lost and it can't be used until the app is restarted",
+                registration.type_info().type_path()
+            );
+            registration.type_id()
+        })
+        .collect::<HashSet<_>>();
+
+    for serialized in state.resources {
+        let Some((value, registration)) = deserialize(&serialized, &registry) else {
+            continue;
+        };
+        if changed.contains(&registration.type_id()) {
+            continue;
+        }
+        match registration.data::<ReflectResource>() {
+            Some(reflect_resource) => reflect_resource.insert(world, value.as_ref()),
+            None => warn!(
+                "{} is no longer a reflected resource, its state is lost",
+                registration.type_info().type_path()
+            ),
+        }
+    }
+
+    for (entity, serialized) in state.components {
+        let Some((value, registration)) = deserialize(&serialized, &registry) else {
+            continue;
+        };
+        if changed.contains(&registration.type_id()) {
+            continue;
+        }
+        let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+            warn!(
+                "{} is no longer a reflected component, its state is lost",
+                registration.type_info().type_path()
+            );
+            continue;
+        };
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            reflect_component.insert(&mut entity, value.as_ref());
+        }
+    }
+}
+
+/// Returns whether the world stores the type of `registration` as a component or a resource with
+/// another memory layout, cached from a previous version of the type.
+fn layout_changed(world: &World, registration: &TypeRegistration) -> bool {
+    let components = world.components();
+    [
+        components.get_id(registration.type_id()),
+        components.get_resource_id(registration.type_id()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|id| components.get_info(id))
+    .any(|info| info.layout() != registration.layout())
+}
+
+fn deserialize<'a>(
+    serialized: &str,
+    registry: &'a TypeRegistry,
+) -> Option<(
+    Box<dyn bevy_reflect::Reflect>,
+    &'a bevy_reflect::TypeRegistration,
+)> {
+    let value = ron::Deserializer::from_str(serialized)
+        .map_err(ron::Error::from)
+        .and_then(|mut deserializer| {
+            UntypedReflectDeserializer::new(registry).deserialize(&mut deserializer)
+        })
+        .map_err(|err| warn!("Failed to restore hot reloaded state {serialized}: {err}"))
+        .ok()?;
+    let registration = registry.get(value.get_represented_type_info()?.type_id())?;
+    Some((value, registration))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_hot_reloaded_plugin, replace_plugin, HotReloadPlugin, HotReloadedPlugins};
+    use bevy_app::{App, Plugin, Update};
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+    use std::path::PathBuf;
+
+    #[derive(Component, Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Resource)]
+    struct Counter(u32);
+
+    #[derive(Resource)]
+    struct Finished(&'static str);
+
+    struct OldPlugin;
+
+    impl Plugin for OldPlugin {
+        fn build(&self, app: &mut App) {
+            app.register_type::<Counter>()
+                .init_resource::<Counter>()
+                .add_systems(Update, |mut counter: ResMut<Counter>| counter.0 += 1);
+        }
+
+        fn finish(&self, app: &mut App) {
+            app.insert_resource(Finished("old"));
+        }
+    }
+
+    struct NewPlugin;
+
+    impl Plugin for NewPlugin {
+        fn build(&self, app: &mut App) {
+            app.register_type::<Counter>()
+                .init_resource::<Counter>()
+                .add_systems(Update, |mut counter: ResMut<Counter>| counter.0 += 10);
+        }
+
+        fn finish(&self, app: &mut App) {
+            app.insert_resource(Finished("new"));
+        }
+    }
+
+    fn library_copy(name: &str) -> PathBuf {
+        let copy =
+            std::env::temp_dir().join(format!("hot_reload_test-{}-{name}", std::process::id()));
+        std::fs::write(&copy, []).unwrap();
+        copy
+    }
+
+    #[test]
+    fn reload_replaces_systems_and_keeps_state() {
+        let mut app = App::new();
+        app.add_plugins(HotReloadPlugin);
+        let old_copy = library_copy("old");
+        add_hot_reloaded_plugin(
+            &mut app,
+            PathBuf::new(),
+            old_copy.clone(),
+            None,
+            Box::new(OldPlugin),
+        );
+        app.finish();
+        app.cleanup();
+        assert_eq!("old", app.world.resource::<Finished>().0);
+        let entity = app.world.spawn(Counter(7)).id();
+
+        app.update();
+        app.update();
+        assert_eq!(2, app.world.resource::<Counter>().0);
+
+        let new_copy = library_copy("new");
+        app.world
+            .resource_scope(|world, mut plugins: Mut<HotReloadedPlugins>| {
+                replace_plugin(
+                    world,
+                    &mut plugins,
+                    0,
+                    Box::new(NewPlugin),
+                    new_copy.clone(),
+                );
+            });
+        assert_eq!(2, app.world.resource::<Counter>().0);
+        assert_eq!(Some(&Counter(7)), app.world.get::<Counter>(entity));
+        assert_eq!("new", app.world.resource::<Finished>().0);
+        assert!(!old_copy.exists());
+        assert!(new_copy.exists());
+        std::fs::remove_file(new_copy).unwrap();
+
+        app.update();
+        assert_eq!(12, app.world.resource::<Counter>().0);
+        let plugins = app.world.resource::<HotReloadedPlugins>();
+        assert_eq!(
+            vec![1],
+            plugins
+                .iter()
+                .map(|(_, reloads)| reloads)
+                .collect::<Vec<_>>()
+        );
+    }
+}
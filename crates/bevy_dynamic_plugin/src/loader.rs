@@ -1,9 +1,11 @@
 use libloading::{Library, Symbol};
-use std::ffi::OsStr;
+use std::{ffi::OsStr, path::PathBuf};
 use thiserror::Error;
 
 use bevy_app::{App, CreatePlugin, Plugin};
 
+use crate::hot_reload::load_hot_reloadable_plugin;
+
 /// Errors that can occur when loading a dynamic plugin
 #[derive(Debug, Error)]
 pub enum DynamicPluginLoadError {
@@ -37,6 +39,16 @@ pub trait DynamicPluginExt {
     ///
     /// Same as [`dynamically_load_plugin`].
     unsafe fn load_plugin<P: AsRef<OsStr>>(&mut self, path: P) -> &mut Self;
+
+    /// Loads the plugin at `path` and reloads it whenever the library is rebuilt, keeping the
+    /// state of its reflected resources and components.
+    ///
+    /// See [`HotReloadedPlugins`](crate::HotReloadedPlugins) for how plugins are reloaded.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`dynamically_load_plugin`], for every version of the library.
+    unsafe fn load_hot_reloadable_plugin<P: Into<PathBuf>>(&mut self, path: P) -> &mut Self;
 }
 
 impl DynamicPluginExt for App {
@@ -46,4 +58,9 @@ impl DynamicPluginExt for App {
         plugin.build(self);
         self
     }
+
+    unsafe fn load_hot_reloadable_plugin<P: Into<PathBuf>>(&mut self, path: P) -> &mut Self {
+        load_hot_reloadable_plugin(self, path.into()).unwrap();
+        self
+    }
 }
@@ -27,7 +27,7 @@ pub use bevy_ptr as ptr;
 pub mod prelude {
     #[doc(hidden)]
     #[cfg(feature = "bevy_reflect")]
-    pub use crate::reflect::{AppTypeRegistry, ReflectComponent, ReflectResource};
+    pub use crate::reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectResource};
     #[doc(hidden)]
     pub use crate::{
         bundle::Bundle,
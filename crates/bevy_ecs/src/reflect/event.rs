@@ -0,0 +1,75 @@
+//! Definitions for [`Event`] reflection.
+
+use crate::{event::Event, world::World};
+use bevy_reflect::{FromReflect, FromType, Reflect};
+
+/// A struct used to send reflected [`Event`]s of a type.
+///
+/// A [`ReflectEvent`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`], once `T` derives [`Reflect`] with the
+/// `#[reflect(Event)]` attribute and has been added to the [`TypeRegistry`](bevy_reflect::TypeRegistry).
+///
+/// This lets events be sent from data only known at runtime, for example events read from a
+/// scripted test or a debugging tool.
+#[derive(Clone)]
+pub struct ReflectEvent {
+    send: fn(&mut World, &dyn Reflect) -> bool,
+}
+
+impl ReflectEvent {
+    /// Sends a reflected [`Event`] like [`send_event()`](World::send_event).
+    ///
+    /// The value is converted to the concrete event type with [`FromReflect`], so it can be a
+    /// dynamic value such as a [`DynamicStruct`](bevy_reflect::DynamicStruct).
+    ///
+    /// Returns `false` if the value could not be converted to the event type, or if the event
+    /// hasn't been added to the world.
+    pub fn send(&self, world: &mut World, event: &dyn Reflect) -> bool {
+        (self.send)(world, event)
+    }
+}
+
+impl<E: Event + FromReflect> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        ReflectEvent {
+            send: |world, event| {
+                let Some(event) = E::from_reflect(event) else {
+                    return false;
+                };
+                world.send_event(event).is_some()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_ecs, event::Events, prelude::*};
+    use bevy_reflect::{DynamicStruct, FromType, Reflect};
+
+    use super::ReflectEvent;
+
+    #[derive(Event, Reflect, Debug, PartialEq)]
+    struct Jump {
+        height: f32,
+    }
+
+    #[test]
+    fn send_reflected_event() {
+        let reflect_event = <ReflectEvent as FromType<Jump>>::from_type();
+        let mut world = World::new();
+
+        let mut jump = DynamicStruct::default();
+        jump.insert("height", 2.0f32);
+        assert!(!reflect_event.send(&mut world, &jump));
+
+        world.init_resource::<Events<Jump>>();
+        assert!(reflect_event.send(&mut world, &jump));
+        assert!(!reflect_event.send(&mut world, &1.0f32));
+
+        let events = world.resource::<Events<Jump>>();
+        let mut reader = events.get_reader();
+        let sent = reader.read(events).collect::<Vec<_>>();
+        assert_eq!(vec![&Jump { height: 2.0 }], sent);
+    }
+}
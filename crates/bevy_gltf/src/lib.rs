@@ -55,6 +55,9 @@ impl Plugin for GltfPlugin {
             .init_asset::<GltfNode>()
             .init_asset::<GltfPrimitive>()
             .init_asset::<GltfMesh>()
+            .register_asset_partial_eq::<GltfNode>()
+            .register_asset_partial_eq::<GltfPrimitive>()
+            .register_asset_partial_eq::<GltfMesh>()
             .preregister_asset_loader::<GltfLoader>(&["gltf", "glb"]);
     }
 
@@ -104,7 +107,7 @@ pub struct Gltf {
 /// [`Transform`](bevy_transform::prelude::Transform) and an optional [`GltfExtras`].
 ///
 /// See [the relevant glTF specification section](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#reference-node).
-#[derive(Asset, Debug, Clone, TypePath)]
+#[derive(Asset, Debug, Clone, PartialEq, TypePath)]
 pub struct GltfNode {
     /// Direct children of the node.
     pub children: Vec<GltfNode>,
@@ -120,7 +123,7 @@ pub struct GltfNode {
 /// and an optional [`GltfExtras`].
 ///
 /// See [the relevant glTF specification section](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#reference-mesh).
-#[derive(Asset, Debug, Clone, TypePath)]
+#[derive(Asset, Debug, Clone, PartialEq, TypePath)]
 pub struct GltfMesh {
     /// Primitives of the glTF mesh.
     pub primitives: Vec<GltfPrimitive>,
@@ -131,7 +134,7 @@ pub struct GltfMesh {
 /// Part of a [`GltfMesh`] that consists of a [`Mesh`], an optional [`StandardMaterial`] and [`GltfExtras`].
 ///
 /// See [the relevant glTF specification section](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#reference-mesh-primitive).
-#[derive(Asset, Debug, Clone, TypePath)]
+#[derive(Asset, Debug, Clone, PartialEq, TypePath)]
 pub struct GltfPrimitive {
     /// Topology to be rendered.
     pub mesh: Handle<Mesh>,
@@ -146,7 +149,7 @@ pub struct GltfPrimitive {
 /// Additional untyped data that can be present on most glTF types.
 ///
 /// See [the relevant glTF specification section](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#reference-extras).
-#[derive(Clone, Debug, Reflect, Default, Component)]
+#[derive(Clone, Debug, PartialEq, Eq, Reflect, Default, Component)]
 #[reflect(Component)]
 pub struct GltfExtras {
     /// Content of the extra data.
@@ -0,0 +1,106 @@
+//! Input helpers for [`AppHarness`].
+
+use crate::{ButtonInput, ButtonState, InputSystem};
+use bevy_app::{AppHarness, PreUpdate};
+use bevy_ecs::prelude::*;
+use std::hash::Hash;
+
+/// Extension trait to press and release buttons in an [`AppHarness`].
+///
+/// Presses and releases are applied to the [`ButtonInput<T>`] resource on the next frame, right
+/// after the [`InputSystem`] set. The app sees them in that frame as if they came from a device,
+/// including [`ButtonInput::just_pressed`] and [`ButtonInput::just_released`].
+///
+/// ```
+/// # use bevy_app::{App, AppHarness};
+/// # use bevy_input::{harness::InputHarnessExt, prelude::*, InputPlugin};
+/// let mut app = App::new();
+/// app.add_plugins(InputPlugin);
+///
+/// let mut harness = AppHarness::new(app);
+/// harness.press(KeyCode::Space).update();
+/// assert!(harness
+///     .world()
+///     .resource::<ButtonInput<KeyCode>>()
+///     .just_pressed(KeyCode::Space));
+/// ```
+pub trait InputHarnessExt {
+    /// Presses `input` on the next frame.
+    fn press<T: Copy + Eq + Hash + Send + Sync + 'static>(&mut self, input: T) -> &mut Self;
+
+    /// Releases `input` on the next frame.
+    fn release<T: Copy + Eq + Hash + Send + Sync + 'static>(&mut self, input: T) -> &mut Self;
+}
+
+impl InputHarnessExt for AppHarness {
+    fn press<T: Copy + Eq + Hash + Send + Sync + 'static>(&mut self, input: T) -> &mut Self {
+        queue_button_input(self, input, ButtonState::Pressed);
+        self
+    }
+
+    fn release<T: Copy + Eq + Hash + Send + Sync + 'static>(&mut self, input: T) -> &mut Self {
+        queue_button_input(self, input, ButtonState::Released);
+        self
+    }
+}
+
+/// Button changes queued by an [`AppHarness`], in order.
+#[derive(Resource)]
+struct QueuedButtonInput<T: Copy + Eq + Hash + Send + Sync + 'static>(Vec<(T, ButtonState)>);
+
+fn queue_button_input<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    harness: &mut AppHarness,
+    input: T,
+    state: ButtonState,
+) {
+    let app = harness.app_mut();
+    if !app.world.contains_resource::<QueuedButtonInput<T>>() {
+        app.init_resource::<ButtonInput<T>>()
+            .insert_resource(QueuedButtonInput::<T>(Vec::new()))
+            .add_systems(PreUpdate, apply_queued_button_input::<T>.after(InputSystem));
+    }
+    app.world
+        .resource_mut::<QueuedButtonInput<T>>()
+        .0
+        .push((input, state));
+}
+
+fn apply_queued_button_input<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    mut queued: ResMut<QueuedButtonInput<T>>,
+    mut button_input: ResMut<ButtonInput<T>>,
+) {
+    for (input, state) in queued.0.drain(..) {
+        match state {
+            ButtonState::Pressed => button_input.press(input),
+            ButtonState::Released => button_input.release(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputHarnessExt;
+    use crate::{keyboard::KeyCode, ButtonInput, InputPlugin};
+    use bevy_app::{App, AppHarness};
+
+    #[test]
+    fn press_and_release() {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin);
+        let mut harness = AppHarness::new(app);
+
+        harness.press(KeyCode::KeyA).update();
+        let input = harness.world().resource::<ButtonInput<KeyCode>>();
+        assert!(input.just_pressed(KeyCode::KeyA));
+
+        harness.update();
+        let input = harness.world().resource::<ButtonInput<KeyCode>>();
+        assert!(input.pressed(KeyCode::KeyA));
+        assert!(!input.just_pressed(KeyCode::KeyA));
+
+        harness.release(KeyCode::KeyA).update();
+        let input = harness.world().resource::<ButtonInput<KeyCode>>();
+        assert!(input.just_released(KeyCode::KeyA));
+        assert!(!input.pressed(KeyCode::KeyA));
+    }
+}
@@ -11,6 +11,7 @@ mod button_input;
 /// Common run conditions
 pub mod common_conditions;
 pub mod gamepad;
+pub mod harness;
 pub mod keyboard;
 pub mod mouse;
 pub mod touch;
@@ -49,7 +50,7 @@ use gamepad::{
     gamepad_event_system, AxisSettings, ButtonAxisSettings, ButtonSettings, Gamepad, GamepadAxis,
     GamepadAxisChangedEvent, GamepadAxisType, GamepadButton, GamepadButtonChangedEvent,
     GamepadButtonInput, GamepadButtonType, GamepadConnection, GamepadConnectionEvent, GamepadEvent,
-    GamepadRumbleRequest, GamepadSettings, Gamepads,
+    GamepadInfo, GamepadRumbleRequest, GamepadSettings, Gamepads,
 };
 
 #[cfg(feature = "serialize")]
@@ -134,7 +135,12 @@ impl Plugin for InputPlugin {
 
         // Register gamepad types
         app.register_type::<Gamepad>()
+            .register_type::<GamepadInfo>()
             .register_type::<GamepadConnection>()
+            .register_type::<GamepadConnectionEvent>()
+            .register_type::<GamepadButtonChangedEvent>()
+            .register_type::<GamepadAxisChangedEvent>()
+            .register_type::<GamepadEvent>()
             .register_type::<GamepadButtonType>()
             .register_type::<GamepadButton>()
             .register_type::<GamepadButtonInput>()
@@ -0,0 +1,176 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Write as _},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use bevy_ecs::system::Resource;
+use bevy_utils::{
+    tracing::{
+        field::{Field, Visit},
+        Event, Level, Subscriber,
+    },
+    SystemTime,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// A log record kept by the [`LogBuffer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    /// The level of the record.
+    pub level: Level,
+    /// The target of the record, usually the module it was logged from.
+    pub target: String,
+    /// The names of the spans the record was logged in, from the outermost one, separated by `:`.
+    pub span: Option<String>,
+    /// The message of the record, followed by its other fields.
+    pub message: String,
+    /// When the record was logged.
+    pub timestamp: SystemTime,
+}
+
+/// The last log records of the app, for in-game consoles or bug reports.
+///
+/// The [`LogPlugin`](crate::LogPlugin) inserts this resource when
+/// [`LogPlugin::capture`](crate::LogPlugin::capture) is set. Only the records passing the
+/// [`LogFilter`](crate::LogFilter) are kept, and the oldest ones are dropped once the buffer is
+/// full.
+///
+/// Cloning the buffer returns a handle to the same records.
+#[derive(Resource, Clone)]
+pub struct LogBuffer {
+    shared: Arc<Mutex<LogBufferState>>,
+}
+
+struct LogBufferState {
+    capacity: usize,
+    records: VecDeque<LogRecord>,
+}
+
+impl LogBuffer {
+    /// Creates a buffer keeping the last `capacity` records.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            shared: Arc::new(Mutex::new(LogBufferState {
+                capacity,
+                records: VecDeque::with_capacity(capacity),
+            })),
+        }
+    }
+
+    /// Returns the maximum number of records kept.
+    pub fn capacity(&self) -> usize {
+        self.state().capacity
+    }
+
+    /// Returns the records kept, oldest first.
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.state().records.iter().cloned().collect()
+    }
+
+    /// Removes all records.
+    pub fn clear(&self) {
+        self.state().records.clear();
+    }
+
+    /// Returns the `tracing` layer recording logs into this buffer.
+    ///
+    /// This is done by the [`LogPlugin`](crate::LogPlugin), and only needed when setting up a
+    /// custom subscriber.
+    pub fn layer(&self) -> LogBufferLayer {
+        LogBufferLayer {
+            buffer: self.clone(),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, LogBufferState> {
+        self.shared.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn push(&self, record: LogRecord) {
+        let mut state = self.state();
+        if state.capacity == 0 {
+            return;
+        }
+        if state.records.len() == state.capacity {
+            state.records.pop_front();
+        }
+        state.records.push_back(record);
+    }
+}
+
+/// The `tracing` layer recording logs into a [`LogBuffer`].
+pub struct LogBufferLayer {
+    buffer: LogBuffer,
+}
+
+/// Formats the message of an event, followed by its other fields.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            write!(self.message, "{value:?}").unwrap();
+        } else {
+            write!(self.fields, " {}={value:?}", field.name()).unwrap();
+        }
+    }
+}
+
+impl<S> Layer<S> for LogBufferLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let span = ctx.event_scope(event).map(|scope| {
+            let names = scope.from_root().map(|span| span.name());
+            names.collect::<Vec<_>>().join(":")
+        });
+
+        let metadata = event.metadata();
+        self.buffer.push(LogRecord {
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            span,
+            message: visitor.message + &visitor.fields,
+            timestamp: SystemTime::now(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogBuffer;
+    use bevy_utils::tracing::{self, Level};
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    #[test]
+    fn keeps_last_records() {
+        let buffer = LogBuffer::new(2);
+        let subscriber = Registry::default().with(buffer.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("first");
+            let _span = tracing::info_span!("outer").entered();
+            let _span = tracing::info_span!("inner").entered();
+            tracing::warn!(target: "my_game", "second");
+            tracing::error!(count = 3, "third {}", 3);
+        });
+
+        let records = buffer.records();
+        assert_eq!(2, records.len());
+        assert_eq!(Level::WARN, records[0].level);
+        assert_eq!("my_game", records[0].target);
+        assert_eq!(Some("outer:inner"), records[0].span.as_deref());
+        assert_eq!("second", records[0].message);
+        assert_eq!("third 3 count=3", records[1].message);
+
+        buffer.clear();
+        assert!(buffer.records().is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+};
+
+/// Settings of the log file written by the [`LogPlugin`](crate::LogPlugin).
+///
+/// Once the file would grow past [`max_size`](Self::max_size), it is rotated: it's renamed to
+/// `<path>.1`, the previous `<path>.1` to `<path>.2` and so on, and a new file is started. Only
+/// [`max_files`](Self::max_files) rotated files are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFileSettings {
+    /// The file logs are written to. Logs are appended if it already exists.
+    pub path: PathBuf,
+    /// The size of the file above which it is rotated, in bytes.
+    pub max_size: u64,
+    /// The number of rotated files kept.
+    pub max_files: usize,
+}
+
+impl LogFileSettings {
+    /// Writes logs to `path`, rotating it every 10 MiB and keeping 5 rotated files.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            max_size: 10 * 1024 * 1024,
+            max_files: 5,
+        }
+    }
+}
+
+/// A log file, rotated according to its [`LogFileSettings`].
+pub(crate) struct RotatingFile {
+    settings: LogFileSettings,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFile {
+    pub(crate) fn open(settings: LogFileSettings) -> io::Result<Self> {
+        if let Some(parent) = settings.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&settings.path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            settings,
+            file,
+            size,
+        })
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = self.settings.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        path.into()
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let max_files = self.settings.max_files;
+        if max_files == 0 {
+            fs::remove_file(&self.settings.path)?;
+        } else {
+            for index in (1..max_files).rev() {
+                match fs::rename(self.rotated_path(index), self.rotated_path(index + 1)) {
+                    Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                    _ => {}
+                }
+            }
+            fs::rename(&self.settings.path, self.rotated_path(1))?;
+        }
+        self.file = File::create(&self.settings.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.settings.max_size {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LogFileSettings, RotatingFile};
+    use std::{fs, io::Write};
+
+    #[test]
+    fn rotate_by_size() {
+        let dir = std::env::temp_dir().join(format!("bevy_log_rotation_{}", std::process::id()));
+        let path = dir.join("app.log");
+        let mut file = RotatingFile::open(LogFileSettings {
+            path: path.clone(),
+            max_size: 10,
+            max_files: 2,
+        })
+        .unwrap();
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+
+        let read = |name: &str| fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!("fourth\n", read("app.log"));
+        assert_eq!("third\n", read("app.log.1"));
+        assert_eq!("second\n", read("app.log.2"));
+        assert!(!dir.join("app.log.3").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use bevy_ecs::system::Resource;
+use thiserror::Error;
+use tracing_subscriber::{
+    filter::{LevelFilter, ParseError},
+    layer::Layered,
+    reload, EnvFilter, Registry,
+};
+
+use crate::BoxedLayer;
+
+/// The subscriber the [`LogFilter`] is applied to.
+pub(crate) type FilteredSubscriber = Layered<Option<BoxedLayer>, Registry>;
+
+/// Errors that can occur when changing the [`LogFilter`].
+#[derive(Debug, Error)]
+pub enum LogFilterError {
+    /// The filter isn't in the [`EnvFilter`] format.
+    #[error("invalid log filter: {0}")]
+    Parse(#[from] ParseError),
+    /// The subscriber using the filter was dropped.
+    #[error("cannot update the log filter: {0}")]
+    Reload(#[from] reload::Error),
+}
+
+/// Changes which logs are recorded while the app runs.
+///
+/// The [`LogPlugin`](crate::LogPlugin) inserts this resource, starting from the filter set up by
+/// [`LogPlugin::level`](crate::LogPlugin::level) and [`LogPlugin::filter`](crate::LogPlugin::filter),
+/// or by the `RUST_LOG` environment variable. Levels set per target take precedence over it.
+///
+/// ```
+/// # use bevy_ecs::system::Res;
+/// # use bevy_log::{LevelFilter, LogFilter};
+/// fn debug_rendering(filter: Res<LogFilter>) {
+///     filter.set_target_level("bevy_render", LevelFilter::DEBUG).unwrap();
+/// }
+/// ```
+#[derive(Resource, Clone)]
+pub struct LogFilter {
+    handle: reload::Handle<EnvFilter, FilteredSubscriber>,
+    directives: Arc<Mutex<FilterDirectives>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct FilterDirectives {
+    base: String,
+    targets: BTreeMap<String, LevelFilter>,
+}
+
+impl FilterDirectives {
+    fn to_filter_string(&self) -> String {
+        let targets = self
+            .targets
+            .iter()
+            .map(|(target, level)| format!("{target}={level}"));
+        let directives = std::iter::once(self.base.clone())
+            .filter(|base| !base.is_empty())
+            .chain(targets)
+            .collect::<Vec<_>>();
+        directives.join(",")
+    }
+}
+
+impl LogFilter {
+    pub(crate) fn new(base: String, handle: reload::Handle<EnvFilter, FilteredSubscriber>) -> Self {
+        Self {
+            handle,
+            directives: Arc::new(Mutex::new(FilterDirectives {
+                base,
+                targets: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// Returns the current filter, in the [`EnvFilter`] format.
+    pub fn filter(&self) -> String {
+        self.directives().to_filter_string()
+    }
+
+    /// Replaces the filter set up at startup, keeping the levels set per target.
+    ///
+    /// `filter` uses the [`EnvFilter`] format, like [`LogPlugin::filter`](crate::LogPlugin::filter).
+    pub fn set_filter(&self, filter: impl Into<String>) -> Result<(), LogFilterError> {
+        self.update(|directives| directives.base = filter.into())
+    }
+
+    /// Sets the maximum level of the logs of `target` and its modules.
+    pub fn set_target_level(
+        &self,
+        target: impl Into<String>,
+        level: LevelFilter,
+    ) -> Result<(), LogFilterError> {
+        self.update(|directives| {
+            directives.targets.insert(target.into(), level);
+        })
+    }
+
+    /// Removes the level set for `target`, falling back to the filter.
+    pub fn reset_target_level(&self, target: &str) -> Result<(), LogFilterError> {
+        self.update(|directives| {
+            directives.targets.remove(target);
+        })
+    }
+
+    /// Returns the levels set per target.
+    pub fn target_levels(&self) -> Vec<(String, LevelFilter)> {
+        let directives = self.directives();
+        let targets = directives.targets.iter();
+        targets
+            .map(|(target, level)| (target.clone(), *level))
+            .collect()
+    }
+
+    fn directives(&self) -> std::sync::MutexGuard<'_, FilterDirectives> {
+        self.directives
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Applies `update` to the directives, keeping the previous ones if the result is invalid.
+    fn update(&self, update: impl FnOnce(&mut FilterDirectives)) -> Result<(), LogFilterError> {
+        let mut directives = self.directives();
+        let mut updated = directives.clone();
+        update(&mut updated);
+        let filter = EnvFilter::try_new(updated.to_filter_string())?;
+        self.handle.reload(filter)?;
+        *directives = updated;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LogFilter, LogFilterError};
+    use crate::BoxedLayer;
+    use bevy_utils::tracing::{self, Level};
+    use tracing_subscriber::{
+        filter::LevelFilter, layer::SubscriberExt, reload, EnvFilter, Registry,
+    };
+
+    #[test]
+    fn change_filter_at_runtime() {
+        let (layer, handle) = reload::Layer::new(EnvFilter::new("info"));
+        let filter = LogFilter::new("info".to_string(), handle);
+        let subscriber = Registry::default().with(None::<BoxedLayer>).with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            assert!(!tracing::enabled!(target: "my_game::ai", Level::DEBUG));
+
+            filter
+                .set_target_level("my_game", LevelFilter::DEBUG)
+                .unwrap();
+            assert_eq!("info,my_game=debug", filter.filter());
+            assert!(tracing::enabled!(target: "my_game::ai", Level::DEBUG));
+            assert!(!tracing::enabled!(target: "other", Level::DEBUG));
+
+            filter.set_filter("warn").unwrap();
+            assert!(!tracing::enabled!(target: "other", Level::INFO));
+            assert!(tracing::enabled!(target: "my_game::ai", Level::DEBUG));
+
+            filter.reset_target_level("my_game").unwrap();
+            assert!(!tracing::enabled!(target: "my_game::ai", Level::DEBUG));
+            assert_eq!("warn", filter.filter());
+        });
+    }
+
+    #[test]
+    fn invalid_filter_is_rejected() {
+        let (_layer, handle) = reload::Layer::new(EnvFilter::new("info"));
+        let filter = LogFilter::new("info".to_string(), handle);
+        assert!(matches!(
+            filter.set_filter("my_game=loud"),
+            Err(LogFilterError::Parse(_))
+        ));
+        assert_eq!("info", filter.filter());
+    }
+}
@@ -11,7 +11,12 @@
 //! For more fine-tuned control over logging behavior, set up the [`LogPlugin`] or
 //! `DefaultPlugins` during app initialization.
 
+mod capture;
+#[cfg(not(target_arch = "wasm32"))]
+mod file;
+mod filter;
 mod once;
+mod profiler;
 
 #[cfg(feature = "trace")]
 use std::panic;
@@ -35,15 +40,23 @@ pub mod prelude {
 }
 
 pub use bevy_utils::tracing::{
-    debug, debug_span, error, error_span, info, info_span, trace, trace_span, warn, warn_span,
-    Level,
+    debug, debug_span, error, error_span, info, info_span, level_filters::LevelFilter, trace,
+    trace_span, warn, warn_span, Level,
 };
 
+pub use capture::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use file::LogFileSettings;
+pub use filter::*;
+pub use profiler::*;
+
+pub use tracing_subscriber;
+
 use bevy_app::{App, Plugin};
 use tracing_log::LogTracer;
 #[cfg(feature = "tracing-chrome")]
 use tracing_subscriber::fmt::{format::DefaultFields, FormattedFields};
-use tracing_subscriber::{prelude::*, registry::Registry, EnvFilter};
+use tracing_subscriber::{prelude::*, registry::Registry, reload, EnvFilter, Layer};
 
 /// Adds logging to Apps. This plugin is part of the `DefaultPlugins`. Adding
 /// this plugin will setup a collector appropriate to your target platform:
@@ -64,6 +77,7 @@ use tracing_subscriber::{prelude::*, registry::Registry, EnvFilter};
 ///         .add_plugins(DefaultPlugins.set(LogPlugin {
 ///             level: Level::DEBUG,
 ///             filter: "wgpu=error,bevy_render=info,bevy_ecs=trace".to_string(),
+///             ..Default::default()
 ///         }))
 ///         .run();
 /// }
@@ -76,6 +90,11 @@ use tracing_subscriber::{prelude::*, registry::Registry, EnvFilter};
 /// If you define the `RUST_LOG` environment variable, the [`LogPlugin`] settings
 /// will be ignored.
 ///
+/// Filters can be changed while the app runs through the [`LogFilter`] resource.
+///
+/// Logs can also be written to a file, with [`LogPlugin::file`], and the last log records can be
+/// kept in the [`LogBuffer`] resource, with [`LogPlugin::capture`].
+///
 /// If you want to setup your own tracing collector, you should disable this
 /// plugin from `DefaultPlugins`:
 /// ```no_run
@@ -100,19 +119,38 @@ pub struct LogPlugin {
     /// Filters out logs that are "less than" the given level.
     /// This can be further filtered using the `filter` setting.
     pub level: Level,
+
+    /// Keeps this many of the last log records in the [`LogBuffer`] resource.
+    pub capture: Option<usize>,
+
+    /// Writes logs to a file, rotating it by size. This isn't supported in WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub file: Option<LogFileSettings>,
+
+    /// Returns an extra layer added to the subscriber, for example to record spans from a plugin.
+    ///
+    /// This is called while building the plugin, so the layer can share state with resources
+    /// inserted in the app.
+    pub custom_layer: fn(app: &mut App) -> Option<BoxedLayer>,
 }
 
+/// A boxed [`Layer`] that can be used with [`LogPlugin::custom_layer`].
+pub type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync + 'static>;
+
 impl Default for LogPlugin {
     fn default() -> Self {
         Self {
             filter: "wgpu=error,naga=warn".to_string(),
             level: Level::INFO,
+            capture: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            file: None,
+            custom_layer: |_| None,
         }
     }
 }
 
 impl Plugin for LogPlugin {
-    #[cfg_attr(not(feature = "tracing-chrome"), allow(unused_variables))]
     fn build(&self, app: &mut App) {
         #[cfg(feature = "trace")]
         {
@@ -125,10 +163,45 @@ impl Plugin for LogPlugin {
 
         let finished_subscriber;
         let default_filter = { format!("{},{}", self.level, self.filter) };
-        let filter_layer = EnvFilter::try_from_default_env()
-            .or_else(|_| EnvFilter::try_new(&default_filter))
-            .unwrap();
-        let subscriber = Registry::default().with(filter_layer);
+        let (filter, filter_layer) = std::env::var(EnvFilter::DEFAULT_ENV)
+            .ok()
+            .and_then(|filter| Some((filter.clone(), EnvFilter::try_new(filter).ok()?)))
+            .unwrap_or_else(|| {
+                let filter_layer = EnvFilter::try_new(&default_filter).unwrap();
+                (default_filter, filter_layer)
+            });
+        let (filter_layer, filter_handle) = reload::Layer::new(filter_layer);
+        app.insert_resource(LogFilter::new(filter, filter_handle));
+
+        let profiler = FrameProfiler::default();
+        let log_buffer = self.capture.map(LogBuffer::new);
+        let subscriber = Registry::default()
+            .with((self.custom_layer)(app))
+            .with(filter_layer)
+            .with(profiler.layer())
+            .with(log_buffer.as_ref().map(LogBuffer::layer));
+        app.insert_resource(profiler);
+        if let Some(log_buffer) = log_buffer {
+            app.insert_resource(log_buffer);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut file_error = None;
+        #[cfg(not(target_arch = "wasm32"))]
+        let subscriber = subscriber.with(self.file.clone().and_then(|settings| {
+            let path = settings.path.clone();
+            match file::RotatingFile::open(settings) {
+                Ok(file) => Some(
+                    tracing_subscriber::fmt::Layer::default()
+                        .with_ansi(false)
+                        .with_writer(std::sync::Mutex::new(file)),
+                ),
+                Err(err) => {
+                    file_error = Some((path, err));
+                    None
+                }
+            }
+        }));
 
         #[cfg(feature = "trace")]
         let subscriber = subscriber.with(tracing_error::ErrorLayer::default());
@@ -207,5 +280,10 @@ impl Plugin for LogPlugin {
             (_, true) => warn!("Could not set global tracing subscriber as it is already set. Consider disabling LogPlugin."),
             _ => (),
         }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some((path, err)) = file_error {
+            warn!("Could not open log file {}: {err}", path.display());
+        }
     }
 }
@@ -0,0 +1,480 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Write as _},
+    io,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+};
+
+use bevy_ecs::system::Resource;
+use bevy_utils::{
+    tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id},
+        Subscriber,
+    },
+    Duration, HashMap, Instant,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// The names of the spans collected by the [`FrameProfiler`].
+///
+/// These are the spans emitted for schedules and systems by `bevy_ecs`, and for
+/// render graphs and their nodes by `bevy_render`.
+pub const PROFILED_SPANS: &[&str] = &[
+    "schedule",
+    "system",
+    "system_commands",
+    "check_conditions",
+    "run_graph",
+    "node",
+    "submit_graph_commands",
+    "present_frames",
+];
+
+/// An in-app profiler, recording the timings of the last frames in memory.
+///
+/// The [`LogPlugin`](crate::LogPlugin) installs the profiler as a `tracing` layer and inserts it
+/// as a resource. While [enabled](Self::set_enabled), it records the [`PROFILED_SPANS`] into a ring
+/// buffer of frames. A frame ends when the `update` span of the app closes, or when
+/// [`end_frame`](Self::end_frame) is called.
+///
+/// The spans are only emitted when Bevy is built with the `trace` feature, but no other feature
+/// or external tool is needed: a [`capture`](Self::capture) of the recorded frames can be exported
+/// to the Chrome trace format, and viewed in `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+///
+/// Cloning the profiler returns a handle to the same recordings.
+#[derive(Resource, Clone, Default)]
+pub struct FrameProfiler {
+    shared: Arc<ProfilerShared>,
+}
+
+struct ProfilerShared {
+    enabled: AtomicBool,
+    max_frames: AtomicUsize,
+    epoch: Instant,
+    current: Mutex<Vec<ProfiledSpan>>,
+    frames: Mutex<VecDeque<ProfiledFrame>>,
+}
+
+impl Default for ProfilerShared {
+    fn default() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            max_frames: AtomicUsize::new(FrameProfiler::DEFAULT_MAX_FRAMES),
+            epoch: Instant::now(),
+            current: Mutex::default(),
+            frames: Mutex::default(),
+        }
+    }
+}
+
+/// A span recorded by the [`FrameProfiler`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfiledSpan {
+    /// The name of the span, such as `system` or `node`.
+    pub span: &'static str,
+    /// The value of the `name` field of the span, such as the name of the system.
+    pub name: Option<String>,
+    /// An identifier of the thread the span ran on.
+    pub thread: u64,
+    /// When the span was entered, relative to the creation of the profiler.
+    pub start: Duration,
+    /// How long the span was entered.
+    pub duration: Duration,
+}
+
+/// The spans recorded by the [`FrameProfiler`] during a frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfiledFrame {
+    /// The spans of the frame, in the order they were exited.
+    pub spans: Vec<ProfiledSpan>,
+}
+
+/// The frames captured from a [`FrameProfiler`], oldest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfilerCapture {
+    /// The captured frames.
+    pub frames: Vec<ProfiledFrame>,
+}
+
+/// Timing statistics of the spans with the same name in a [`ProfilerCapture`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanSummary {
+    /// The value of the `name` field of the spans.
+    pub name: String,
+    /// How many times the span was entered.
+    pub count: usize,
+    /// The shortest duration of the span.
+    pub min: Duration,
+    /// The average duration of the span.
+    pub avg: Duration,
+    /// The longest duration of the span.
+    pub max: Duration,
+}
+
+impl FrameProfiler {
+    /// The number of frames kept by default.
+    pub const DEFAULT_MAX_FRAMES: usize = 120;
+
+    /// Returns whether spans are being recorded.
+    pub fn is_enabled(&self) -> bool {
+        self.shared.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Starts or stops recording spans. Recorded frames are kept when the profiler is disabled.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.shared.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns the number of frames kept in the ring buffer.
+    pub fn max_frames(&self) -> usize {
+        self.shared.max_frames.load(Ordering::Relaxed)
+    }
+
+    /// Sets the number of frames kept in the ring buffer, dropping the oldest frames if needed.
+    pub fn set_max_frames(&self, max_frames: usize) {
+        self.shared.max_frames.store(max_frames, Ordering::Relaxed);
+        let mut frames = self.frames();
+        while frames.len() > max_frames {
+            frames.pop_front();
+        }
+    }
+
+    /// Ends the current frame, moving its spans into the ring buffer.
+    pub fn end_frame(&self) {
+        let spans = std::mem::take(
+            &mut *self
+                .shared
+                .current
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        if spans.is_empty() && !self.is_enabled() {
+            return;
+        }
+        let max_frames = self.max_frames();
+        let mut frames = self.frames();
+        frames.push_back(ProfiledFrame { spans });
+        while frames.len() > max_frames {
+            frames.pop_front();
+        }
+    }
+
+    /// Returns a copy of the frames in the ring buffer.
+    pub fn capture(&self) -> ProfilerCapture {
+        ProfilerCapture {
+            frames: self.frames().iter().cloned().collect(),
+        }
+    }
+
+    /// Returns the `tracing` layer recording spans into this profiler.
+    ///
+    /// This is done by the [`LogPlugin`](crate::LogPlugin), and only needed when setting up a
+    /// custom subscriber.
+    pub fn layer(&self) -> FrameProfilerLayer {
+        FrameProfilerLayer {
+            profiler: self.clone(),
+        }
+    }
+
+    fn frames(&self) -> std::sync::MutexGuard<'_, VecDeque<ProfiledFrame>> {
+        self.shared
+            .frames
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn record(&self, span: ProfiledSpan) {
+        self.shared
+            .current
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(span);
+    }
+}
+
+impl ProfilerCapture {
+    /// Returns the minimum, average and maximum durations of each system, sorted by decreasing
+    /// average duration.
+    pub fn system_summaries(&self) -> Vec<SpanSummary> {
+        self.summaries("system")
+    }
+
+    /// Returns the minimum, average and maximum durations of the spans named `span` for each
+    /// value of their `name` field, sorted by decreasing average duration.
+    pub fn summaries(&self, span: &str) -> Vec<SpanSummary> {
+        let mut durations = HashMap::<&str, Vec<Duration>>::default();
+        for profiled in self.frames.iter().flat_map(|frame| &frame.spans) {
+            if let (true, Some(name)) = (profiled.span == span, &profiled.name) {
+                durations.entry(name).or_default().push(profiled.duration);
+            }
+        }
+
+        let mut summaries = durations
+            .into_iter()
+            .map(|(name, durations)| SpanSummary {
+                name: name.to_string(),
+                count: durations.len(),
+                min: durations.iter().copied().min().unwrap_or_default(),
+                avg: durations.iter().sum::<Duration>() / durations.len() as u32,
+                max: durations.iter().copied().max().unwrap_or_default(),
+            })
+            .collect::<Vec<_>>();
+        summaries.sort_by(|a, b| b.avg.cmp(&a.avg).then_with(|| a.name.cmp(&b.name)));
+        summaries
+    }
+
+    /// Returns the capture in the Chrome trace event JSON format.
+    ///
+    /// Each span becomes a complete event named after its `name` field, with the span name as
+    /// its category.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut json = String::from("{\"traceEvents\":[");
+        let spans = self.frames.iter().flat_map(|frame| &frame.spans);
+        for (index, span) in spans.enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"name\":");
+            write_json_string(&mut json, span.name.as_deref().unwrap_or(span.span));
+            json.push_str(",\"cat\":");
+            write_json_string(&mut json, span.span);
+            write!(
+                json,
+                ",\"ph\":\"X\",\"pid\":1,\"tid\":{},\"ts\":{:.3},\"dur\":{:.3}}}",
+                span.thread,
+                span.start.as_secs_f64() * 1e6,
+                span.duration.as_secs_f64() * 1e6,
+            )
+            .unwrap();
+        }
+        json.push_str("],\"displayTimeUnit\":\"ms\"}");
+        json
+    }
+
+    /// Writes the capture to `path` in the Chrome trace event JSON format.
+    pub fn write_chrome_trace(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_chrome_trace())
+    }
+}
+
+fn write_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// The `tracing` layer recording spans into a [`FrameProfiler`].
+pub struct FrameProfilerLayer {
+    profiler: FrameProfiler,
+}
+
+/// The `name` field of a profiled span.
+struct SpanName(Option<String>);
+
+/// When a profiled span was entered.
+struct EnteredAt(Instant);
+
+#[derive(Default)]
+struct NameVisitor(Option<String>);
+
+impl Visit for NameVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "name" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "name" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+fn thread_id() -> u64 {
+    static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(0);
+    thread_local! {
+        static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+    }
+    THREAD_ID.with(|id| *id)
+}
+
+impl<S> Layer<S> for FrameProfilerLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        // Spans such as the system spans are created once and entered every frame, so their name
+        // is kept even while the profiler is disabled.
+        if !PROFILED_SPANS.contains(&attrs.metadata().name()) {
+            return;
+        }
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = NameVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanName(visitor.0));
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if !self.profiler.is_enabled() {
+            return;
+        }
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if extensions.get_mut::<SpanName>().is_some() {
+            extensions.replace(EnteredAt(Instant::now()));
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let metadata = span.metadata();
+        if metadata.name() == "update" && metadata.target().starts_with("bevy_app") {
+            self.profiler.end_frame();
+            return;
+        }
+
+        let mut extensions = span.extensions_mut();
+        let Some(EnteredAt(entered_at)) = extensions.remove::<EnteredAt>() else {
+            return;
+        };
+        let name = extensions
+            .get_mut::<SpanName>()
+            .and_then(|name| name.0.clone());
+        self.profiler.record(ProfiledSpan {
+            span: metadata.name(),
+            name,
+            thread: thread_id(),
+            start: entered_at.duration_since(self.profiler.shared.epoch),
+            duration: entered_at.elapsed(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FrameProfiler, ProfiledSpan};
+    use bevy_utils::{tracing, Duration};
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    fn span(name: &str, duration_ms: u64) -> ProfiledSpan {
+        ProfiledSpan {
+            span: "system",
+            name: Some(name.to_string()),
+            thread: 0,
+            start: Duration::ZERO,
+            duration: Duration::from_millis(duration_ms),
+        }
+    }
+
+    #[test]
+    fn records_profiled_spans_while_enabled() {
+        let profiler = FrameProfiler::default();
+        let subscriber = Registry::default().with(profiler.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            let system = tracing::info_span!("system", name = "my_system");
+            system.in_scope(|| {});
+            profiler.end_frame();
+
+            profiler.set_enabled(true);
+            system.in_scope(|| {});
+            tracing::info_span!("other").in_scope(|| {});
+            profiler.end_frame();
+            system.in_scope(|| {});
+        });
+        profiler.end_frame();
+
+        let capture = profiler.capture();
+        assert_eq!(2, capture.frames.len());
+        let names = capture
+            .frames
+            .iter()
+            .map(|frame| {
+                let spans = frame.spans.iter();
+                spans.map(|span| span.name.as_deref()).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![vec![Some("my_system")]; 2], names);
+    }
+
+    #[test]
+    fn ring_buffer_keeps_last_frames() {
+        let profiler = FrameProfiler::default();
+        profiler.set_enabled(true);
+        profiler.set_max_frames(2);
+        for duration in 1..=3 {
+            profiler.record(span("a", duration));
+            profiler.end_frame();
+        }
+
+        let capture = profiler.capture();
+        assert_eq!(2, capture.frames.len());
+        assert_eq!(
+            Duration::from_millis(2),
+            capture.frames[0].spans[0].duration
+        );
+    }
+
+    #[test]
+    fn summaries_and_chrome_trace() {
+        let profiler = FrameProfiler::default();
+        profiler.set_enabled(true);
+        profiler.record(span("fast", 1));
+        profiler.record(span("slow", 4));
+        profiler.end_frame();
+        profiler.record(span("fast", 3));
+        profiler.record(span("quo\"te", 1));
+        profiler.end_frame();
+
+        let capture = profiler.capture();
+        let summaries = capture.system_summaries();
+        let summaries = summaries
+            .iter()
+            .map(|summary| {
+                let millis = |duration: Duration| duration.as_millis();
+                let (min, avg, max) = (
+                    millis(summary.min),
+                    millis(summary.avg),
+                    millis(summary.max),
+                );
+                (summary.name.as_str(), summary.count, min, avg, max)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("slow", 1, 4, 4, 4),
+                ("fast", 2, 1, 2, 3),
+                ("quo\"te", 1, 1, 1, 1)
+            ],
+            summaries
+        );
+
+        let trace = capture.to_chrome_trace();
+        assert!(trace.starts_with(
+            "{\"traceEvents\":[{\"name\":\"fast\",\"cat\":\"system\",\"ph\":\"X\",\"pid\":1,\"tid\":0,\"ts\":0.000,\"dur\":1000.000}"
+        ));
+        assert!(trace.contains("\"name\":\"quo\\\"te\""));
+        assert!(trace.ends_with("],\"displayTimeUnit\":\"ms\"}"));
+    }
+}
@@ -264,6 +264,7 @@ impl Plugin for PbrPlugin {
                 LightmapPlugin,
                 LightProbePlugin,
             ))
+            .register_asset_partial_eq::<StandardMaterial>()
             .configure_sets(
                 PostUpdate,
                 (
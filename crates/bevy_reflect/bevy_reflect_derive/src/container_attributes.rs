@@ -5,6 +5,7 @@
 //! the derive helper attribute for `Reflect`, which looks like:
 //! `#[reflect(PartialEq, Default, ...)]` and `#[reflect_value(PartialEq, Default, ...)]`.
 
+use crate::custom_attributes::CustomAttributes;
 use crate::utility;
 use bevy_macro_utils::fq_std::{FQAny, FQOption};
 use proc_macro2::{Ident, Span};
@@ -13,7 +14,7 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Expr, LitBool, Meta, Path};
+use syn::{Expr, LitBool, Meta, Path, Token};
 
 // The "special" trait idents that are used internally for reflection.
 // Received via attributes like `#[reflect(PartialEq, Hash, ...)]`
@@ -211,10 +212,39 @@ pub(crate) struct ReflectTraits {
     partial_eq: TraitImpl,
     from_reflect_attrs: FromReflectAttrs,
     type_path_attrs: TypePathAttrs,
+    custom_attributes: CustomAttributes,
     idents: Vec<Ident>,
 }
 
 impl ReflectTraits {
+    /// Parses the contents of a `#[reflect(...)]` or `#[reflect_value(...)]` attribute.
+    ///
+    /// Custom attributes (`@expr`) may be freely mixed with the other comma-separated items.
+    pub fn parse_args(input: ParseStream, is_from_reflect_derive: bool) -> syn::Result<Self> {
+        let mut custom_attributes = CustomAttributes::default();
+        let mut metas = Punctuated::<Meta, Comma>::new();
+
+        while !input.is_empty() {
+            if input.peek(Token![@]) {
+                custom_attributes.parse_custom_attribute(input)?;
+            } else {
+                metas.push_value(input.parse()?);
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            let comma = input.parse::<Comma>()?;
+            if !metas.empty_or_trailing() {
+                metas.push_punct(comma);
+            }
+        }
+
+        let mut traits = ReflectTraits::from_metas(metas, is_from_reflect_derive)?;
+        traits.custom_attributes = custom_attributes;
+        Ok(traits)
+    }
+
     pub fn from_metas(
         metas: Punctuated<Meta, Comma>,
         is_from_reflect_derive: bool,
@@ -333,6 +363,11 @@ impl ReflectTraits {
         &self.type_path_attrs
     }
 
+    /// The custom attributes found within `#[reflect(...)]` attributes on this type.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// Returns the implementation of `Reflect::reflect_hash` as a `TokenStream`.
     ///
     /// If `Hash` was not registered, returns `None`.
@@ -411,6 +446,7 @@ impl ReflectTraits {
         self.partial_eq.merge(other.partial_eq)?;
         self.from_reflect_attrs.merge(other.from_reflect_attrs)?;
         self.type_path_attrs.merge(other.type_path_attrs)?;
+        self.custom_attributes.merge(other.custom_attributes);
         for ident in other.idents {
             add_unique_ident(&mut self.idents, ident)?;
         }
@@ -420,7 +456,7 @@ impl ReflectTraits {
 
 impl Parse for ReflectTraits {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        ReflectTraits::from_metas(Punctuated::<Meta, Comma>::parse_terminated(input)?, false)
+        ReflectTraits::parse_args(input, false)
     }
 }
 
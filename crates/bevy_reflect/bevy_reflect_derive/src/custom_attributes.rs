@@ -0,0 +1,46 @@
+//! Contains code related to custom attributes for reflected types, fields, and variants.
+//!
+//! A custom attribute is an arbitrary expression prefixed with `@` within the
+//! `#[reflect(...)]` helper attribute, such as `#[reflect(@RangeInclusive::<f32>::new(0.0, 1.0))]`.
+//! The expression is evaluated when the type info is built and stored by its type.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::{Expr, Path, Token};
+
+/// A collection of custom attribute expressions registered via `#[reflect(@expr)]`.
+#[derive(Default, Clone)]
+pub(crate) struct CustomAttributes {
+    attributes: Vec<Expr>,
+}
+
+impl CustomAttributes {
+    /// Generates a `.with_custom_attributes(...)` builder call for the type info,
+    /// or nothing if no custom attributes were registered.
+    pub fn to_tokens(&self, bevy_reflect_path: &Path) -> TokenStream {
+        if self.attributes.is_empty() {
+            return TokenStream::new();
+        }
+
+        let attributes = self.attributes.iter();
+        quote! {
+            .with_custom_attributes(
+                #bevy_reflect_path::CustomAttributes::default()
+                    #(.with_attribute(#attributes))*
+            )
+        }
+    }
+
+    /// Parses a single custom attribute of the form `@expr`.
+    pub fn parse_custom_attribute(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<Token![@]>()?;
+        self.attributes.push(input.parse()?);
+        Ok(())
+    }
+
+    /// Appends the custom attributes of `other` to this collection.
+    pub fn merge(&mut self, other: CustomAttributes) {
+        self.attributes.extend(other.attributes);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::container_attributes::{FromReflectAttrs, ReflectTraits};
 use crate::field_attributes::{parse_field_attrs, ReflectFieldAttr};
+use crate::remote::RemoteType;
 use crate::type_path::parse_path_no_leading_colon;
-use crate::utility::{StringExpr, WhereClauseOptions};
+use crate::utility::{ident_or_index, StringExpr, WhereClauseOptions};
 use quote::{quote, ToTokens};
 use syn::token::Comma;
 
@@ -10,6 +11,7 @@ use crate::{
     utility, REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME, TYPE_NAME_ATTRIBUTE_NAME,
     TYPE_PATH_ATTRIBUTE_NAME,
 };
+use syn::parse::ParseStream;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
@@ -45,6 +47,8 @@ pub(crate) struct ReflectMeta<'a> {
     type_path: ReflectTypePath<'a>,
     /// A cached instance of the path to the `bevy_reflect` crate.
     bevy_reflect_path: Path,
+    /// The remote type this type is a wrapper for, if it was created with `#[reflect_remote]`.
+    remote_ty: Option<RemoteType<'a>>,
     /// The documentation for this type, if any
     #[cfg(feature = "documentation")]
     docs: crate::documentation::Documentation,
@@ -115,7 +119,6 @@ pub(crate) struct EnumVariant<'a> {
     /// The fields within this variant.
     pub fields: EnumVariantFields<'a>,
     /// The reflection-based attributes on the variant.
-    #[allow(dead_code)]
     pub attrs: ReflectFieldAttr,
     /// The index of this variant within the enum.
     #[allow(dead_code)]
@@ -167,10 +170,9 @@ impl<'a> ReflectDerive<'a> {
                     }
 
                     reflect_mode = Some(ReflectMode::Normal);
-                    let new_traits = ReflectTraits::from_metas(
-                        meta_list.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?,
-                        is_from_reflect_derive,
-                    )?;
+                    let new_traits = meta_list.parse_args_with(|input: ParseStream| {
+                        ReflectTraits::parse_args(input, is_from_reflect_derive)
+                    })?;
                     traits.merge(new_traits)?;
                 }
                 Meta::List(meta_list) if meta_list.path.is_ident(REFLECT_VALUE_ATTRIBUTE_NAME) => {
@@ -182,10 +184,9 @@ impl<'a> ReflectDerive<'a> {
                     }
 
                     reflect_mode = Some(ReflectMode::Value);
-                    let new_traits = ReflectTraits::from_metas(
-                        meta_list.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?,
-                        is_from_reflect_derive,
-                    )?;
+                    let new_traits = meta_list.parse_args_with(|input: ParseStream| {
+                        ReflectTraits::parse_args(input, is_from_reflect_derive)
+                    })?;
                     traits.merge(new_traits)?;
                 }
                 Meta::Path(path) if path.is_ident(REFLECT_VALUE_ATTRIBUTE_NAME) => {
@@ -312,6 +313,17 @@ impl<'a> ReflectDerive<'a> {
         }
     }
 
+    /// Marks this type as a wrapper for the given remote type.
+    pub fn set_remote(&mut self, remote_ty: Option<RemoteType<'a>>) {
+        match self {
+            ReflectDerive::Struct(data)
+            | ReflectDerive::TupleStruct(data)
+            | ReflectDerive::UnitStruct(data) => data.meta.remote_ty = remote_ty,
+            ReflectDerive::Enum(data) => data.meta.remote_ty = remote_ty,
+            ReflectDerive::Value(meta) => meta.remote_ty = remote_ty,
+        }
+    }
+
     fn collect_struct_fields(fields: &'a Fields) -> Result<Vec<StructField<'a>>, syn::Error> {
         let mut active_index = 0;
         let sifter: utility::ResultSifter<StructField<'a>> = fields
@@ -384,6 +396,7 @@ impl<'a> ReflectMeta<'a> {
             traits,
             type_path,
             bevy_reflect_path: utility::get_bevy_reflect_path(),
+            remote_ty: None,
             #[cfg(feature = "documentation")]
             docs: Default::default(),
         }
@@ -416,6 +429,11 @@ impl<'a> ReflectMeta<'a> {
         &self.bevy_reflect_path
     }
 
+    /// The remote type this type wraps, if it was created with `#[reflect_remote]`.
+    pub fn remote_ty(&self) -> Option<&RemoteType<'a>> {
+        self.remote_ty.as_ref()
+    }
+
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
     pub fn get_type_registration(
         &self,
@@ -459,10 +477,33 @@ impl<'a> ReflectStruct<'a> {
     /// Get a collection of types which are exposed to the reflection API
     pub fn active_types(&self) -> Vec<Type> {
         self.active_fields()
-            .map(|field| field.data.ty.clone())
+            .map(|field| field.reflected_type().clone())
             .collect()
     }
 
+    /// Returns an expression borrowing the given field from `self`.
+    ///
+    /// For remote wrappers, the field is accessed through the wrapped remote value.
+    pub fn access_for_field(
+        &self,
+        field: &StructField<'a>,
+        is_mutable: bool,
+    ) -> proc_macro2::TokenStream {
+        let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+        let accessor = if self.meta.remote_ty().is_some() {
+            quote!(self.0.#member)
+        } else {
+            quote!(self.#member)
+        };
+
+        let reference = if is_mutable {
+            quote!(&mut #accessor)
+        } else {
+            quote!(&#accessor)
+        };
+        field.as_reflected_ref(reference, is_mutable, self.meta.bevy_reflect_path())
+    }
+
     /// Get an iterator of fields which are exposed to the reflection API
     pub fn active_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
         self.fields
@@ -495,13 +536,31 @@ impl<'a> ReflectEnum<'a> {
     }
 
     /// Returns the given ident as a qualified unit variant of this enum.
+    ///
+    /// For remote wrappers, this is a variant of the remote enum.
     pub fn get_unit(&self, variant: &Ident) -> proc_macro2::TokenStream {
+        if let Some(remote_ty) = self.meta.remote_ty() {
+            let name = remote_ty.as_expr_path();
+            return quote! {
+                #name::#variant
+            };
+        }
+
         let name = self.meta.type_path();
         quote! {
             #name::#variant
         }
     }
 
+    /// Returns the expression to match on when inspecting the current variant of `self`.
+    pub fn match_this(&self, is_mutable: bool) -> proc_macro2::TokenStream {
+        match (self.meta.remote_ty().is_some(), is_mutable) {
+            (false, _) => quote!(self),
+            (true, false) => quote!(&self.0),
+            (true, true) => quote!(&mut self.0),
+        }
+    }
+
     /// The complete set of variants in this enum.
     pub fn variants(&self) -> &[EnumVariant<'a>] {
         &self.variants
@@ -526,6 +585,51 @@ impl<'a> ReflectEnum<'a> {
     }
 }
 
+impl<'a> StructField<'a> {
+    /// The type used to reflect this field.
+    ///
+    /// This is the remote wrapper type if the field uses `#[reflect(remote = ...)]`.
+    pub fn reflected_type(&self) -> &Type {
+        self.attrs.remote.as_ref().unwrap_or(&self.data.ty)
+    }
+
+    /// Converts a reference to this field into a reference to its [reflected type].
+    ///
+    /// [reflected type]: Self::reflected_type
+    pub fn as_reflected_ref(
+        &self,
+        reference: proc_macro2::TokenStream,
+        is_mutable: bool,
+        bevy_reflect_path: &Path,
+    ) -> proc_macro2::TokenStream {
+        match (&self.attrs.remote, is_mutable) {
+            (None, _) => reference,
+            (Some(wrapper), false) => quote! {
+                <#wrapper as #bevy_reflect_path::ReflectRemote>::as_wrapper(#reference)
+            },
+            (Some(wrapper), true) => quote! {
+                <#wrapper as #bevy_reflect_path::ReflectRemote>::as_wrapper_mut(#reference)
+            },
+        }
+    }
+
+    /// Converts a value of this field's [reflected type] back into the field's declared type.
+    ///
+    /// [reflected type]: Self::reflected_type
+    pub fn convert_reflected_value(
+        &self,
+        value: proc_macro2::TokenStream,
+        bevy_reflect_path: &Path,
+    ) -> proc_macro2::TokenStream {
+        match &self.attrs.remote {
+            None => value,
+            Some(wrapper) => quote! {
+                <#wrapper as #bevy_reflect_path::ReflectRemote>::into_remote(#value)
+            },
+        }
+    }
+}
+
 impl<'a> EnumVariant<'a> {
     /// Get an iterator of fields which are exposed to the reflection API
     #[allow(dead_code)]
@@ -42,18 +42,18 @@ pub(crate) fn get_variant_constructors(
         let mut reflect_index: usize = 0;
         let constructor_fields = fields.iter().enumerate().map(|(declare_index, field)| {
             let field_ident = ident_or_index(field.data.ident.as_ref(), declare_index);
-            let field_ty = &field.data.ty;
+            let field_ty = field.reflected_type();
 
             let field_value = if field.attrs.ignore.is_ignored() {
                 match &field.attrs.default {
                     DefaultBehavior::Func(path) => quote! { #path() },
-                    _ => quote! { #FQDefault::default() }
+                    _ => quote! { #FQDefault::default() },
                 }
             } else {
                 let (resolve_error, resolve_missing) = if can_panic {
                     let field_ref_str = match &field_ident {
                         Member::Named(ident) => format!("the field `{ident}`"),
-                        Member::Unnamed(index) => format!("the field at index {}", index.index)
+                        Member::Unnamed(index) => format!("the field at index {}", index.index),
                     };
                     let ty = field.data.ty.to_token_stream();
 
@@ -74,33 +74,51 @@ pub(crate) fn get_variant_constructors(
                 };
                 reflect_index += 1;
 
-                match &field.attrs.default {
-                    DefaultBehavior::Func(path) => quote! {
-                        if let #FQOption::Some(field) = #field_accessor {
-                            <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                let from_reflect = |accessor: proc_macro2::TokenStream| {
+                    field.convert_reflected_value(
+                        quote! {
+                            <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#accessor)
                             #resolve_error
-                        } else {
-                            #path()
+                        },
+                        bevy_reflect_path,
+                    )
+                };
+
+                match &field.attrs.default {
+                    DefaultBehavior::Func(path) => {
+                        let value = from_reflect(quote!(field));
+                        quote! {
+                            if let #FQOption::Some(field) = #field_accessor {
+                                #value
+                            } else {
+                                #path()
+                            }
                         }
-                    },
-                    DefaultBehavior::Default => quote! {
-                        if let #FQOption::Some(field) = #field_accessor {
-                            <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
-                            #resolve_error
-                        } else {
-                            #FQDefault::default()
+                    }
+                    DefaultBehavior::Default => {
+                        let value = from_reflect(quote!(field));
+                        quote! {
+                            if let #FQOption::Some(field) = #field_accessor {
+                                #value
+                            } else {
+                                #FQDefault::default()
+                            }
                         }
-                    },
-                    DefaultBehavior::Required => quote! {
-                        <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#field_accessor #resolve_missing)
-                        #resolve_error
-                    },
+                    }
+                    DefaultBehavior::Required => {
+                        from_reflect(quote!(#field_accessor #resolve_missing))
+                    }
                 }
             };
             quote! { #field_ident : #field_value }
         });
-        variant_constructors.push(quote! {
+        let variant_constructor = quote! {
             #variant_constructor { #( #constructor_fields ),* }
+        };
+        variant_constructors.push(if reflect_enum.meta().remote_ty().is_some() {
+            quote!(Self(#variant_constructor))
+        } else {
+            variant_constructor
         });
         variant_names.push(name);
     }
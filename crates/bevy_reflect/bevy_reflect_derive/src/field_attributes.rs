@@ -4,15 +4,18 @@
 //! as opposed to an entire struct or enum. An example of such an attribute is
 //! the derive helper attribute for `Reflect`, which looks like: `#[reflect(ignore)]`.
 
+use crate::custom_attributes::CustomAttributes;
 use crate::REFLECT_ATTRIBUTE_NAME;
-use syn::meta::ParseNestedMeta;
-use syn::{Attribute, LitStr, Token};
+use syn::parse::ParseStream;
+use syn::{Attribute, Expr, ExprLit, ExprPath, Lit, Meta, Token, Type, TypePath};
 
 pub(crate) static IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
 pub(crate) static IGNORE_ALL_ATTR: &str = "ignore";
 
 pub(crate) static DEFAULT_ATTR: &str = "default";
 
+pub(crate) static REMOTE_ATTR: &str = "remote";
+
 /// Stores data about if the field should be visible via the Reflect and serialization interfaces
 ///
 /// Note the relationship between serialization and reflection is such that a member must be reflected in order to be serialized.
@@ -51,6 +54,10 @@ pub(crate) struct ReflectFieldAttr {
     pub ignore: ReflectIgnoreBehavior,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// Custom attributes created via `#[reflect(@...)]`.
+    pub custom_attributes: CustomAttributes,
+    /// The remote wrapper type to reflect this field through, set via `#[reflect(remote = Wrapper)]`.
+    pub remote: Option<Type>,
 }
 
 /// Controls how the default value is determined for a field.
@@ -69,6 +76,8 @@ pub(crate) enum DefaultBehavior {
 }
 
 /// Parse all field attributes marked "reflect" (such as `#[reflect(ignore)]`).
+///
+/// This is also used for the attributes of enum variants.
 pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr, syn::Error> {
     let mut args = ReflectFieldAttr::default();
     let mut errors: Option<syn::Error> = None;
@@ -77,7 +86,7 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
         .iter()
         .filter(|a| a.path().is_ident(REFLECT_ATTRIBUTE_NAME));
     for attr in attrs {
-        let result = attr.parse_nested_meta(|meta| parse_meta(&mut args, meta));
+        let result = attr.parse_args_with(|input: ParseStream| parse_args(&mut args, input));
         if let Err(err) = result {
             if let Some(ref mut error) = errors {
                 error.combine(err);
@@ -94,53 +103,125 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
     }
 }
 
-fn parse_meta(args: &mut ReflectFieldAttr, meta: ParseNestedMeta) -> Result<(), syn::Error> {
-    if meta.path.is_ident(DEFAULT_ATTR) {
+fn parse_args(args: &mut ReflectFieldAttr, input: ParseStream) -> Result<(), syn::Error> {
+    while !input.is_empty() {
+        if input.peek(Token![@]) {
+            args.custom_attributes.parse_custom_attribute(input)?;
+        } else {
+            parse_meta(args, &input.parse()?)?;
+        }
+
+        if input.is_empty() {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+    }
+
+    Ok(())
+}
+
+fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error> {
+    let path = meta.path();
+    if path.is_ident(DEFAULT_ATTR) {
         // Allow:
         // - `#[reflect(default)]`
         // - `#[reflect(default = "path::to::func")]`
         if !matches!(args.default, DefaultBehavior::Required) {
-            return Err(meta.error(format!("only one of [{:?}] is allowed", [DEFAULT_ATTR])));
+            return Err(syn::Error::new_spanned(
+                path,
+                format!("only one of [{:?}] is allowed", [DEFAULT_ATTR]),
+            ));
         }
 
-        if meta.input.peek(Token![=]) {
-            let lit = meta.value()?.parse::<LitStr>()?;
-            args.default = DefaultBehavior::Func(lit.parse()?);
-        } else {
-            args.default = DefaultBehavior::Default;
+        match meta {
+            Meta::Path(_) => args.default = DefaultBehavior::Default,
+            Meta::NameValue(pair) => {
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) = &pair.value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &pair.value,
+                        "expected a string literal containing the name of a function",
+                    ));
+                };
+                args.default = DefaultBehavior::Func(lit.parse()?);
+            }
+            Meta::List(list) => {
+                return Err(syn::Error::new_spanned(
+                    list,
+                    format!("expected `{DEFAULT_ATTR}` or `{DEFAULT_ATTR} = \"path::to::func\"`"),
+                ));
+            }
         }
 
         Ok(())
-    } else if meta.path.is_ident(IGNORE_ALL_ATTR) {
+    } else if path.is_ident(IGNORE_ALL_ATTR) || path.is_ident(IGNORE_SERIALIZATION_ATTR) {
         // Allow:
         // - `#[reflect(ignore)]`
+        // - `#[reflect(skip_serializing)]`
+        let Meta::Path(path) = meta else {
+            return Err(syn::Error::new_spanned(meta, "unexpected arguments"));
+        };
+
         if args.ignore != ReflectIgnoreBehavior::None {
-            return Err(meta.error(format!(
-                "only one of [{:?}] is allowed",
-                [IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR]
-            )));
+            return Err(syn::Error::new_spanned(
+                path,
+                format!(
+                    "only one of [{:?}] is allowed",
+                    [IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR]
+                ),
+            ));
         }
 
-        args.ignore = ReflectIgnoreBehavior::IgnoreAlways;
+        args.ignore = if path.is_ident(IGNORE_ALL_ATTR) {
+            ReflectIgnoreBehavior::IgnoreAlways
+        } else {
+            ReflectIgnoreBehavior::IgnoreSerialization
+        };
 
         Ok(())
-    } else if meta.path.is_ident(IGNORE_SERIALIZATION_ATTR) {
+    } else if path.is_ident(REMOTE_ATTR) {
         // Allow:
-        // - `#[reflect(skip_serializing)]`
-        if args.ignore != ReflectIgnoreBehavior::None {
-            return Err(meta.error(format!(
-                "only one of [{:?}] is allowed",
-                [IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR]
-            )));
+        // - `#[reflect(remote = path::to::Wrapper)]`
+        if args.remote.is_some() {
+            return Err(syn::Error::new_spanned(
+                path,
+                format!("only one of [{:?}] is allowed", [REMOTE_ATTR]),
+            ));
         }
 
-        args.ignore = ReflectIgnoreBehavior::IgnoreSerialization;
+        let Meta::NameValue(pair) = meta else {
+            return Err(syn::Error::new_spanned(
+                meta,
+                format!("expected `{REMOTE_ATTR} = path::to::Wrapper`"),
+            ));
+        };
+        let Expr::Path(ExprPath { qself, path, .. }) = &pair.value else {
+            return Err(syn::Error::new_spanned(
+                &pair.value,
+                "expected a path to the remote wrapper type",
+            ));
+        };
+
+        args.remote = Some(Type::Path(TypePath {
+            qself: qself.clone(),
+            path: path.clone(),
+        }));
 
         Ok(())
     } else {
-        Err(meta.error(format!(
-            "unknown attribute, expected {:?}",
-            [DEFAULT_ATTR, IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR]
-        )))
+        Err(syn::Error::new_spanned(
+            path,
+            format!(
+                "unknown attribute, expected {:?}",
+                [
+                    DEFAULT_ATTR,
+                    IGNORE_ALL_ATTR,
+                    IGNORE_SERIALIZATION_ATTR,
+                    REMOTE_ATTR
+                ]
+            ),
+        ))
     }
 }
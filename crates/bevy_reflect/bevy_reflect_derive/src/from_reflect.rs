@@ -112,14 +112,20 @@ fn impl_struct_internal(
     let MemberValuePair(active_members, active_values) =
         get_active_fields(reflect_struct, &ref_struct, &ref_struct_type, is_tuple);
 
+    let remote_ty = reflect_struct.meta().remote_ty();
     let is_defaultable = reflect_struct.meta().traits().contains(REFLECT_DEFAULT);
     let constructor = if is_defaultable {
+        let this = if remote_ty.is_some() {
+            quote!(__this.0)
+        } else {
+            quote!(__this)
+        };
         quote!(
             let mut __this: Self = #FQDefault::default();
             #(
                 if let #fqoption::Some(__field) = #active_values() {
                     // Iff field exists -> use its value
-                    __this.#active_members = __field;
+                    #this.#active_members = __field;
                 }
             )*
             #FQOption::Some(__this)
@@ -127,14 +133,19 @@ fn impl_struct_internal(
     } else {
         let MemberValuePair(ignored_members, ignored_values) = get_ignored_fields(reflect_struct);
 
-        quote!(
-            #FQOption::Some(
-                Self {
-                    #(#active_members: #active_values()?,)*
-                    #(#ignored_members: #ignored_values,)*
-                }
-            )
-        )
+        let constructor = quote!(
+            {
+                #(#active_members: #active_values()?,)*
+                #(#ignored_members: #ignored_values,)*
+            }
+        );
+        match remote_ty {
+            Some(remote_ty) => {
+                let remote_path = remote_ty.as_expr_path();
+                quote!(#FQOption::Some(Self(#remote_path #constructor)))
+            }
+            None => quote!(#FQOption::Some(Self #constructor)),
+        }
     };
 
     let (impl_generics, ty_generics, where_clause) = reflect_struct
@@ -224,7 +235,11 @@ fn get_active_fields(
                     field.reflection_index.expect("field should be active"),
                     is_tuple,
                 );
-                let ty = field.data.ty.clone();
+                let ty = field.reflected_type().clone();
+                let into_field = field.attrs.remote.as_ref().map(|_| {
+                    let value = field.convert_reflected_value(quote!(__value), bevy_reflect_path);
+                    quote!(.map(|__value| #value))
+                });
 
                 let get_field = quote! {
                     #bevy_reflect_path::#struct_type::field(#dyn_struct_name, #accessor)
@@ -234,7 +249,7 @@ fn get_active_fields(
                     DefaultBehavior::Func(path) => quote! {
                         (||
                             if let #FQOption::Some(field) = #get_field {
-                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field) #into_field
                             } else {
                                 #FQOption::Some(#path())
                             }
@@ -243,14 +258,14 @@ fn get_active_fields(
                     DefaultBehavior::Default => quote! {
                         (||
                             if let #FQOption::Some(field) = #get_field {
-                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field) #into_field
                             } else {
                                 #FQOption::Some(#FQDefault::default())
                             }
                         )
                     },
                     DefaultBehavior::Required => quote! {
-                        (|| <#ty as #bevy_reflect_path::FromReflect>::from_reflect(#get_field?))
+                        (|| <#ty as #bevy_reflect_path::FromReflect>::from_reflect(#get_field?) #into_field)
                     },
                 };
 
@@ -20,7 +20,9 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
     let EnumImpls {
         variant_info,
         enum_field,
+        enum_field_mut,
         enum_field_at,
+        enum_field_at_mut,
         enum_index_of,
         enum_name_at,
         enum_field_len,
@@ -58,18 +60,24 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
             }
         });
 
+    let custom_attributes = reflect_enum
+        .meta()
+        .traits()
+        .custom_attributes()
+        .to_tokens(bevy_reflect_path);
+
     #[cfg(feature = "documentation")]
     let info_generator = {
         let doc = reflect_enum.meta().doc();
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(&variants).with_docs(#doc)
+            #bevy_reflect_path::EnumInfo::new::<Self>(&variants).with_docs(#doc) #custom_attributes
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(&variants)
+            #bevy_reflect_path::EnumInfo::new::<Self>(&variants) #custom_attributes
         }
     };
 
@@ -94,6 +102,9 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
     let where_reflect_clause = extend_where_clause(where_clause, &where_clause_options);
 
+    let match_this = reflect_enum.match_this(false);
+    let match_this_mut = reflect_enum.match_this(true);
+
     quote! {
         #get_type_registration_impl
 
@@ -103,42 +114,42 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
         impl #impl_generics #bevy_reflect_path::Enum for #enum_path #ty_generics #where_reflect_clause {
             fn field(&self, #ref_name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
-                 match self {
+                 match #match_this {
                     #(#enum_field,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at(&self, #ref_index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
-                match self {
+                match #match_this {
                     #(#enum_field_at,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_mut(&mut self, #ref_name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
-                 match self {
-                    #(#enum_field,)*
+                 match #match_this_mut {
+                    #(#enum_field_mut,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at_mut(&mut self, #ref_index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
-                match self {
-                    #(#enum_field_at,)*
+                match #match_this_mut {
+                    #(#enum_field_at_mut,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn index_of(&self, #ref_name: &str) -> #FQOption<usize> {
-                 match self {
+                 match #match_this {
                     #(#enum_index_of,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn name_at(&self, #ref_index: usize) -> #FQOption<&str> {
-                 match self {
+                 match #match_this {
                     #(#enum_name_at,)*
                     _ => #FQOption::None,
                 }
@@ -150,7 +161,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn field_len(&self) -> usize {
-                 match self {
+                 match #match_this {
                     #(#enum_field_len,)*
                     _ => 0,
                 }
@@ -158,7 +169,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn variant_name(&self) -> &str {
-                 match self {
+                 match #match_this {
                     #(#enum_variant_name,)*
                     _ => unreachable!(),
                 }
@@ -166,7 +177,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn variant_index(&self) -> usize {
-                 match self {
+                 match #match_this {
                     #(#enum_variant_index,)*
                     _ => unreachable!(),
                 }
@@ -174,7 +185,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn variant_type(&self) -> #bevy_reflect_path::VariantType {
-                 match self {
+                 match #match_this {
                     #(#enum_variant_type,)*
                     _ => unreachable!(),
                 }
@@ -289,7 +300,9 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 struct EnumImpls {
     variant_info: Vec<proc_macro2::TokenStream>,
     enum_field: Vec<proc_macro2::TokenStream>,
+    enum_field_mut: Vec<proc_macro2::TokenStream>,
     enum_field_at: Vec<proc_macro2::TokenStream>,
+    enum_field_at_mut: Vec<proc_macro2::TokenStream>,
     enum_index_of: Vec<proc_macro2::TokenStream>,
     enum_name_at: Vec<proc_macro2::TokenStream>,
     enum_field_len: Vec<proc_macro2::TokenStream>,
@@ -303,7 +316,9 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
 
     let mut variant_info = Vec::new();
     let mut enum_field = Vec::new();
+    let mut enum_field_mut = Vec::new();
     let mut enum_field_at = Vec::new();
+    let mut enum_field_at_mut = Vec::new();
     let mut enum_index_of = Vec::new();
     let mut enum_name_at = Vec::new();
     let mut enum_field_len = Vec::new();
@@ -356,29 +371,32 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             constructor_argument
         }
 
-        let mut push_variant =
-            |_variant: &EnumVariant, arguments: proc_macro2::TokenStream, field_len: usize| {
-                #[cfg(feature = "documentation")]
-                let with_docs = {
-                    let doc = quote::ToTokens::to_token_stream(&_variant.doc);
-                    Some(quote!(.with_docs(#doc)))
-                };
-                #[cfg(not(feature = "documentation"))]
-                let with_docs: Option<proc_macro2::TokenStream> = None;
-
-                variant_info.push(quote! {
-                    #bevy_reflect_path::VariantInfo::#variant_type_ident(
-                        #bevy_reflect_path::#variant_info_ident::new(#arguments)
-                        #with_docs
-                    )
-                });
-                enum_field_len.push(quote! {
-                    #unit{..} => #field_len
-                });
-                enum_variant_type.push(quote! {
-                    #unit{..} => #bevy_reflect_path::VariantType::#variant_type_ident
-                });
+        let mut push_variant = |variant: &EnumVariant,
+                                arguments: proc_macro2::TokenStream,
+                                field_len: usize| {
+            #[cfg(feature = "documentation")]
+            let with_docs = {
+                let doc = quote::ToTokens::to_token_stream(&variant.doc);
+                Some(quote!(.with_docs(#doc)))
             };
+            #[cfg(not(feature = "documentation"))]
+            let with_docs: Option<proc_macro2::TokenStream> = None;
+            let custom_attributes = variant.attrs.custom_attributes.to_tokens(bevy_reflect_path);
+
+            variant_info.push(quote! {
+                #bevy_reflect_path::VariantInfo::#variant_type_ident(
+                    #bevy_reflect_path::#variant_info_ident::new(#arguments)
+                    #with_docs
+                    #custom_attributes
+                )
+            });
+            enum_field_len.push(quote! {
+                #unit{..} => #field_len
+            });
+            enum_variant_type.push(quote! {
+                #unit{..} => #bevy_reflect_path::VariantType::#variant_type_ident
+            });
+        };
 
         match &variant.fields {
             EnumVariantFields::Unit => {
@@ -387,8 +405,13 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             EnumVariantFields::Unnamed(fields) => {
                 let args = get_field_args(fields, |reflect_idx, declaration_index, field| {
                     let declare_field = syn::Index::from(declaration_index);
+                    let value = field.as_reflected_ref(quote!(value), false, bevy_reflect_path);
+                    let value_mut = field.as_reflected_ref(quote!(value), true, bevy_reflect_path);
                     enum_field_at.push(quote! {
-                        #unit { #declare_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(value)
+                        #unit { #declare_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(#value)
+                    });
+                    enum_field_at_mut.push(quote! {
+                        #unit { #declare_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(#value_mut)
                     });
 
                     #[cfg(feature = "documentation")]
@@ -399,10 +422,13 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     #[cfg(not(feature = "documentation"))]
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
-                    let field_ty = &field.data.ty;
+                    let custom_attributes =
+                        field.attrs.custom_attributes.to_tokens(bevy_reflect_path);
+                    let field_ty = field.reflected_type();
                     quote! {
                         #bevy_reflect_path::UnnamedField::new::<#field_ty>(#reflect_idx)
                         #with_docs
+                        #custom_attributes
                     }
                 });
 
@@ -413,11 +439,21 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                 let args = get_field_args(fields, |reflect_idx, _, field| {
                     let field_ident = field.data.ident.as_ref().unwrap();
                     let field_name = field_ident.to_string();
+                    let value =
+                        field.as_reflected_ref(quote!(#field_ident), false, bevy_reflect_path);
+                    let value_mut =
+                        field.as_reflected_ref(quote!(#field_ident), true, bevy_reflect_path);
                     enum_field.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#field_ident)
+                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#value)
+                    });
+                    enum_field_mut.push(quote! {
+                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#value_mut)
                     });
                     enum_field_at.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#field_ident)
+                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#value)
+                    });
+                    enum_field_at_mut.push(quote! {
+                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#value_mut)
                     });
                     enum_index_of.push(quote! {
                         #unit{ .. } if #ref_name == #field_name => #FQOption::Some(#reflect_idx)
@@ -434,10 +470,13 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     #[cfg(not(feature = "documentation"))]
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
-                    let field_ty = &field.data.ty;
+                    let custom_attributes =
+                        field.attrs.custom_attributes.to_tokens(bevy_reflect_path);
+                    let field_ty = field.reflected_type();
                     quote! {
                         #bevy_reflect_path::NamedField::new::<#field_ty>(#field_name)
                         #with_docs
+                        #custom_attributes
                     }
                 });
 
@@ -450,7 +489,9 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
     EnumImpls {
         variant_info,
         enum_field,
+        enum_field_mut,
         enum_field_at,
+        enum_field_at_mut,
         enum_index_of,
         enum_name_at,
         enum_field_len,
@@ -27,6 +27,14 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
         .map(|field| ident_or_index(field.data.ident.as_ref(), field.declaration_index))
         .collect::<Vec<_>>();
     let field_types = reflect_struct.active_types();
+    let field_refs = reflect_struct
+        .active_fields()
+        .map(|field| reflect_struct.access_for_field(field, false))
+        .collect::<Vec<_>>();
+    let field_muts = reflect_struct
+        .active_fields()
+        .map(|field| reflect_struct.access_for_field(field, true))
+        .collect::<Vec<_>>();
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
 
@@ -46,20 +54,30 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
             }
         });
 
+    let field_custom_attributes = reflect_struct
+        .active_fields()
+        .map(|field| field.attrs.custom_attributes.to_tokens(bevy_reflect_path))
+        .collect::<Vec<_>>();
+    let custom_attributes = reflect_struct
+        .meta()
+        .traits()
+        .custom_attributes()
+        .to_tokens(bevy_reflect_path);
+
     #[cfg(feature = "documentation")]
     let field_generator = {
         let docs = reflect_struct
             .active_fields()
             .map(|field| ToTokens::to_token_stream(&field.doc));
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names).with_docs(#docs) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names).with_docs(#docs) #field_custom_attributes ,)*
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let field_generator = {
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) #field_custom_attributes ,)*
         }
     };
 
@@ -67,14 +85,14 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
     let info_generator = {
         let doc = reflect_struct.meta().doc();
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(&fields).with_docs(#doc)
+            #bevy_reflect_path::StructInfo::new::<Self>(&fields).with_docs(#doc) #custom_attributes
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(&fields)
+            #bevy_reflect_path::StructInfo::new::<Self>(&fields) #custom_attributes
         }
     };
 
@@ -111,28 +129,28 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
         impl #impl_generics #bevy_reflect_path::Struct for #struct_path #ty_generics #where_reflect_clause {
             fn field(&self, name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match name {
-                    #(#field_names => #fqoption::Some(&self.#field_idents),)*
+                    #(#field_names => #fqoption::Some(#field_refs),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_mut(&mut self, name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match name {
-                    #(#field_names => #fqoption::Some(&mut self.#field_idents),)*
+                    #(#field_names => #fqoption::Some(#field_muts),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at(&self, index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&self.#field_idents),)*
+                    #(#field_indices => #fqoption::Some(#field_refs),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at_mut(&mut self, index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&mut self.#field_idents),)*
+                    #(#field_indices => #fqoption::Some(#field_muts),)*
                     _ => #FQOption::None,
                 }
             }
@@ -155,7 +173,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
             fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicStruct {
                 let mut dynamic: #bevy_reflect_path::DynamicStruct = #FQDefault::default();
                 dynamic.set_represented_type(#bevy_reflect_path::Reflect::get_represented_type_info(self));
-                #(dynamic.insert_boxed(#field_names, #bevy_reflect_path::Reflect::clone_value(&self.#field_idents));)*
+                #(dynamic.insert_boxed(#field_names, #bevy_reflect_path::Reflect::clone_value(#field_refs));)*
                 dynamic
             }
         }
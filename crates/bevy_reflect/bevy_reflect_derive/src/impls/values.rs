@@ -21,12 +21,17 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     #[cfg(not(feature = "documentation"))]
     let with_docs: Option<proc_macro2::TokenStream> = None;
 
+    let custom_attributes = meta
+        .traits()
+        .custom_attributes()
+        .to_tokens(bevy_reflect_path);
+
     let where_clause_options = WhereClauseOptions::new_value(meta);
     let typed_impl = impl_typed(
         meta,
         &where_clause_options,
         quote! {
-            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs;
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs #custom_attributes;
             #bevy_reflect_path::TypeInfo::Value(info)
         },
     );
@@ -15,6 +15,7 @@
 extern crate proc_macro;
 
 mod container_attributes;
+mod custom_attributes;
 mod derive_data;
 #[cfg(feature = "documentation")]
 mod documentation;
@@ -24,6 +25,7 @@ mod from_reflect;
 mod impls;
 mod reflect_value;
 mod registration;
+mod remote;
 mod serialization;
 mod trait_reflection;
 mod type_path;
@@ -155,7 +157,15 @@ pub(crate) static TYPE_NAME_ATTRIBUTE_NAME: &str = "type_name";
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## `#[reflect(remote = path::to::Wrapper)]`
+///
+/// This attribute allows a field whose type does not implement `Reflect` to be reflected
+/// through a wrapper created with the [`reflect_remote`] attribute macro.
+///
+/// The field keeps its original type, but is exposed to the reflection API as the given wrapper type.
+///
 /// [`reflect_trait`]: macro@reflect_trait
+/// [`reflect_remote`]: macro@reflect_remote
 #[proc_macro_derive(Reflect, attributes(reflect, reflect_value, type_path, type_name))]
 pub fn derive_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
@@ -165,6 +175,17 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
         Err(err) => return err.into_compile_error().into(),
     };
 
+    let reflect_impls = match_reflect_impls(derive_data);
+
+    TokenStream::from(quote! {
+        const _: () = {
+            #reflect_impls
+        };
+    })
+}
+
+/// Generates the `Reflect` impls, along with the `FromReflect` impl if needed, for the given derive data.
+fn match_reflect_impls(derive_data: ReflectDerive) -> proc_macro2::TokenStream {
     let (reflect_impls, from_reflect_impl) = match derive_data {
         ReflectDerive::Struct(struct_data) | ReflectDerive::UnitStruct(struct_data) => (
             impls::impl_struct(&struct_data),
@@ -200,12 +221,10 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
         ),
     };
 
-    TokenStream::from(quote! {
-        const _: () = {
-            #reflect_impls
-            #from_reflect_impl
-        };
-    })
+    quote! {
+        #reflect_impls
+        #from_reflect_impl
+    }
 }
 
 /// Derives the `FromReflect` trait.
@@ -358,6 +377,57 @@ pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
     trait_reflection::reflect_trait(&args, input)
 }
 
+/// Generates a wrapper type that can be used to reflect a type defined in another crate.
+///
+/// Due to Rust's orphan rule, `Reflect` cannot be implemented for foreign types.
+/// This macro takes a definition that mirrors the foreign (or "remote") type
+/// and turns it into a `#[repr(transparent)]` newtype around that remote type.
+/// The wrapper then implements the full reflection API by reading and writing the remote type's fields.
+///
+/// The mirrored definition must declare the same fields (or variants) with the same types
+/// as the remote type, which is verified at compile time.
+/// All fields of the remote type must therefore be visible from where the macro is used.
+///
+/// The same container and field attributes as [`#[derive(Reflect)]`](Reflect) are supported,
+/// with the exception of `#[reflect_value]`.
+/// Note that this macro should be used _instead of_ deriving `Reflect`, not in addition to it.
+///
+/// The wrapper also implements `ReflectRemote`, which allows converting between it and the remote type.
+/// Fields containing the remote type can be reflected using `#[reflect(remote = path::to::Wrapper)]`.
+///
+/// # Example
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// mod external_crate {
+///     pub struct Position {
+///         pub x: f32,
+///         pub y: f32,
+///     }
+/// }
+///
+/// #[reflect_remote(external_crate::Position)]
+/// struct PositionWrapper {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(remote = PositionWrapper)]
+///     position: external_crate::Position,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn reflect_remote(args: TokenStream, input: TokenStream) -> TokenStream {
+    let remote_ty = parse_macro_input!(args as syn::TypePath);
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match remote::reflect_remote(ast, remote_ty) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
 /// A macro used to generate reflection trait implementations for the given type.
 ///
 /// This is functionally the same as [deriving `Reflect`] using the `#[reflect_value]` container attribute.
@@ -0,0 +1,210 @@
+use crate::derive_data::{EnumVariantFields, ReflectDerive, StructField};
+use crate::utility::{extend_where_clause, ident_or_index};
+use crate::{
+    REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME, TYPE_NAME_ATTRIBUTE_NAME,
+    TYPE_PATH_ATTRIBUTE_NAME,
+};
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{DeriveInput, Path, PathArguments, TypePath};
+
+/// A type that is reflected on behalf of a wrapper created with `#[reflect_remote]`.
+#[derive(Copy, Clone)]
+pub(crate) struct RemoteType<'a> {
+    path: &'a TypePath,
+}
+
+impl<'a> RemoteType<'a> {
+    pub fn new(path: &'a TypePath) -> Self {
+        Self { path }
+    }
+
+    /// The full path to the remote type, including any generic arguments.
+    pub fn type_path(&self) -> &'a TypePath {
+        self.path
+    }
+
+    /// The path to the remote type with all generic arguments stripped.
+    ///
+    /// This can be used in expression and pattern position,
+    /// such as when constructing or destructuring the remote type.
+    pub fn as_expr_path(&self) -> Path {
+        let mut path = self.path.path.clone();
+        for segment in &mut path.segments {
+            segment.arguments = PathArguments::None;
+        }
+        path
+    }
+}
+
+/// Generates the wrapper type and its reflection impls for the `#[reflect_remote]` attribute.
+pub(crate) fn reflect_remote(
+    input: DeriveInput,
+    remote_ty: TypePath,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let remote_ty = RemoteType::new(&remote_ty);
+
+    let mut derive_data = ReflectDerive::from_input(&input, false)?;
+    if let ReflectDerive::Value(_) = &derive_data {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            format_args!(
+                "`#[{REFLECT_VALUE_ATTRIBUTE_NAME}]` cannot be used with `#[reflect_remote]`"
+            ),
+        ));
+    }
+    derive_data.set_remote(Some(remote_ty));
+
+    let wrapper_definition = generate_wrapper_definition(&input, remote_ty);
+    let remote_impl = impl_reflect_remote(&derive_data, remote_ty);
+    let assertions = generate_layout_assertions(&derive_data, remote_ty);
+    let reflect_impls = crate::match_reflect_impls(derive_data);
+
+    Ok(quote! {
+        #wrapper_definition
+
+        const _: () = {
+            #reflect_impls
+
+            #remote_impl
+
+            #assertions
+        };
+    })
+}
+
+/// Generates the `#[repr(transparent)]` newtype that stands in for the remote type.
+///
+/// Reflection-specific attributes are consumed by the macro and are not re-emitted.
+fn generate_wrapper_definition(
+    input: &DeriveInput,
+    remote_ty: RemoteType,
+) -> proc_macro2::TokenStream {
+    let attrs = input.attrs.iter().filter(|attr| {
+        let path = attr.path();
+        !(path.is_ident(REFLECT_ATTRIBUTE_NAME)
+            || path.is_ident(REFLECT_VALUE_ATTRIBUTE_NAME)
+            || path.is_ident(TYPE_PATH_ATTRIBUTE_NAME)
+            || path.is_ident(TYPE_NAME_ATTRIBUTE_NAME))
+    });
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let where_clause = &input.generics.where_clause;
+    let params = input.generics.params.iter();
+    let remote_ty = remote_ty.type_path();
+
+    quote! {
+        #(#attrs)*
+        #[repr(transparent)]
+        #vis struct #ident <#(#params),*> (pub #remote_ty) #where_clause;
+    }
+}
+
+fn impl_reflect_remote(
+    derive_data: &ReflectDerive,
+    remote_ty: RemoteType,
+) -> proc_macro2::TokenStream {
+    let meta = derive_data.meta();
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    let type_path = meta.type_path();
+    let remote_ty = remote_ty.type_path();
+
+    let where_clause_options = match derive_data {
+        ReflectDerive::Struct(data)
+        | ReflectDerive::TupleStruct(data)
+        | ReflectDerive::UnitStruct(data) => data.where_clause_options(),
+        ReflectDerive::Enum(data) => data.where_clause_options(),
+        ReflectDerive::Value(_) => unreachable!("value types cannot be remote"),
+    };
+    let (impl_generics, ty_generics, where_clause) = type_path.generics().split_for_impl();
+    let where_reflect_clause = extend_where_clause(where_clause, &where_clause_options);
+
+    quote! {
+        impl #impl_generics #bevy_reflect_path::ReflectRemote for #type_path #ty_generics #where_reflect_clause {
+            type Remote = #remote_ty;
+
+            fn as_remote(&self) -> &Self::Remote {
+                &self.0
+            }
+
+            fn as_remote_mut(&mut self) -> &mut Self::Remote {
+                &mut self.0
+            }
+
+            fn into_remote(self) -> Self::Remote {
+                self.0
+            }
+
+            fn as_wrapper(remote: &Self::Remote) -> &Self {
+                // SAFETY: The wrapper type is `#[repr(transparent)]` over `Self::Remote`
+                unsafe { ::core::mem::transmute::<&Self::Remote, &Self>(remote) }
+            }
+
+            fn as_wrapper_mut(remote: &mut Self::Remote) -> &mut Self {
+                // SAFETY: The wrapper type is `#[repr(transparent)]` over `Self::Remote`
+                unsafe { ::core::mem::transmute::<&mut Self::Remote, &mut Self>(remote) }
+            }
+
+            fn into_wrapper(remote: Self::Remote) -> Self {
+                Self(remote)
+            }
+        }
+    }
+}
+
+/// Generates a function that exhaustively destructures the remote type,
+/// ensuring the fields declared on the wrapper match the remote type's fields.
+fn generate_layout_assertions(
+    derive_data: &ReflectDerive,
+    remote_ty: RemoteType,
+) -> proc_macro2::TokenStream {
+    let meta = derive_data.meta();
+    let (impl_generics, _, where_clause) = meta.type_path().generics().split_for_impl();
+    let remote_path = remote_ty.as_expr_path();
+    let remote_ty = remote_ty.type_path();
+
+    let destructure = |path: proc_macro2::TokenStream, fields: &[StructField]| {
+        let members = fields
+            .iter()
+            .map(|field| ident_or_index(field.data.ident.as_ref(), field.declaration_index));
+        let bindings = (0..fields.len())
+            .map(|index| Ident::new(&format!("__field_{index}"), Span::call_site()))
+            .collect::<Vec<_>>();
+        let types = fields.iter().map(|field| &field.data.ty);
+        quote! {
+            #path { #(#members: #bindings),* } => {
+                #(let _: &#types = #bindings;)*
+            }
+        }
+    };
+
+    let arms = match derive_data {
+        ReflectDerive::Struct(data)
+        | ReflectDerive::TupleStruct(data)
+        | ReflectDerive::UnitStruct(data) => {
+            vec![destructure(quote!(#remote_path), data.fields())]
+        }
+        ReflectDerive::Enum(data) => data
+            .variants()
+            .iter()
+            .map(|variant| {
+                let ident = &variant.data.ident;
+                let fields: &[StructField] = match &variant.fields {
+                    EnumVariantFields::Unit => &[],
+                    EnumVariantFields::Named(fields) | EnumVariantFields::Unnamed(fields) => fields,
+                };
+                destructure(quote!(#remote_path::#ident), fields)
+            })
+            .collect(),
+        ReflectDerive::Value(_) => unreachable!("value types cannot be remote"),
+    };
+
+    quote! {
+        #[allow(unused, clippy::all)]
+        fn assert_remote_layout #impl_generics (remote: &#remote_ty) #where_clause {
+            match remote {
+                #(#arms)*
+            }
+        }
+    }
+}
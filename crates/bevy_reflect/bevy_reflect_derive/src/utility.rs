@@ -133,9 +133,12 @@ impl WhereClauseOptions {
 
         let (active_types, active_trait_bounds): (Vec<_>, Vec<_>) = active_fields
             .map(|field| {
-                let ty = field.data.ty.clone();
+                let ty = field.reflected_type().clone();
 
-                let custom_bounds = active_bounds(field).map(|bounds| quote!(+ #bounds));
+                // Custom bounds apply to the remote type itself, not its reflected wrapper
+                let custom_bounds = active_bounds(field)
+                    .filter(|_| field.attrs.remote.is_none())
+                    .map(|bounds| quote!(+ #bounds));
 
                 let bounds = if is_from_reflect {
                     quote!(#bevy_reflect_path::FromReflect #custom_bounds)
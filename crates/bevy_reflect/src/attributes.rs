@@ -0,0 +1,121 @@
+use crate::Reflect;
+use bevy_utils::HashMap;
+use std::any::TypeId;
+use std::fmt::{Debug, Formatter};
+
+/// A collection of custom attributes for a type, field, or variant.
+///
+/// Custom attributes are arbitrary values registered with the `Reflect` derive using
+/// `#[reflect(@expr)]`, where `expr` evaluates to a value implementing [`Reflect`].
+/// Each attribute is stored by its [`TypeId`], so a collection can hold at most one attribute of each type.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// use core::ops::RangeInclusive;
+///
+/// #[derive(Reflect)]
+/// struct Slider {
+///     #[reflect(@RangeInclusive::<f32>::new(0.0, 1.0))]
+///     value: f32,
+/// }
+///
+/// let TypeInfo::Struct(info) = Slider::type_info() else {
+///     panic!("expected struct info");
+/// };
+/// let range = info
+///     .field("value")
+///     .unwrap()
+///     .get_attribute::<RangeInclusive<f32>>()
+///     .unwrap();
+/// assert_eq!(*range, 0.0..=1.0);
+/// ```
+#[derive(Default)]
+pub struct CustomAttributes {
+    attributes: HashMap<TypeId, Box<dyn Reflect>>,
+}
+
+impl CustomAttributes {
+    /// Adds the given attribute, replacing any existing attribute of the same type.
+    pub fn with_attribute<T: Reflect>(mut self, value: T) -> Self {
+        self.attributes.insert(TypeId::of::<T>(), Box::new(value));
+        self
+    }
+
+    /// Returns `true` if this collection contains an attribute of type `T`.
+    pub fn contains<T: Reflect>(&self) -> bool {
+        self.attributes.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns `true` if this collection contains an attribute with the given [`TypeId`].
+    pub fn contains_by_id(&self, id: TypeId) -> bool {
+        self.attributes.contains_key(&id)
+    }
+
+    /// Returns the attribute of type `T`, if any.
+    pub fn get<T: Reflect>(&self) -> Option<&T> {
+        self.attributes.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns the attribute with the given [`TypeId`], if any.
+    pub fn get_by_id(&self, id: TypeId) -> Option<&dyn Reflect> {
+        self.attributes.get(&id).map(AsRef::as_ref)
+    }
+
+    /// Iterates over all attributes and their [`TypeId`]s.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&TypeId, &dyn Reflect)> {
+        self.attributes
+            .iter()
+            .map(|(id, value)| (id, value.as_ref()))
+    }
+
+    /// The number of attributes in this collection.
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns `true` if this collection contains no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+}
+
+impl Debug for CustomAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.attributes.values().map(|value| value as &dyn Debug))
+            .finish()
+    }
+}
+
+/// Implements the custom attribute accessors for a type that stores
+/// its [`CustomAttributes`] in an `Arc` field called `$field`.
+macro_rules! impl_custom_attribute_methods {
+    ($field:ident, $term:literal) => {
+        #[doc = concat!("Sets the custom attributes for this ", $term, ".")]
+        pub fn with_custom_attributes(self, $field: $crate::CustomAttributes) -> Self {
+            Self {
+                $field: ::std::sync::Arc::new($field),
+                ..self
+            }
+        }
+
+        #[doc = concat!("The custom attributes of this ", $term, ".")]
+        pub fn custom_attributes(&self) -> &$crate::CustomAttributes {
+            &self.$field
+        }
+
+        #[doc = concat!("Returns the custom attribute of type `T` of this ", $term, ", if any.")]
+        pub fn get_attribute<T: $crate::Reflect>(&self) -> Option<&T> {
+            self.$field.get::<T>()
+        }
+
+        #[doc = concat!("Returns `true` if this ", $term, " has a custom attribute of type `T`.")]
+        pub fn has_attribute<T: $crate::Reflect>(&self) -> bool {
+            self.$field.contains::<T>()
+        }
+    };
+}
+
+pub(crate) use impl_custom_attribute_methods;
@@ -0,0 +1,647 @@
+use crate::{Enum, List, Map, Reflect, ReflectMut, ReflectRef, Struct, TypeInfo, VariantType};
+use thiserror::Error;
+
+/// The difference between two reflected values of the same type.
+///
+/// A diff is created with [`diff`] and only records what changed:
+/// unchanged fields and elements are left out entirely.
+/// Applying it to the old value with [`apply_diff`] turns that value into the new one.
+///
+/// Diffs can be serialized with [`ReflectDiffSerializer`] and deserialized with [`ReflectDiffDeserializer`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{apply_diff, diff, Reflect, ReflectDiff};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+///     inventory: Vec<String>,
+/// }
+///
+/// let old = Player {
+///     name: String::from("Ferris"),
+///     health: 100,
+///     inventory: vec![String::from("sword")],
+/// };
+/// let new = Player {
+///     health: 80,
+///     inventory: vec![String::from("sword"), String::from("shield")],
+///     ..old.clone()
+/// };
+///
+/// let diff = diff(&old, &new).unwrap();
+/// let ReflectDiff::Fields(fields) = &diff else {
+///     panic!("expected a struct diff");
+/// };
+/// assert_eq!(2, fields.len());
+///
+/// let mut value = old.clone();
+/// apply_diff(&mut value, &diff).unwrap();
+/// assert_eq!(new, value);
+/// ```
+///
+/// [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+/// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+#[derive(Debug)]
+pub enum ReflectDiff {
+    /// The two values are equal.
+    Unchanged,
+    /// The value was replaced entirely.
+    ///
+    /// This is used for [value types](ReflectRef::Value), for enums that changed variant,
+    /// and whenever the two values cannot be compared piece by piece.
+    Replaced(Box<dyn Reflect>),
+    /// The changed fields of a struct or struct variant, by name.
+    Fields(Vec<(String, ReflectDiff)>),
+    /// The changed elements of a tuple, tuple struct, array, or tuple variant, by index.
+    Elements(Vec<(usize, ReflectDiff)>),
+    /// The edits turning one list into another, in the order they must be applied.
+    List(Vec<ListDiff>),
+    /// The changed, inserted, and removed entries of a map.
+    Map(Vec<MapDiff>),
+}
+
+impl ReflectDiff {
+    /// Returns `true` if this diff does not change anything.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, ReflectDiff::Unchanged)
+    }
+}
+
+/// A single edit of a list within a [`ReflectDiff::List`].
+///
+/// Indices refer to the list as it is after all previous edits have been applied.
+#[derive(Debug)]
+pub enum ListDiff {
+    /// The element at the given index changed.
+    Modified(usize, ReflectDiff),
+    /// An element was inserted at the given index.
+    Inserted(usize, Box<dyn Reflect>),
+    /// The element at the given index was removed.
+    Removed(usize),
+}
+
+/// A single edit of a map within a [`ReflectDiff::Map`].
+#[derive(Debug)]
+pub enum MapDiff {
+    /// The value of the given key changed.
+    Modified(Box<dyn Reflect>, ReflectDiff),
+    /// An entry with the given key and value was inserted.
+    Inserted(Box<dyn Reflect>, Box<dyn Reflect>),
+    /// The entry with the given key was removed.
+    Removed(Box<dyn Reflect>),
+}
+
+/// An error returned by [`diff`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum DiffError {
+    /// The two values are not of the same type.
+    #[error("cannot diff a value of type `{old}` against a value of type `{new}`")]
+    MismatchedTypes { old: String, new: String },
+}
+
+/// An error returned by [`apply_diff`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ApplyDiffError {
+    /// The diff expected a different kind of value.
+    #[error("expected a {expected} but found a {received}")]
+    MismatchedKinds {
+        expected: &'static str,
+        received: &'static str,
+    },
+    /// The diff refers to a field that does not exist.
+    #[error("no field named `{0}`")]
+    MissingField(String),
+    /// The diff refers to an element that does not exist.
+    #[error("no element at index {0}")]
+    MissingElement(usize),
+    /// The diff refers to a map entry that does not exist.
+    #[error("no entry with key {0}")]
+    MissingKey(String),
+}
+
+/// Computes the [`ReflectDiff`] turning `old` into `new`.
+///
+/// Both values must have the same type, though either may be a dynamic type representing it.
+///
+/// Value types are compared using [`Reflect::reflect_partial_eq`],
+/// so values without a reflected `PartialEq` implementation are always treated as changed.
+pub fn diff(old: &dyn Reflect, new: &dyn Reflect) -> Result<ReflectDiff, DiffError> {
+    let (old_path, new_path) = (type_path_of(old), type_path_of(new));
+    if old_path != new_path {
+        return Err(DiffError::MismatchedTypes {
+            old: old_path.to_string(),
+            new: new_path.to_string(),
+        });
+    }
+
+    Ok(diff_values(old, new))
+}
+
+/// Applies a [`ReflectDiff`] to `target`.
+///
+/// The target should be equal to the `old` value the diff was computed from.
+/// Replaced values are applied with [`Reflect::apply`], and will panic if they are not compatible with the target.
+pub fn apply_diff(target: &mut dyn Reflect, diff: &ReflectDiff) -> Result<(), ApplyDiffError> {
+    match diff {
+        ReflectDiff::Unchanged => Ok(()),
+        ReflectDiff::Replaced(value) => {
+            target.apply(value.as_ref());
+            Ok(())
+        }
+        ReflectDiff::Fields(fields) => {
+            for (name, diff) in fields {
+                let field = match target.reflect_mut() {
+                    ReflectMut::Struct(value) => value.field_mut(name),
+                    ReflectMut::Enum(value) if value.variant_type() == VariantType::Struct => {
+                        value.field_mut(name)
+                    }
+                    other => return Err(mismatched_kinds("struct", &other)),
+                };
+                let field = field.ok_or_else(|| ApplyDiffError::MissingField(name.clone()))?;
+                apply_diff(field, diff)?;
+            }
+            Ok(())
+        }
+        ReflectDiff::Elements(elements) => {
+            for (index, diff) in elements {
+                let index = *index;
+                let element = match target.reflect_mut() {
+                    ReflectMut::TupleStruct(value) => value.field_mut(index),
+                    ReflectMut::Tuple(value) => value.field_mut(index),
+                    ReflectMut::Array(value) => value.get_mut(index),
+                    ReflectMut::Enum(value) if value.variant_type() == VariantType::Tuple => {
+                        value.field_at_mut(index)
+                    }
+                    other => return Err(mismatched_kinds("tuple", &other)),
+                };
+                let element = element.ok_or(ApplyDiffError::MissingElement(index))?;
+                apply_diff(element, diff)?;
+            }
+            Ok(())
+        }
+        ReflectDiff::List(edits) => {
+            let list = match target.reflect_mut() {
+                ReflectMut::List(list) => list,
+                other => return Err(mismatched_kinds("list", &other)),
+            };
+            for edit in edits {
+                match edit {
+                    ListDiff::Modified(index, diff) => {
+                        let element = list
+                            .get_mut(*index)
+                            .ok_or(ApplyDiffError::MissingElement(*index))?;
+                        apply_diff(element, diff)?;
+                    }
+                    ListDiff::Inserted(index, value) => {
+                        if *index > list.len() {
+                            return Err(ApplyDiffError::MissingElement(*index));
+                        }
+                        list.insert(*index, value.clone_value());
+                    }
+                    ListDiff::Removed(index) => {
+                        if *index >= list.len() {
+                            return Err(ApplyDiffError::MissingElement(*index));
+                        }
+                        list.remove(*index);
+                    }
+                }
+            }
+            Ok(())
+        }
+        ReflectDiff::Map(edits) => {
+            let map = match target.reflect_mut() {
+                ReflectMut::Map(map) => map,
+                other => return Err(mismatched_kinds("map", &other)),
+            };
+            for edit in edits {
+                match edit {
+                    MapDiff::Modified(key, diff) => {
+                        let value = find_map_value_mut(map, key.as_ref())
+                            .ok_or_else(|| ApplyDiffError::MissingKey(format!("{key:?}")))?;
+                        apply_diff(value, diff)?;
+                    }
+                    MapDiff::Inserted(key, value) => {
+                        map.insert_boxed(key.clone_value(), value.clone_value());
+                    }
+                    MapDiff::Removed(key) => {
+                        map.remove(key.as_ref())
+                            .ok_or_else(|| ApplyDiffError::MissingKey(format!("{key:?}")))?;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn diff_values(old: &dyn Reflect, new: &dyn Reflect) -> ReflectDiff {
+    if type_path_of(old) != type_path_of(new) {
+        return ReflectDiff::Replaced(new.clone_value());
+    }
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new)) => diff_structs(old, new),
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new))
+            if old.field_len() == new.field_len() =>
+        {
+            diff_elements(old.iter_fields().zip(new.iter_fields()))
+        }
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) if old.field_len() == new.field_len() => {
+            diff_elements(old.iter_fields().zip(new.iter_fields()))
+        }
+        (ReflectRef::Array(old), ReflectRef::Array(new)) if old.len() == new.len() => {
+            diff_elements(old.iter().zip(new.iter()))
+        }
+        (ReflectRef::List(old), ReflectRef::List(new)) => diff_lists(old, new),
+        (ReflectRef::Map(old), ReflectRef::Map(new)) => diff_maps(old, new),
+        (ReflectRef::Enum(old), ReflectRef::Enum(new)) => diff_enums(old, new),
+        (ReflectRef::Value(old), ReflectRef::Value(new))
+            if old.reflect_partial_eq(new) == Some(true) =>
+        {
+            ReflectDiff::Unchanged
+        }
+        _ => ReflectDiff::Replaced(new.clone_value()),
+    }
+}
+
+fn diff_structs(old: &dyn Struct, new: &dyn Struct) -> ReflectDiff {
+    let mut fields = Vec::new();
+    for (index, new_field) in new.iter_fields().enumerate() {
+        let name = new.name_at(index).unwrap();
+        let Some(old_field) = old.field(name) else {
+            return ReflectDiff::Replaced(new.clone_value());
+        };
+        let diff = diff_values(old_field, new_field);
+        if !diff.is_unchanged() {
+            fields.push((name.to_string(), diff));
+        }
+    }
+
+    if fields.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Fields(fields)
+    }
+}
+
+fn diff_elements<'a>(
+    pairs: impl Iterator<Item = (&'a dyn Reflect, &'a dyn Reflect)>,
+) -> ReflectDiff {
+    let elements: Vec<_> = pairs
+        .enumerate()
+        .map(|(index, (old, new))| (index, diff_values(old, new)))
+        .filter(|(_, diff)| !diff.is_unchanged())
+        .collect();
+
+    if elements.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Elements(elements)
+    }
+}
+
+fn diff_lists(old: &dyn List, new: &dyn List) -> ReflectDiff {
+    let equal = |old_index: usize, new_index: usize| {
+        old.get(old_index)
+            .unwrap()
+            .reflect_partial_eq(new.get(new_index).unwrap())
+            .unwrap_or(false)
+    };
+
+    // Only the middle section between the common prefix and suffix needs to be edited
+    let max_common = old.len().min(new.len());
+    let prefix = (0..max_common).take_while(|&i| equal(i, i)).count();
+    let suffix = (0..max_common - prefix)
+        .take_while(|&i| equal(old.len() - 1 - i, new.len() - 1 - i))
+        .count();
+    let old_end = old.len() - suffix;
+    let new_end = new.len() - suffix;
+    let paired = (old_end - prefix).min(new_end - prefix);
+
+    let mut edits = Vec::new();
+    for index in prefix..prefix + paired {
+        let diff = diff_values(old.get(index).unwrap(), new.get(index).unwrap());
+        if !diff.is_unchanged() {
+            edits.push(ListDiff::Modified(index, diff));
+        }
+    }
+    for index in (prefix + paired..old_end).rev() {
+        edits.push(ListDiff::Removed(index));
+    }
+    for index in prefix + paired..new_end {
+        edits.push(ListDiff::Inserted(
+            index,
+            new.get(index).unwrap().clone_value(),
+        ));
+    }
+
+    if edits.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::List(edits)
+    }
+}
+
+fn diff_maps(old: &dyn Map, new: &dyn Map) -> ReflectDiff {
+    let mut edits = Vec::new();
+    for (key, old_value) in old.iter() {
+        match new.get(key) {
+            Some(new_value) => {
+                let diff = diff_values(old_value, new_value);
+                if !diff.is_unchanged() {
+                    edits.push(MapDiff::Modified(key.clone_value(), diff));
+                }
+            }
+            None => edits.push(MapDiff::Removed(key.clone_value())),
+        }
+    }
+    for (key, new_value) in new.iter() {
+        if old.get(key).is_none() {
+            edits.push(MapDiff::Inserted(
+                key.clone_value(),
+                new_value.clone_value(),
+            ));
+        }
+    }
+
+    if edits.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Map(edits)
+    }
+}
+
+fn diff_enums(old: &dyn Enum, new: &dyn Enum) -> ReflectDiff {
+    if old.variant_name() != new.variant_name()
+        || old.variant_type() != new.variant_type()
+        || old.field_len() != new.field_len()
+    {
+        return ReflectDiff::Replaced(new.clone_value());
+    }
+
+    match new.variant_type() {
+        VariantType::Unit => ReflectDiff::Unchanged,
+        VariantType::Tuple => diff_elements(
+            old.iter_fields()
+                .zip(new.iter_fields())
+                .map(|(old, new)| (old.value(), new.value())),
+        ),
+        VariantType::Struct => {
+            let mut fields = Vec::new();
+            for field in new.iter_fields() {
+                let name = field.name().unwrap();
+                let Some(old_field) = old.field(name) else {
+                    return ReflectDiff::Replaced(new.clone_value());
+                };
+                let diff = diff_values(old_field, field.value());
+                if !diff.is_unchanged() {
+                    fields.push((name.to_string(), diff));
+                }
+            }
+
+            if fields.is_empty() {
+                ReflectDiff::Unchanged
+            } else {
+                ReflectDiff::Fields(fields)
+            }
+        }
+    }
+}
+
+/// Finds the value for `key` in `map`.
+///
+/// Keys stored in a diff are cloned with [`Reflect::clone_value`], so non-value keys
+/// may be dynamic types that [`Map::get_mut`] cannot downcast.
+/// Instead, the entry is found by comparing keys with [`Reflect::reflect_partial_eq`].
+fn find_map_value_mut<'a>(map: &'a mut dyn Map, key: &dyn Reflect) -> Option<&'a mut dyn Reflect> {
+    let index = map
+        .iter()
+        .position(|(entry_key, _)| entry_key.reflect_partial_eq(key).unwrap_or(false))?;
+    map.get_at_mut(index).map(|(_, value)| value)
+}
+
+/// Returns the path of the type `value` represents, which for dynamic types may differ from its own type.
+fn type_path_of(value: &dyn Reflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
+        .unwrap_or_else(|| value.reflect_type_path())
+}
+
+fn mismatched_kinds(expected: &'static str, received: &ReflectMut) -> ApplyDiffError {
+    let received = match received {
+        ReflectMut::Struct(_) => "struct",
+        ReflectMut::TupleStruct(_) => "tuple struct",
+        ReflectMut::Tuple(_) => "tuple",
+        ReflectMut::List(_) => "list",
+        ReflectMut::Array(_) => "array",
+        ReflectMut::Map(_) => "map",
+        ReflectMut::Enum(_) => "enum",
+        ReflectMut::Value(_) => "value",
+    };
+    ApplyDiffError::MismatchedKinds { expected, received }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::DynamicStruct;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Point,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Foo {
+        a: u32,
+        b: String,
+        list: Vec<u32>,
+        map: HashMap<String, u32>,
+        shape: Shape,
+        tuple: (u8, bool),
+    }
+
+    fn foo() -> Foo {
+        Foo {
+            a: 1,
+            b: String::from("hello"),
+            list: vec![1, 2, 3, 4, 5],
+            map: HashMap::from([(String::from("x"), 1), (String::from("y"), 2)]),
+            shape: Shape::Circle { radius: 1.0 },
+            tuple: (0, false),
+        }
+    }
+
+    fn assert_roundtrip(old: &Foo, new: &Foo) {
+        let diff = diff(old, new).unwrap();
+        let mut value = old.clone();
+        apply_diff(&mut value, &diff).unwrap();
+        assert_eq!(new, &value);
+    }
+
+    #[test]
+    fn should_diff_unchanged() {
+        assert!(diff(&foo(), &foo()).unwrap().is_unchanged());
+    }
+
+    #[test]
+    fn should_diff_changed_fields() {
+        let old = foo();
+        let new = Foo {
+            a: 2,
+            tuple: (0, true),
+            ..foo()
+        };
+
+        let ReflectDiff::Fields(fields) = diff(&old, &new).unwrap() else {
+            panic!("expected a struct diff");
+        };
+        assert_eq!(2, fields.len());
+        assert_eq!("a", fields[0].0);
+        assert!(matches!(fields[0].1, ReflectDiff::Replaced(_)));
+        assert_eq!("tuple", fields[1].0);
+        let ReflectDiff::Elements(elements) = &fields[1].1 else {
+            panic!("expected a tuple diff");
+        };
+        assert_eq!(1, elements.len());
+        assert_eq!(1, elements[0].0);
+
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_lists() {
+        let old = foo();
+        let mut new = foo();
+        new.list = vec![1, 2, 9, 8, 7, 4, 5];
+
+        let ReflectDiff::Fields(fields) = diff(&old, &new).unwrap() else {
+            panic!("expected a struct diff");
+        };
+        let ReflectDiff::List(edits) = &fields[0].1 else {
+            panic!("expected a list diff");
+        };
+        assert!(matches!(edits[0], ListDiff::Modified(2, _)));
+        assert!(matches!(edits[1], ListDiff::Inserted(3, _)));
+        assert!(matches!(edits[2], ListDiff::Inserted(4, _)));
+        assert_roundtrip(&old, &new);
+
+        new.list = vec![1, 5];
+        assert_roundtrip(&old, &new);
+        new.list = vec![];
+        assert_roundtrip(&old, &new);
+        new.list = vec![0, 1, 2, 3, 4, 5, 6];
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_maps() {
+        let old = foo();
+        let mut new = foo();
+        new.map.remove("x");
+        new.map.insert(String::from("y"), 3);
+        new.map.insert(String::from("z"), 4);
+
+        let ReflectDiff::Fields(fields) = diff(&old, &new).unwrap() else {
+            panic!("expected a struct diff");
+        };
+        let ReflectDiff::Map(edits) = &fields[0].1 else {
+            panic!("expected a map diff");
+        };
+        assert_eq!(3, edits.len());
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_maps_with_non_value_keys() {
+        #[derive(Reflect, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Key {
+            id: u32,
+        }
+
+        let old = HashMap::from([((0_u32, 0_u32), 1.0_f32), ((1, 2), 2.0)]);
+        let mut new = old.clone();
+        new.insert((1, 2), 3.0);
+        new.insert((4, 4), 4.0);
+        new.remove(&(0, 0));
+
+        let diff_result = diff(&old, &new).unwrap();
+        let ReflectDiff::Map(edits) = &diff_result else {
+            panic!("expected a map diff");
+        };
+        assert_eq!(3, edits.len());
+        let mut value = old.clone();
+        apply_diff(&mut value, &diff_result).unwrap();
+        assert_eq!(new, value);
+
+        let old = HashMap::from([(Key { id: 1 }, vec![1_u32]), (Key { id: 2 }, vec![2])]);
+        let mut new = old.clone();
+        new.get_mut(&Key { id: 2 }).unwrap().push(3);
+
+        let diff_result = diff(&old, &new).unwrap();
+        let mut value = old.clone();
+        apply_diff(&mut value, &diff_result).unwrap();
+        assert_eq!(new, value);
+    }
+
+    #[test]
+    fn should_diff_enums() {
+        let old = foo();
+        let mut new = foo();
+        new.shape = Shape::Circle { radius: 2.0 };
+
+        let ReflectDiff::Fields(fields) = diff(&old, &new).unwrap() else {
+            panic!("expected a struct diff");
+        };
+        assert!(matches!(&fields[0].1, ReflectDiff::Fields(fields) if fields[0].0 == "radius"));
+        assert_roundtrip(&old, &new);
+
+        new.shape = Shape::Rect(1.0, 2.0);
+        let ReflectDiff::Fields(fields) = diff(&old, &new).unwrap() else {
+            panic!("expected a struct diff");
+        };
+        assert!(matches!(fields[0].1, ReflectDiff::Replaced(_)));
+        assert_roundtrip(&old, &new);
+
+        new.shape = Shape::Point;
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_against_dynamic() {
+        let old = foo();
+        let mut new = foo().clone_dynamic();
+        new.insert("a", 5_u32);
+
+        let mut value = old.clone();
+        apply_diff(&mut value, &diff(&old, &new).unwrap()).unwrap();
+        assert_eq!(5, value.a);
+    }
+
+    #[test]
+    fn should_error_on_mismatched_types() {
+        let result = diff(&foo(), &DynamicStruct::default());
+        assert!(matches!(result, Err(DiffError::MismatchedTypes { .. })));
+
+        let diff = ReflectDiff::Fields(vec![(String::from("a"), ReflectDiff::Unchanged)]);
+        assert_eq!(
+            Err(ApplyDiffError::MismatchedKinds {
+                expected: "struct",
+                received: "list",
+            }),
+            apply_diff(&mut vec![1_u32], &diff)
+        );
+        assert_eq!(
+            Err(ApplyDiffError::MissingField(String::from("a"))),
+            apply_diff(&mut DynamicStruct::default(), &diff)
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use crate::func::FunctionError;
+use crate::{FromReflect, Reflect, TypePath};
+use std::fmt::{Display, Formatter};
+
+/// How an argument is passed to a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Ownership {
+    /// The argument is passed by value.
+    Owned,
+    /// The argument is passed by reference.
+    Ref,
+    /// The argument is passed by mutable reference.
+    Mut,
+}
+
+impl Display for Ownership {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Owned => write!(f, "owned"),
+            Self::Ref => write!(f, "a reference"),
+            Self::Mut => write!(f, "a mutable reference"),
+        }
+    }
+}
+
+/// A single argument passed to a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug)]
+pub enum Arg<'a> {
+    /// An owned value.
+    Owned(Box<dyn Reflect>),
+    /// A reference to a value.
+    Ref(&'a dyn Reflect),
+    /// A mutable reference to a value.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> Arg<'a> {
+    /// Returns how this argument is passed.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            Self::Owned(_) => Ownership::Owned,
+            Self::Ref(_) => Ownership::Ref,
+            Self::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// Returns the value of this argument.
+    pub fn value(&self) -> &dyn Reflect {
+        match self {
+            Self::Owned(value) => value.as_ref(),
+            Self::Ref(value) => *value,
+            Self::Mut(value) => *value,
+        }
+    }
+
+    /// Converts this argument into a value of type `T`.
+    ///
+    /// Owned values of type `T` are moved out directly. Any other value (including references)
+    /// is converted using [`FromReflect`].
+    pub fn take_owned<T: FromReflect + TypePath>(self, index: usize) -> Result<T, FunctionError> {
+        let type_path = |value: &dyn Reflect| value.reflect_type_path().to_string();
+        let value = match self {
+            Self::Owned(value) => match value.take::<T>() {
+                Ok(value) => return Ok(value),
+                Err(value) => T::from_reflect(value.as_ref()).ok_or_else(|| type_path(&*value)),
+            },
+            Self::Ref(value) => T::from_reflect(value).ok_or_else(|| type_path(value)),
+            Self::Mut(value) => T::from_reflect(value).ok_or_else(|| type_path(value)),
+        };
+        value.map_err(|received| FunctionError::InvalidArgType {
+            index,
+            expected: T::type_path(),
+            received,
+        })
+    }
+
+    /// Converts this argument into a reference to a value of type `T`.
+    pub fn take_ref<T: Reflect + TypePath>(self, index: usize) -> Result<&'a T, FunctionError> {
+        let value: &'a dyn Reflect = match self {
+            Self::Ref(value) => value,
+            Self::Mut(value) => value,
+            Self::Owned(_) => {
+                return Err(FunctionError::InvalidArgOwnership {
+                    index,
+                    expected: Ownership::Ref,
+                    received: Ownership::Owned,
+                })
+            }
+        };
+        value
+            .downcast_ref::<T>()
+            .ok_or_else(|| FunctionError::InvalidArgType {
+                index,
+                expected: T::type_path(),
+                received: value.reflect_type_path().to_string(),
+            })
+    }
+
+    /// Converts this argument into a mutable reference to a value of type `T`.
+    pub fn take_mut<T: Reflect + TypePath>(self, index: usize) -> Result<&'a mut T, FunctionError> {
+        let Self::Mut(value) = self else {
+            return Err(FunctionError::InvalidArgOwnership {
+                index,
+                expected: Ownership::Mut,
+                received: self.ownership(),
+            });
+        };
+        if !value.is::<T>() {
+            return Err(FunctionError::InvalidArgType {
+                index,
+                expected: T::type_path(),
+                received: value.reflect_type_path().to_string(),
+            });
+        }
+        Ok(value.downcast_mut::<T>().unwrap())
+    }
+}
+
+/// The list of arguments passed to a [`DynamicFunction`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::ArgList;
+/// let value = 2_i32;
+/// let args = ArgList::new().with_owned(1_i32).with_ref(&value);
+/// assert_eq!(args.len(), 2);
+/// ```
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Default)]
+pub struct ArgList<'a>(Vec<Arg<'a>>);
+
+impl<'a> ArgList<'a> {
+    /// Creates an empty [`ArgList`].
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Appends an argument.
+    pub fn push(&mut self, arg: Arg<'a>) {
+        self.0.push(arg);
+    }
+
+    /// Appends an owned argument.
+    pub fn with_owned<T: Reflect>(self, value: T) -> Self {
+        self.with_boxed(Box::new(value))
+    }
+
+    /// Appends an owned, boxed argument.
+    pub fn with_boxed(mut self, value: Box<dyn Reflect>) -> Self {
+        self.push(Arg::Owned(value));
+        self
+    }
+
+    /// Appends an argument passed by reference.
+    pub fn with_ref(mut self, value: &'a dyn Reflect) -> Self {
+        self.push(Arg::Ref(value));
+        self
+    }
+
+    /// Appends an argument passed by mutable reference.
+    pub fn with_mut(mut self, value: &'a mut dyn Reflect) -> Self {
+        self.push(Arg::Mut(value));
+        self
+    }
+
+    /// The number of arguments in the list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list contains no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the arguments in the list.
+    pub fn iter(&self) -> impl Iterator<Item = &Arg<'a>> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for ArgList<'a> {
+    type Item = Arg<'a>;
+    type IntoIter = std::vec::IntoIter<Arg<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl From<Vec<Box<dyn Reflect>>> for ArgList<'_> {
+    fn from(args: Vec<Box<dyn Reflect>>) -> Self {
+        Self(args.into_iter().map(Arg::Owned).collect())
+    }
+}
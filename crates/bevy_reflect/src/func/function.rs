@@ -0,0 +1,114 @@
+use crate::func::args::ArgList;
+use crate::func::info::FunctionInfo;
+use crate::func::FunctionError;
+use crate::Reflect;
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// The result of calling a [`DynamicFunction`].
+pub type FunctionResult = Result<Box<dyn Reflect>, FunctionError>;
+
+/// A dynamically-callable function, created from a regular function or closure with [`IntoFunction`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoFunction};
+/// fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// let add = add.into_function().with_arg_names(["a", "b"]);
+/// assert_eq!(add.info().args()[1].name(), Some("b"));
+///
+/// let args = ArgList::new().with_owned(25_i32).with_owned(75_i32);
+/// let result = add.call(args).unwrap();
+/// assert_eq!(result.take::<i32>().unwrap(), 100);
+/// ```
+#[derive(Clone)]
+pub struct DynamicFunction {
+    info: FunctionInfo,
+    func: Arc<dyn for<'a> Fn(ArgList<'a>) -> FunctionResult + Send + Sync + 'static>,
+}
+
+impl DynamicFunction {
+    /// Creates a new [`DynamicFunction`] from the given callback and its [`FunctionInfo`].
+    ///
+    /// The callback is only called with argument lists of the length given by [`FunctionInfo::arg_count`].
+    pub fn new(
+        func: impl for<'a> Fn(ArgList<'a>) -> FunctionResult + Send + Sync + 'static,
+        info: FunctionInfo,
+    ) -> Self {
+        Self {
+            info,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.info = self.info.with_name(name);
+        self
+    }
+
+    /// Sets the names of the function's arguments, in order.
+    pub fn with_arg_names<N: Into<Cow<'static, str>>>(
+        mut self,
+        names: impl IntoIterator<Item = N>,
+    ) -> Self {
+        self.info = self.info.with_arg_names(names);
+        self
+    }
+
+    /// Calls the function with the given arguments.
+    pub fn call(&self, args: ArgList) -> FunctionResult {
+        if args.len() != self.info.arg_count() {
+            return Err(FunctionError::ArgCount {
+                expected: self.info.arg_count(),
+                received: args.len(),
+            });
+        }
+        (self.func)(args)
+    }
+
+    /// The [`FunctionInfo`] of this function.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+}
+
+impl Debug for DynamicFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DynamicFunction(fn {}(", self.info.name())?;
+        for (index, arg) in self.info.args().iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", arg.name().unwrap_or("_"), arg.type_path())?;
+        }
+        write!(f, ") -> {})", self.info.return_info().type_path())
+    }
+}
+
+/// Converts a function or closure into a [`DynamicFunction`].
+///
+/// This is implemented for functions and closures with up to 8 arguments (plus an optional receiver), where:
+/// - arguments passed by value implement [`FromReflect`] and [`Typed`],
+/// - the first argument may instead be a `&T` or `&mut T` receiver (such as `&self`), where `T` implements [`Typed`],
+/// - the return type implements [`Typed`] (`()` included).
+///
+/// The `Marker` type parameter only exists to differentiate between the implementations.
+///
+/// [`FromReflect`]: crate::FromReflect
+/// [`Typed`]: crate::Typed
+pub trait IntoFunction<Marker> {
+    /// Converts `self` into a [`DynamicFunction`].
+    fn into_function(self) -> DynamicFunction;
+}
+
+impl IntoFunction<()> for DynamicFunction {
+    fn into_function(self) -> DynamicFunction {
+        self
+    }
+}
@@ -0,0 +1,163 @@
+use crate::func::args::Ownership;
+use crate::{TypeInfo, Typed};
+use std::any::TypeId;
+use std::borrow::Cow;
+
+/// Type information for a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    name: Cow<'static, str>,
+    args: Vec<ArgInfo>,
+    return_info: ReturnInfo,
+}
+
+impl FunctionInfo {
+    /// Creates a new [`FunctionInfo`] for a function with the given `name`, arguments and return type.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        args: Vec<ArgInfo>,
+        return_info: ReturnInfo,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            args,
+            return_info,
+        }
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the names of the function's arguments, in order.
+    pub fn with_arg_names<N: Into<Cow<'static, str>>>(
+        mut self,
+        names: impl IntoIterator<Item = N>,
+    ) -> Self {
+        for (arg, name) in self.args.iter_mut().zip(names) {
+            arg.name = Some(name.into());
+        }
+        self
+    }
+
+    /// The name of the function.
+    ///
+    /// Functions converted with [`IntoFunction`] default to their [type name](std::any::type_name).
+    ///
+    /// [`IntoFunction`]: crate::func::IntoFunction
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The function's arguments.
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// The number of arguments the function takes.
+    pub fn arg_count(&self) -> usize {
+        self.args.len()
+    }
+
+    /// The function's return type.
+    pub fn return_info(&self) -> &ReturnInfo {
+        &self.return_info
+    }
+}
+
+/// Type information for an argument of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    index: usize,
+    name: Option<Cow<'static, str>>,
+    ownership: Ownership,
+    type_id: TypeId,
+    type_info: &'static TypeInfo,
+}
+
+impl ArgInfo {
+    /// Creates a new [`ArgInfo`] for an argument of type `T` at the given `index`.
+    pub fn new<T: Typed>(index: usize, ownership: Ownership) -> Self {
+        Self {
+            index,
+            name: None,
+            ownership,
+            type_id: TypeId::of::<T>(),
+            type_info: T::type_info(),
+        }
+    }
+
+    /// The index of the argument.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the argument, if one was given.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// How the argument is passed.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+
+    /// The [`TypeId`] of the argument (without any reference).
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The [type path] of the argument (without any reference).
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_info.type_path()
+    }
+
+    /// The [`TypeInfo`] of the argument (without any reference).
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+}
+
+/// Type information for the return type of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ReturnInfo {
+    type_id: TypeId,
+    type_info: &'static TypeInfo,
+}
+
+impl ReturnInfo {
+    /// Creates a new [`ReturnInfo`] for the return type `T`.
+    pub fn new<T: Typed>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_info: T::type_info(),
+        }
+    }
+
+    /// The [`TypeId`] of the return type.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The [type path] of the return type.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_info.type_path()
+    }
+
+    /// The [`TypeInfo`] of the return type.
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+}
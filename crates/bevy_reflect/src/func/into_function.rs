@@ -0,0 +1,107 @@
+use crate::func::args::{ArgList, Ownership};
+use crate::func::info::{ArgInfo, FunctionInfo, ReturnInfo};
+use crate::func::{DynamicFunction, IntoFunction};
+use crate::{FromReflect, Typed};
+use bevy_utils::all_tuples;
+
+/// Marker for functions that take a `&T` receiver.
+#[doc(hidden)]
+pub struct RefReceiver;
+
+/// Marker for functions that take a `&mut T` receiver.
+#[doc(hidden)]
+pub struct MutReceiver;
+
+macro_rules! impl_into_function {
+    ($($arg:ident),*) => {
+        #[allow(non_snake_case, unused_mut, unused_variables)]
+        impl<F, R, $($arg,)*> IntoFunction<fn($($arg),*) -> R> for F
+        where
+            F: Fn($($arg),*) -> R + Send + Sync + 'static,
+            R: Typed,
+            $($arg: FromReflect + Typed,)*
+        {
+            fn into_function(self) -> DynamicFunction {
+                let mut index = 0;
+                let args = vec![$({
+                    index += 1;
+                    ArgInfo::new::<$arg>(index - 1, Ownership::Owned)
+                }),*];
+                let info = FunctionInfo::new(std::any::type_name::<F>(), args, ReturnInfo::new::<R>());
+                DynamicFunction::new(
+                    move |args: ArgList| {
+                        let mut args = args.into_iter().enumerate();
+                        $(
+                            let (index, $arg) = args.next().unwrap();
+                            let $arg = $arg.take_owned::<$arg>(index)?;
+                        )*
+                        Ok(Box::new((self)($($arg),*)))
+                    },
+                    info,
+                )
+            }
+        }
+
+        #[allow(non_snake_case, unused_mut, unused_variables)]
+        impl<F, T, R, $($arg,)*> IntoFunction<(RefReceiver, fn(&T, $($arg),*) -> R)> for F
+        where
+            F: for<'a> Fn(&'a T, $($arg),*) -> R + Send + Sync + 'static,
+            T: Typed,
+            R: Typed,
+            $($arg: FromReflect + Typed,)*
+        {
+            fn into_function(self) -> DynamicFunction {
+                let mut index = 1;
+                let args = vec![ArgInfo::new::<T>(0, Ownership::Ref), $({
+                    index += 1;
+                    ArgInfo::new::<$arg>(index - 1, Ownership::Owned)
+                }),*];
+                let info = FunctionInfo::new(std::any::type_name::<F>(), args, ReturnInfo::new::<R>());
+                DynamicFunction::new(
+                    move |args: ArgList| {
+                        let mut args = args.into_iter().enumerate();
+                        let receiver = args.next().unwrap().1.take_ref::<T>(0)?;
+                        $(
+                            let (index, $arg) = args.next().unwrap();
+                            let $arg = $arg.take_owned::<$arg>(index)?;
+                        )*
+                        Ok(Box::new((self)(receiver, $($arg),*)))
+                    },
+                    info,
+                )
+            }
+        }
+
+        #[allow(non_snake_case, unused_mut, unused_variables)]
+        impl<F, T, R, $($arg,)*> IntoFunction<(MutReceiver, fn(&mut T, $($arg),*) -> R)> for F
+        where
+            F: for<'a> Fn(&'a mut T, $($arg),*) -> R + Send + Sync + 'static,
+            T: Typed,
+            R: Typed,
+            $($arg: FromReflect + Typed,)*
+        {
+            fn into_function(self) -> DynamicFunction {
+                let mut index = 1;
+                let args = vec![ArgInfo::new::<T>(0, Ownership::Mut), $({
+                    index += 1;
+                    ArgInfo::new::<$arg>(index - 1, Ownership::Owned)
+                }),*];
+                let info = FunctionInfo::new(std::any::type_name::<F>(), args, ReturnInfo::new::<R>());
+                DynamicFunction::new(
+                    move |args: ArgList| {
+                        let mut args = args.into_iter().enumerate();
+                        let receiver = args.next().unwrap().1.take_mut::<T>(0)?;
+                        $(
+                            let (index, $arg) = args.next().unwrap();
+                            let $arg = $arg.take_owned::<$arg>(index)?;
+                        )*
+                        Ok(Box::new((self)(receiver, $($arg),*)))
+                    },
+                    info,
+                )
+            }
+        }
+    };
+}
+
+all_tuples!(impl_into_function, 0, 8, A);
@@ -0,0 +1,78 @@
+use crate::func::{ArgList, DynamicFunction, FunctionResult};
+use bevy_utils::HashMap;
+use std::borrow::Cow;
+
+/// Type data containing the reflected methods of a type.
+///
+/// Methods are registered with [`TypeRegistry::register_method`] or [`TypeRegistration::register_method`].
+/// The receiver (such as `&self`) is passed as the first argument when calling them.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::func::{ArgList, ReflectMethods};
+/// #[derive(Reflect)]
+/// struct Counter(u32);
+///
+/// impl Counter {
+///     fn increment(&mut self, by: u32) {
+///         self.0 += by;
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Counter>();
+/// registry.register_method::<Counter, _>("increment", Counter::increment);
+///
+/// let mut counter = Counter(1);
+/// let methods = registry.get_type_data::<ReflectMethods>(std::any::TypeId::of::<Counter>()).unwrap();
+/// let args = ArgList::new().with_mut(&mut counter).with_owned(2_u32);
+/// methods.call("increment", args).unwrap().unwrap();
+/// assert_eq!(counter.0, 3);
+/// ```
+///
+/// [`TypeRegistry::register_method`]: crate::TypeRegistry::register_method
+/// [`TypeRegistration::register_method`]: crate::TypeRegistration::register_method
+#[derive(Debug, Clone, Default)]
+pub struct ReflectMethods {
+    methods: HashMap<Cow<'static, str>, DynamicFunction>,
+}
+
+impl ReflectMethods {
+    /// Adds a method with the given `name`, returning the method it replaced (if any).
+    pub fn insert(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: DynamicFunction,
+    ) -> Option<DynamicFunction> {
+        self.methods.insert(name.into(), method)
+    }
+
+    /// Returns the method with the given `name`.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction> {
+        self.methods.get(name)
+    }
+
+    /// Calls the method with the given `name`, or returns `None` if it does not exist.
+    pub fn call(&self, name: &str, args: ArgList) -> Option<FunctionResult> {
+        self.get(name).map(|method| method.call(args))
+    }
+
+    /// Iterates over the names and methods.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &DynamicFunction)> {
+        self.methods
+            .iter()
+            .map(|(name, method)| (name.as_ref(), method))
+    }
+
+    /// The number of methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if there are no methods.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
@@ -0,0 +1,180 @@
+//! Reflection for functions and methods.
+//!
+//! Any function or closure whose arguments and return type can be reflected can be converted into a
+//! [`DynamicFunction`] using [`IntoFunction`]. A [`DynamicFunction`] describes itself with [`FunctionInfo`]
+//! and can be called with an [`ArgList`] of reflected values, such as a list of `Box<dyn Reflect>`.
+//!
+//! Methods can be registered on a type's [`TypeRegistration`] as [`ReflectMethods`] type data.
+//!
+//! [`TypeRegistration`]: crate::TypeRegistration
+
+mod args;
+mod error;
+mod function;
+mod info;
+mod into_function;
+mod methods;
+
+pub use args::*;
+pub use error::*;
+pub use function::*;
+pub use info::*;
+pub use into_function::*;
+pub use methods::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{Reflect, TypeInfo, TypeRegistry};
+    use std::any::TypeId;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: u32,
+    }
+
+    impl Player {
+        fn health(&self) -> u32 {
+            self.health
+        }
+
+        fn heal(&mut self, amount: u32) {
+            self.health += amount;
+        }
+    }
+
+    fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    #[test]
+    fn should_call_function() {
+        let function = add.into_function();
+        let args = ArgList::from(vec![
+            Box::new(1_i32) as Box<dyn Reflect>,
+            Box::new(2_i32) as Box<dyn Reflect>,
+        ]);
+        let result = function.call(args).unwrap();
+        assert_eq!(result.take::<i32>().unwrap(), 3);
+    }
+
+    #[test]
+    fn should_call_closure() {
+        let offset = 10;
+        let function = (move |name: String| format!("{name}{offset}")).into_function();
+        let result = function
+            .call(ArgList::new().with_owned(String::from("player")))
+            .unwrap();
+        assert_eq!(result.take::<String>().unwrap(), "player10");
+
+        let function = (|| {}).into_function();
+        assert!(function.call(ArgList::new()).unwrap().is::<()>());
+    }
+
+    #[test]
+    fn should_describe_function() {
+        let function = add.into_function().with_arg_names(["a", "b"]);
+        let info = function.info();
+        assert!(info.name().ends_with("add"));
+        assert_eq!(info.arg_count(), 2);
+        assert_eq!(info.args()[0].name(), Some("a"));
+        assert_eq!(info.args()[1].type_id(), TypeId::of::<i32>());
+        assert_eq!(info.args()[1].ownership(), Ownership::Owned);
+        assert!(matches!(info.args()[1].type_info(), TypeInfo::Value(_)));
+        assert_eq!(info.return_info().type_path(), "i32");
+
+        let info = Player::heal
+            .into_function()
+            .with_name("heal")
+            .info()
+            .clone();
+        assert_eq!(info.name(), "heal");
+        assert_eq!(info.args()[0].ownership(), Ownership::Mut);
+        assert!(matches!(info.args()[0].type_info(), TypeInfo::Struct(_)));
+        assert_eq!(info.return_info().type_id(), TypeId::of::<()>());
+    }
+
+    #[test]
+    fn should_return_argument_errors() {
+        let function = add.into_function();
+        assert_eq!(
+            function.call(ArgList::new().with_owned(1_i32)).unwrap_err(),
+            FunctionError::ArgCount {
+                expected: 2,
+                received: 1
+            }
+        );
+        assert_eq!(
+            function
+                .call(ArgList::new().with_owned(1_i32).with_owned(2_u32))
+                .unwrap_err(),
+            FunctionError::InvalidArgType {
+                index: 1,
+                expected: "i32",
+                received: "u32".to_string(),
+            }
+        );
+
+        let function = Player::health.into_function();
+        let player = Player {
+            name: String::from("player"),
+            health: 10,
+        };
+        assert_eq!(
+            function
+                .call(ArgList::new().with_owned(player))
+                .unwrap_err(),
+            FunctionError::InvalidArgOwnership {
+                index: 0,
+                expected: Ownership::Ref,
+                received: Ownership::Owned,
+            }
+        );
+    }
+
+    #[test]
+    fn should_call_registered_methods() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register_method::<Player, _>("health", Player::health);
+        registry.register_method::<Player, _>("heal", Player::heal);
+
+        let mut player = Player {
+            name: String::from("player"),
+            health: 10,
+        };
+        let methods = registry
+            .get_type_data::<ReflectMethods>(TypeId::of::<Player>())
+            .unwrap();
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods.get("heal").unwrap().info().name(), "heal");
+
+        methods
+            .call(
+                "heal",
+                ArgList::new().with_mut(&mut player).with_owned(5_u32),
+            )
+            .unwrap()
+            .unwrap();
+        let health = methods
+            .call("health", ArgList::new().with_ref(&player))
+            .unwrap()
+            .unwrap();
+        assert_eq!(health.take::<u32>().unwrap(), 15);
+        assert!(methods.call("missing", ArgList::new()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "its receiver is of type `u32`")]
+    fn should_panic_on_mismatched_receiver() {
+        fn double(value: &u32) -> u32 {
+            value * 2
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register_method::<Player, _>("double", double);
+    }
+}
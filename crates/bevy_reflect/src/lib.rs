@@ -466,12 +466,15 @@
 //! [derive `Reflect`]: derive@crate::Reflect
 
 mod array;
+mod attributes;
+mod diff;
 mod fields;
 mod from_reflect;
 mod list;
 mod map;
 mod path;
 mod reflect;
+mod remote;
 mod struct_trait;
 mod tuple;
 mod tuple_struct;
@@ -495,6 +498,7 @@ mod impls {
 }
 
 mod enums;
+pub mod func;
 pub mod serde;
 pub mod std_traits;
 pub mod utility;
@@ -510,6 +514,8 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use attributes::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
@@ -517,6 +523,7 @@ pub use list::*;
 pub use map::*;
 pub use path::*;
 pub use reflect::*;
+pub use remote::*;
 pub use struct_trait::*;
 pub use tuple::*;
 pub use tuple_struct::*;
@@ -1526,6 +1533,79 @@ mod tests {
         dynamic_array.set_represented_type(Some(type_info));
     }
 
+    #[test]
+    fn should_reflect_custom_attributes() {
+        use std::ops::RangeInclusive;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Tooltip(String);
+
+        #[derive(Reflect)]
+        #[reflect(@Tooltip(String::from("A struct")))]
+        struct MyStruct {
+            #[reflect(@RangeInclusive::<f32>::new(0.0, 1.0), default)]
+            value: f32,
+            #[reflect(ignore)]
+            _ignored: usize,
+        }
+
+        #[derive(Reflect)]
+        #[reflect(@0_u8)]
+        struct MyTupleStruct(#[reflect(@Tooltip(String::from("A field")))] i32);
+
+        #[derive(Reflect)]
+        enum MyEnum {
+            #[reflect(@Tooltip(String::from("A unit variant")))]
+            Unit,
+            Struct {
+                #[reflect(@1_u8)]
+                value: i32,
+            },
+        }
+
+        let info = MyStruct::type_info();
+        assert_eq!(
+            Some(&Tooltip(String::from("A struct"))),
+            info.custom_attributes().unwrap().get::<Tooltip>()
+        );
+        let TypeInfo::Struct(info) = info else {
+            panic!("expected struct info");
+        };
+        let field = info.field("value").unwrap();
+        assert_eq!(
+            Some(&(0.0..=1.0)),
+            field.get_attribute::<RangeInclusive<f32>>()
+        );
+        assert!(!field.has_attribute::<Tooltip>());
+
+        let TypeInfo::TupleStruct(info) = MyTupleStruct::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert_eq!(Some(&0), info.get_attribute::<u8>());
+        assert_eq!(
+            Some(&Tooltip(String::from("A field"))),
+            info.field_at(0).unwrap().get_attribute::<Tooltip>()
+        );
+
+        let TypeInfo::Enum(info) = MyEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        assert!(info.custom_attributes().is_empty());
+        let unit = info.variant("Unit").unwrap();
+        assert_eq!(
+            Some(&Tooltip(String::from("A unit variant"))),
+            unit.custom_attributes().get::<Tooltip>()
+        );
+        let VariantInfo::Struct(variant) = info.variant("Struct").unwrap() else {
+            panic!("expected struct variant");
+        };
+        assert!(variant.custom_attributes().is_empty());
+        assert_eq!(
+            Some(&1),
+            variant.field("value").unwrap().get_attribute::<u8>()
+        );
+    }
+
     #[cfg(feature = "documentation")]
     mod docstrings {
         use super::*;
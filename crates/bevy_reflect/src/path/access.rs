@@ -1,7 +1,7 @@
 use std::{borrow::Cow, fmt};
 
 use super::{AccessError, ReflectPathError};
-use crate::{Reflect, ReflectMut, ReflectRef, VariantType};
+use crate::{Map, Reflect, ReflectMut, ReflectRef, VariantType};
 use thiserror::Error;
 
 type InnerResult<T> = Result<Option<T>, Error<'static>>;
@@ -26,6 +26,12 @@ pub(super) enum Error<'a> {
         expected: TypeShape,
         actual: TypeShape,
     },
+
+    #[error("invalid variant filter: expected the `{expected}` variant but found the `{actual}` variant")]
+    Variant { expected: String, actual: String },
+
+    #[error("wildcards can only be used when querying multiple elements")]
+    Wildcard,
 }
 
 impl<'a> Error<'a> {
@@ -105,12 +111,18 @@ impl From<VariantType> for TypeShape {
 /// A singular element access within a path.
 ///
 /// Can be applied to a `dyn Reflect` to get a reference to the targeted element.
+/// Wildcards ([`Access::AnyField`] and [`Access::AnyElement`]) target multiple elements,
+/// and can only be used by path queries.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(super) enum Access<'a> {
     Field(Cow<'a, str>),
     FieldIndex(usize),
     TupleIndex(usize),
     ListIndex(usize),
+    MapKey(Cow<'a, str>),
+    Variant(Cow<'a, str>),
+    AnyField,
+    AnyElement,
 }
 
 impl fmt::Display for Access<'_> {
@@ -120,6 +132,19 @@ impl fmt::Display for Access<'_> {
             Access::FieldIndex(index) => write!(f, "#{index}"),
             Access::TupleIndex(index) => write!(f, ".{index}"),
             Access::ListIndex(index) => write!(f, "[{index}]"),
+            Access::MapKey(key) => {
+                f.write_str("[\"")?;
+                for c in key.chars() {
+                    if matches!(c, '"' | '\\') {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                f.write_str("\"]")
+            }
+            Access::Variant(variant) => write!(f, "{{{variant}}}"),
+            Access::AnyField => f.write_str(".*"),
+            Access::AnyElement => f.write_str("[*]"),
         }
     }
 }
@@ -131,13 +156,18 @@ impl<'a> Access<'a> {
             Self::FieldIndex(value) => Access::FieldIndex(value),
             Self::TupleIndex(value) => Access::TupleIndex(value),
             Self::ListIndex(value) => Access::ListIndex(value),
+            Self::MapKey(value) => Access::MapKey(value.to_string().into()),
+            Self::Variant(value) => Access::Variant(value.to_string().into()),
+            Self::AnyField => Access::AnyField,
+            Self::AnyElement => Access::AnyElement,
         }
     }
 
     fn display_value(&self) -> &dyn fmt::Display {
         match self {
-            Self::Field(value) => value,
+            Self::Field(value) | Self::MapKey(value) | Self::Variant(value) => value,
             Self::FieldIndex(value) | Self::TupleIndex(value) | Self::ListIndex(value) => value,
+            Self::AnyField | Self::AnyElement => &"*",
         }
     }
     fn kind(&self) -> &'static str {
@@ -145,9 +175,17 @@ impl<'a> Access<'a> {
             Self::Field(_) => "field",
             Self::FieldIndex(_) => "field index",
             Self::TupleIndex(_) | Self::ListIndex(_) => "index",
+            Self::MapKey(_) => "key",
+            Self::Variant(_) => "variant",
+            Self::AnyField | Self::AnyElement => "wildcard",
         }
     }
 
+    /// Returns `true` if this access can target more than one element.
+    pub(super) fn is_wildcard(&self) -> bool {
+        matches!(self, Self::AnyField | Self::AnyElement)
+    }
+
     pub(super) fn element<'r>(
         &self,
         base: &'r dyn Reflect,
@@ -162,6 +200,15 @@ impl<'a> Access<'a> {
     fn element_inner<'r>(&self, base: &'r dyn Reflect) -> InnerResult<&'r dyn Reflect> {
         use ReflectRef::*;
         match (self, base.reflect_ref()) {
+            (Self::MapKey(key), Map(map)) => {
+                Ok(map_key_index(map, key).and_then(|index| map.get_at(index).map(|(_, v)| v)))
+            }
+            (Self::MapKey(_), actual) => Err(Error::bad_type(TypeShape::Map, actual)),
+            (Self::Variant(variant), Enum(enum_ref)) => {
+                check_variant(variant, enum_ref.variant_name()).map(|_| Some(base))
+            }
+            (Self::Variant(_), actual) => Err(Error::bad_type(TypeShape::Enum, actual)),
+            (Self::AnyField | Self::AnyElement, _) => Err(Error::Wildcard),
             (Self::Field(field), Struct(struct_ref)) => Ok(struct_ref.field(field.as_ref())),
             (Self::Field(field), Enum(enum_ref)) => match enum_ref.variant_type() {
                 VariantType::Struct => Ok(enum_ref.field(field.as_ref())),
@@ -199,7 +246,21 @@ impl<'a> Access<'a> {
     fn element_inner_mut<'r>(&self, base: &'r mut dyn Reflect) -> InnerResult<&'r mut dyn Reflect> {
         use ReflectMut::*;
         let base_shape: TypeShape = base.reflect_ref().into();
+        if let Self::Variant(variant) = self {
+            return match base.reflect_ref() {
+                ReflectRef::Enum(enum_ref) => {
+                    check_variant(variant, enum_ref.variant_name()).map(|_| Some(base))
+                }
+                actual => Err(Error::bad_type(TypeShape::Enum, actual)),
+            };
+        }
         match (self, base.reflect_mut()) {
+            (Self::MapKey(key), Map(map)) => {
+                Ok(map_key_index(map, key).and_then(|index| map.get_at_mut(index).map(|(_, v)| v)))
+            }
+            (Self::MapKey(_), _) => Err(Error::bad_type(TypeShape::Map, base_shape)),
+            (Self::Variant(_), _) => unreachable!("variant filters are handled above"),
+            (Self::AnyField | Self::AnyElement, _) => Err(Error::Wildcard),
             (Self::Field(field), Struct(struct_mut)) => Ok(struct_mut.field_mut(field.as_ref())),
             (Self::Field(field), Enum(enum_mut)) => match enum_mut.variant_type() {
                 VariantType::Struct => Ok(enum_mut.field_mut(field.as_ref())),
@@ -223,3 +284,30 @@ impl<'a> Access<'a> {
         }
     }
 }
+
+/// Returns the string form of a map key, if it is a string.
+pub(super) fn map_key_str(key: &dyn Reflect) -> Option<&str> {
+    match key.downcast_ref::<String>() {
+        Some(key) => Some(key),
+        None => key.downcast_ref::<Cow<'static, str>>().map(AsRef::as_ref),
+    }
+}
+
+/// Returns the index of the entry of `map` whose key is the string `key`.
+fn map_key_index(map: &dyn Map, key: &str) -> Option<usize> {
+    (0..map.len()).find(|&index| {
+        map.get_at(index)
+            .is_some_and(|(entry_key, _)| map_key_str(entry_key) == Some(key))
+    })
+}
+
+fn check_variant(expected: &str, actual: &str) -> Result<(), Error<'static>> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Error::Variant {
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        })
+    }
+}
@@ -1,5 +1,6 @@
 mod access;
 mod parse;
+mod query;
 
 use std::fmt;
 
@@ -9,6 +10,7 @@ use parse::PathParser;
 use thiserror::Error;
 
 pub use parse::ParseError;
+pub use query::{PathMatches, PathMatchesMut};
 
 type PathResult<'a, T> = Result<T, ReflectPathError<'a>>;
 
@@ -197,6 +199,42 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// assert!(matches!(tuple_variant.path::<u32>(".value"), Err(_)));
 /// ```
 ///
+/// A variant filter, written as the variant name in braces (`{Tuple}`), can be used to
+/// check the current variant before accessing its fields.
+/// It fails with an error describing the actual variant when it doesn't match,
+/// and skips the element when used in a [query](#queries).
+///
+/// ### Example
+/// ```
+/// # use bevy_reflect::{GetPath, Reflect};
+/// # #[derive(Reflect)]
+/// # enum MyEnum {
+/// #   Tuple(bool),
+/// #   Struct {
+/// #     value: u32
+/// #   }
+/// # }
+/// let tuple_variant = MyEnum::Tuple(true);
+/// assert_eq!(tuple_variant.path::<bool>("{Tuple}.0").unwrap(), &true);
+///
+/// // Error: Expected the `Struct` variant
+/// assert!(matches!(tuple_variant.path::<u32>("{Struct}.value"), Err(_)));
+/// ```
+///
+/// ## Maps
+///
+/// [`Map`] entries with string keys are accessed with a quoted key in brackets: `["key"]`.
+/// Quotes and backslashes within the key are escaped with a backslash: `["say \"hi\""]`.
+///
+/// ### Example
+/// ```
+/// # use bevy_reflect::{GetPath};
+/// # use bevy_utils::HashMap;
+/// let mut stats = HashMap::<String, f32>::default();
+/// stats.insert("speed".to_string(), 2.5);
+/// assert_eq!(stats.path::<f32>("[\"speed\"]").unwrap(), &2.5);
+/// ```
+///
 /// # Chaining
 ///
 /// Using the aforementioned syntax, path items may be chained one after another
@@ -219,12 +257,51 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// );
 /// ```
 ///
+/// # Queries
+///
+/// Paths passed to [`reflect_query`](GetPath::reflect_query) and
+/// [`reflect_query_mut`](GetPath::reflect_query_mut) may match any number of elements,
+/// and additionally support wildcards:
+/// - `.*` matches every field of a struct, tuple struct, tuple or enum variant
+/// - `[*]` matches every element of a list or array, and every value of a map with string keys
+///
+/// Each match is returned along with its concrete path, where every wildcard is replaced
+/// by the field, index or key it matched.
+/// Elements that the rest of the path can't be applied to are skipped rather than
+/// reported as errors.
+///
+/// ## Example
+/// ```
+/// # use bevy_reflect::{GetPath, Reflect};
+/// #[derive(Reflect)]
+/// enum Slot {
+///   Empty,
+///   Item { health: u32 },
+/// }
+///
+/// let slots = vec![Slot::Item { health: 3 }, Slot::Empty, Slot::Item { health: 5 }];
+/// let matches: Vec<_> = slots
+///   .reflect_query("[*]{Item}.health")
+///   .unwrap()
+///   .map(|(path, health)| (path.to_string(), *health.downcast_ref::<u32>().unwrap()))
+///   .collect();
+///
+/// assert_eq!(
+///   matches,
+///   [
+///     ("[0]{Item}.health".to_string(), 3),
+///     ("[2]{Item}.health".to_string(), 5),
+///   ],
+/// );
+/// ```
+///
 /// [`Struct`]: crate::Struct
 /// [`Tuple`]: crate::Tuple
 /// [`TupleStruct`]: crate::TupleStruct
 /// [`List`]: crate::List
 /// [`Array`]: crate::Array
 /// [`Enum`]: crate::Enum
+/// [`Map`]: crate::Map
 pub trait GetPath: Reflect {
     /// Returns a reference to the value specified by `path`.
     ///
@@ -266,6 +343,22 @@ pub trait GetPath: Reflect {
     fn path_mut<'p, T: Reflect>(&mut self, path: impl ReflectPath<'p>) -> PathResult<'p, &mut T> {
         path.element_mut(self.as_reflect_mut())
     }
+
+    /// Returns every value matched by the [query](GetPath#queries) `path`,
+    /// along with its concrete path.
+    ///
+    /// Returns an error only if `path` can't be parsed.
+    fn reflect_query<'p>(&self, path: &'p str) -> PathResult<'p, PathMatches<'_>> {
+        Ok(ParsedPath::parse(path)?.query(self.as_reflect()))
+    }
+
+    /// Returns a lending iterator over mutable references to every value matched
+    /// by the [query](GetPath#queries) `path`, along with its concrete path.
+    ///
+    /// Returns an error only if `path` can't be parsed.
+    fn reflect_query_mut<'p>(&mut self, path: &'p str) -> PathResult<'p, PathMatchesMut<'_>> {
+        Ok(ParsedPath::parse(path)?.query_mut(self.as_reflect_mut()))
+    }
 }
 
 // Implement `GetPath` for `dyn Reflect`
@@ -301,6 +394,9 @@ impl ParsedPath {
     /// - Unnamed field access (`.1`)
     /// - Field index access (`#0`)
     /// - Sequence access (`[2]`)
+    /// - Map key access (`["key"]`)
+    /// - Enum variant filter (`{Variant}`)
+    /// - Field and element wildcards (`.*` and `[*]`), only valid in [queries](GetPath#queries)
     ///
     /// # Example
     /// ```
@@ -352,6 +448,29 @@ impl ParsedPath {
         }
         Ok(Self(parts.into_boxed_slice()))
     }
+
+    /// Returns `true` if this path contains a wildcard, and may therefore match
+    /// more than one element.
+    pub fn is_query(&self) -> bool {
+        self.0.iter().any(|(access, _)| access.is_wildcard())
+    }
+
+    /// Returns every element of `root` matched by this path, along with its concrete path.
+    ///
+    /// See the [queries](GetPath#queries) section of [`GetPath`] for more details.
+    pub fn query<'r>(&self, root: &'r dyn Reflect) -> PathMatches<'r> {
+        let mut matches = Vec::new();
+        query::collect_matches(&self.0, root, &mut Vec::new(), &mut matches);
+        PathMatches(matches.into_iter())
+    }
+
+    /// Returns a lending iterator over mutable references to every element of `root`
+    /// matched by this path, along with its concrete path.
+    ///
+    /// See the [queries](GetPath#queries) section of [`GetPath`] for more details.
+    pub fn query_mut<'r>(&self, root: &'r mut dyn Reflect) -> PathMatchesMut<'r> {
+        PathMatchesMut::new(self, root)
+    }
 }
 impl<'a> ReflectPath<'a> for &'a ParsedPath {
     fn reflect_element(self, mut root: &dyn Reflect) -> PathResult<'a, &dyn Reflect> {
@@ -653,4 +772,179 @@ mod tests {
             &[(Access::ListIndex(0), 1), (access_field("łørđ"), 4)]
         );
     }
+
+    #[derive(Reflect)]
+    struct Inventory {
+        slots: Vec<F>,
+        stats: bevy_utils::HashMap<String, f32>,
+    }
+
+    fn inventory_sample() -> Inventory {
+        let mut stats = bevy_utils::HashMap::default();
+        stats.insert("speed".to_string(), 2.5);
+        stats.insert("a \"quoted\" key".to_string(), 1.0);
+        Inventory {
+            slots: vec![
+                F::Tuple(1, 2),
+                F::Unit,
+                F::Şķràźÿ { 東京: 'x' },
+                F::Tuple(3, 4),
+            ],
+            stats,
+        }
+    }
+
+    #[test]
+    fn parsed_path_parse_queries() {
+        let path = ParsedPath::parse(r#"slots[*]{Tuple}.*.stats["speed"]"#).unwrap();
+        assert_eq!(
+            &*path.0,
+            &[
+                (access_field("slots"), 5),
+                (Access::AnyElement, 6),
+                (Access::Variant("Tuple".into()), 9),
+                (Access::AnyField, 16),
+                (access_field("stats"), 18),
+                (Access::MapKey("speed".into()), 24),
+            ]
+        );
+        assert!(path.is_query());
+        assert_eq!(path.to_string(), r#".slots[*]{Tuple}.*.stats["speed"]"#);
+        assert!(!ParsedPath::parse("slots[0]{Tuple}").unwrap().is_query());
+
+        let escaped = ParsedPath::parse(r#"["a \"quoted\" key"]"#).unwrap();
+        assert_eq!(escaped.to_string(), r#"["a \"quoted\" key"]"#);
+    }
+
+    #[test]
+    fn reflect_path_map_keys_and_variants() {
+        let mut inventory = inventory_sample();
+
+        assert_eq!(*inventory.path::<f32>(r#"stats["speed"]"#).unwrap(), 2.5);
+        assert_eq!(
+            *inventory
+                .path::<f32>(r#"stats["a \"quoted\" key"]"#)
+                .unwrap(),
+            1.0
+        );
+        *inventory.path_mut::<f32>(r#"stats["speed"]"#).unwrap() = 5.0;
+        assert_eq!(inventory.stats["speed"], 5.0);
+
+        assert_eq!(*inventory.path::<u32>("slots[0]{Tuple}.1").unwrap(), 2);
+        *inventory.path_mut::<u32>("slots[3]{Tuple}.0").unwrap() = 30;
+        assert_eq!(inventory.slots[3], F::Tuple(30, 4));
+
+        assert_eq!(
+            inventory.reflect_path(r#"stats["missing"]"#).err().unwrap(),
+            ReflectPathError::InvalidAccess {
+                offset: 6,
+                error: AccessError(access::Error::Access {
+                    ty: TypeShape::Map,
+                    access: Access::MapKey("missing".into()),
+                }),
+            }
+        );
+        assert_eq!(
+            inventory.reflect_path("slots[1]{Tuple}.0").err().unwrap(),
+            ReflectPathError::InvalidAccess {
+                offset: 9,
+                error: AccessError(access::Error::Variant {
+                    expected: "Tuple".to_string(),
+                    actual: "Unit".to_string(),
+                }),
+            }
+        );
+        assert_eq!(
+            inventory.reflect_path("slots[*]").err().unwrap(),
+            ReflectPathError::InvalidAccess {
+                offset: 6,
+                error: AccessError(access::Error::Wildcard),
+            }
+        );
+        assert_eq!(
+            inventory.reflect_path(r#"slots["speed"]"#).err().unwrap(),
+            invalid_access(6, TypeShape::List, TypeShape::Map)
+        );
+    }
+
+    #[test]
+    fn reflect_query() {
+        let inventory = inventory_sample();
+
+        let matches: Vec<_> = inventory
+            .reflect_query("slots[*]{Tuple}.*")
+            .unwrap()
+            .map(|(path, value)| (path.to_string(), *value.downcast_ref::<u32>().unwrap()))
+            .collect();
+        assert_eq!(
+            matches,
+            [
+                (".slots[0]{Tuple}.0".to_string(), 1),
+                (".slots[0]{Tuple}.1".to_string(), 2),
+                (".slots[3]{Tuple}.0".to_string(), 3),
+                (".slots[3]{Tuple}.1".to_string(), 4),
+            ]
+        );
+
+        // Unit variants have no fields, and only the struct variant has a `東京` field.
+        let matches: Vec<_> = inventory
+            .reflect_query("slots[*].*")
+            .unwrap()
+            .map(|(path, _)| path.to_string())
+            .collect();
+        assert_eq!(matches.len(), 5);
+        assert!(matches.contains(&".slots[2].東京".to_string()));
+
+        let mut stats: Vec<_> = inventory
+            .reflect_query("stats[*]")
+            .unwrap()
+            .map(|(path, value)| (path.to_string(), *value.downcast_ref::<f32>().unwrap()))
+            .collect();
+        stats.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            stats,
+            [
+                (r#".stats["a \"quoted\" key"]"#.to_string(), 1.0),
+                (r#".stats["speed"]"#.to_string(), 2.5),
+            ]
+        );
+
+        // Concrete paths resolve to the same element with the single element API.
+        for (path, value) in inventory.reflect_query("slots[*]{Şķràźÿ}.東京").unwrap() {
+            assert!(!path.is_query());
+            assert_eq!(path.element::<char>(&inventory).unwrap(), &'x');
+            assert_eq!(value.downcast_ref::<char>(), Some(&'x'));
+        }
+
+        assert_eq!(
+            inventory.reflect_query("slots[*].notreal").unwrap().len(),
+            0
+        );
+        assert!(inventory.reflect_query("slots[*").is_err());
+    }
+
+    #[test]
+    fn reflect_query_mut() {
+        let mut inventory = inventory_sample();
+
+        let mut matches = inventory.reflect_query_mut("slots[*]{Tuple}.1").unwrap();
+        let mut paths = Vec::new();
+        while let Some((path, value)) = matches.next() {
+            paths.push(path.to_string());
+            *value.downcast_mut::<u32>().unwrap() *= 10;
+        }
+        assert_eq!(paths, [".slots[0]{Tuple}.1", ".slots[3]{Tuple}.1"]);
+        assert_eq!(inventory.slots[0], F::Tuple(1, 20));
+        assert_eq!(inventory.slots[3], F::Tuple(3, 40));
+
+        // Whole elements can be replaced through their match.
+        let mut matches = inventory.reflect_query_mut("slots[*]").unwrap();
+        let mut visited = 0;
+        while let Some((_, value)) = matches.next() {
+            visited += 1;
+            value.apply(&F::Unit);
+        }
+        assert_eq!(visited, 4);
+        assert_eq!(inventory.slots, [F::Unit, F::Unit, F::Unit, F::Unit]);
+    }
 }
@@ -1,4 +1,4 @@
-use std::{fmt, num::ParseIntError, str::from_utf8_unchecked};
+use std::{borrow::Cow, fmt, num::ParseIntError, str::from_utf8_unchecked};
 
 use thiserror::Error;
 
@@ -29,6 +29,18 @@ enum Error<'a> {
 
     #[error("a ']' was found before an opening '['")]
     CloseBeforeOpen,
+
+    #[error("a '\"' wasn't closed, reached end of path string before finding a closing '\"'")]
+    UnclosedKey,
+
+    #[error("a '{{' wasn't closed, reached end of path string before finding a '}}'")]
+    UnclosedFilter,
+
+    #[error("a '{{' wasn't closed properly, got '{0}' instead")]
+    BadFilterClose(Token<'a>),
+
+    #[error("a '}}' was found before an opening '{{'")]
+    CloseBeforeOpenFilter,
 }
 
 pub(super) struct PathParser<'a> {
@@ -60,7 +72,8 @@ impl<'a> PathParser<'a> {
         // - The slice's end is either the same as the valid &str or
         //   the last byte before an ASCII utf-8 character (ie: it is a char
         //   boundary).
-        // - The slice always starts after a symbol ie: an ASCII character's boundary.
+        // - The slice always starts after a symbol or the closing quote of a map
+        //   key ie: an ASCII character's boundary.
         let ident = unsafe { from_utf8_unchecked(ident) };
 
         self.remaining = remaining;
@@ -82,16 +95,62 @@ impl<'a> PathParser<'a> {
             Token::Ident(ident) => Ok(ident.field()),
             Token::CloseBracket => Err(Error::CloseBeforeOpen),
             Token::OpenBracket => {
-                let index_ident = self.next_ident()?.list_index()?;
+                let index_ident = if self.remaining.first() == Some(&b'"') {
+                    Access::MapKey(self.next_key()?)
+                } else {
+                    self.next_ident()?.list_index()?
+                };
                 match self.next_token() {
                     Some(Token::CloseBracket) => Ok(index_ident),
                     Some(other) => Err(Error::BadClose(other)),
                     None => Err(Error::Unclosed),
                 }
             }
+            Token::CloseBrace => Err(Error::CloseBeforeOpenFilter),
+            Token::OpenBrace => {
+                let variant = Access::Variant(self.next_ident()?.0.into());
+                match self.next_token() {
+                    Some(Token::CloseBrace) => Ok(variant),
+                    Some(other) => Err(Error::BadFilterClose(other)),
+                    None => Err(Error::UnclosedFilter),
+                }
+            }
         }
     }
 
+    /// Parses a `"quoted"` map key, where `\"` and `\\` escape a quote and a backslash.
+    fn next_key(&mut self) -> Result<Cow<'a, str>, Error<'a>> {
+        // Skip the opening quote, which is ASCII.
+        let start = self.offset() + 1;
+        let quoted = &self.path[start..];
+        let mut escaped = false;
+        let mut has_escapes = false;
+        for (i, c) in quoted.char_indices() {
+            match c {
+                '\\' if !escaped => {
+                    escaped = true;
+                    has_escapes = true;
+                    continue;
+                }
+                '"' if !escaped => {
+                    let key = &quoted[..i];
+                    self.remaining = &quoted.as_bytes()[i + 1..];
+                    if !has_escapes {
+                        return Ok(Cow::Borrowed(key));
+                    }
+                    let mut unescaped = String::with_capacity(key.len());
+                    let mut chars = key.chars();
+                    while let Some(c) = chars.next() {
+                        unescaped.extend(if c == '\\' { chars.next() } else { Some(c) });
+                    }
+                    return Ok(Cow::Owned(unescaped));
+                }
+                _ => escaped = false,
+            }
+        }
+        Err(Error::UnclosedKey)
+    }
+
     fn offset(&self) -> usize {
         self.path.len() - self.remaining.len()
     }
@@ -116,6 +175,9 @@ struct Ident<'a>(&'a str);
 
 impl<'a> Ident<'a> {
     fn field(self) -> Access<'a> {
+        if self.0 == "*" {
+            return Access::AnyField;
+        }
         let field = |_| Access::Field(self.0.into());
         self.0.parse().map(Access::TupleIndex).unwrap_or_else(field)
     }
@@ -123,6 +185,9 @@ impl<'a> Ident<'a> {
         Ok(Access::FieldIndex(self.0.parse()?))
     }
     fn list_index(self) -> Result<Access<'a>, Error<'a>> {
+        if self.0 == "*" {
+            return Ok(Access::AnyElement);
+        }
         Ok(Access::ListIndex(self.0.parse()?))
     }
 }
@@ -137,6 +202,8 @@ enum Token<'a> {
     Pound = b'#',
     OpenBracket = b'[',
     CloseBracket = b']',
+    OpenBrace = b'{',
+    CloseBrace = b'}',
     Ident(Ident<'a>),
 }
 impl fmt::Display for Token<'_> {
@@ -146,19 +213,23 @@ impl fmt::Display for Token<'_> {
             Token::Pound => "#",
             Token::OpenBracket => "[",
             Token::CloseBracket => "]",
+            Token::OpenBrace => "{",
+            Token::CloseBrace => "}",
             Token::Ident(ident) => ident.0,
         };
         f.write_str(text)
     }
 }
 impl<'a> Token<'a> {
-    const SYMBOLS: &'static [u8] = b".#[]";
+    const SYMBOLS: &'static [u8] = b".#[]{}";
     fn symbol_from_byte(byte: u8) -> Option<Self> {
         match byte {
             b'.' => Some(Self::Dot),
             b'#' => Some(Self::Pound),
             b'[' => Some(Self::OpenBracket),
             b']' => Some(Self::CloseBracket),
+            b'{' => Some(Self::OpenBrace),
+            b'}' => Some(Self::CloseBrace),
             _ => None,
         }
     }
@@ -187,5 +258,36 @@ mod test {
                 path: "y[badindex]",
             }),
         ));
+        assert_eq!(
+            ParsedPath::parse_static("z[\"key]"),
+            Err(ReflectPathError::ParseError {
+                error: ParseError(Error::UnclosedKey),
+                offset: 2,
+                path: "z[\"key]",
+            }),
+        );
+        assert_eq!(
+            ParsedPath::parse_static("w{Some.0"),
+            Err(ReflectPathError::ParseError {
+                error: ParseError(Error::BadFilterClose(Token::Dot)),
+                offset: 2,
+                path: "w{Some.0",
+            }),
+        );
+    }
+
+    #[test]
+    fn parse_map_keys() {
+        let mut parser = PathParser::new(r#"["plain"]["a \"quoted\" \\ key"]["[.#]"]"#);
+        let mut next = || parser.next().map(|(access, _)| access.unwrap());
+        assert_eq!(next(), Some(Access::MapKey(Cow::Borrowed("plain"))));
+        assert_eq!(
+            next(),
+            Some(Access::MapKey(Cow::Owned(
+                r#"a "quoted" \ key"#.to_string()
+            )))
+        );
+        assert_eq!(next(), Some(Access::MapKey(Cow::Borrowed("[.#]"))));
+        assert_eq!(next(), None);
     }
 }
@@ -0,0 +1,160 @@
+use std::vec;
+
+use super::{
+    access::{map_key_str, Access},
+    ParsedPath, ReflectPath,
+};
+use crate::{Reflect, ReflectRef};
+
+/// An iterator over the elements matched by a path query.
+///
+/// Each item is the concrete path of the matched element, with every wildcard
+/// replaced by the field, index or map key it resolved to, along with the element itself.
+///
+/// Created by [`ParsedPath::query`] and [`GetPath::reflect_query`].
+///
+/// [`GetPath::reflect_query`]: super::GetPath::reflect_query
+pub struct PathMatches<'r>(pub(super) vec::IntoIter<(ParsedPath, &'r dyn Reflect)>);
+
+impl<'r> Iterator for PathMatches<'r> {
+    type Item = (ParsedPath, &'r dyn Reflect);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for PathMatches<'_> {}
+
+/// A lending iterator over mutable references to the elements matched by a path query.
+///
+/// All matches borrow from the same root value, so only one of them can be borrowed at a time.
+/// This means this type can't implement [`Iterator`], and should be consumed with `while let`:
+///
+/// ```
+/// # use bevy_reflect::{GetPath, Reflect};
+/// #[derive(Reflect)]
+/// struct Item {
+///   health: u32,
+/// }
+///
+/// let mut items = vec![Item { health: 1 }, Item { health: 2 }];
+/// let mut matches = items.reflect_query_mut("[*].health").unwrap();
+/// while let Some((path, health)) = matches.next() {
+///   *health.downcast_mut::<u32>().unwrap() *= 10;
+///   println!("updated {path}");
+/// }
+///
+/// assert_eq!(items[1].health, 20);
+/// ```
+///
+/// Matches are resolved when the query is created, and looked up again by their concrete
+/// path as they are reached.
+///
+/// Created by [`ParsedPath::query_mut`] and [`GetPath::reflect_query_mut`].
+///
+/// [`GetPath::reflect_query_mut`]: super::GetPath::reflect_query_mut
+pub struct PathMatchesMut<'r> {
+    root: &'r mut dyn Reflect,
+    paths: vec::IntoIter<ParsedPath>,
+    current: Option<ParsedPath>,
+}
+
+impl<'r> PathMatchesMut<'r> {
+    pub(super) fn new(path: &ParsedPath, root: &'r mut dyn Reflect) -> Self {
+        let paths: Vec<_> = path.query(root).map(|(path, _)| path).collect();
+        Self {
+            root,
+            paths: paths.into_iter(),
+            current: None,
+        }
+    }
+
+    /// Returns the next matched element, along with its concrete path.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(&ParsedPath, &mut dyn Reflect)> {
+        loop {
+            let path = self.paths.next()?;
+            if path.reflect_element(&*self.root).is_ok() {
+                let path = self.current.insert(path);
+                let element = path.reflect_element_mut(self.root).ok()?;
+                return Some((path, element));
+            }
+        }
+    }
+}
+
+/// Collects every element of `value` matched by `accesses` into `matches`.
+///
+/// `resolved` holds the concrete accesses leading to `value`.
+/// Branches that the remaining accesses can't be applied to are skipped.
+pub(super) fn collect_matches<'r>(
+    accesses: &[(Access<'static>, usize)],
+    value: &'r dyn Reflect,
+    resolved: &mut Vec<(Access<'static>, usize)>,
+    matches: &mut Vec<(ParsedPath, &'r dyn Reflect)>,
+) {
+    let Some(((access, offset), rest)) = accesses.split_first() else {
+        matches.push((ParsedPath(resolved.clone().into_boxed_slice()), value));
+        return;
+    };
+    let mut visit = |access: Access<'static>, element: &'r dyn Reflect| {
+        resolved.push((access, *offset));
+        collect_matches(rest, element, resolved, matches);
+        resolved.pop();
+    };
+    match (access, value.reflect_ref()) {
+        (Access::AnyField, ReflectRef::Struct(struct_ref)) => {
+            for (index, field) in struct_ref.iter_fields().enumerate() {
+                if let Some(name) = struct_ref.name_at(index) {
+                    visit(Access::Field(name.to_string().into()), field);
+                }
+            }
+        }
+        (Access::AnyField, ReflectRef::TupleStruct(tuple)) => {
+            for (index, field) in tuple.iter_fields().enumerate() {
+                visit(Access::TupleIndex(index), field);
+            }
+        }
+        (Access::AnyField, ReflectRef::Tuple(tuple)) => {
+            for (index, field) in tuple.iter_fields().enumerate() {
+                visit(Access::TupleIndex(index), field);
+            }
+        }
+        (Access::AnyField, ReflectRef::Enum(enum_ref)) => {
+            for (index, field) in enum_ref.iter_fields().enumerate() {
+                match field.name() {
+                    Some(name) => visit(Access::Field(name.to_string().into()), field.value()),
+                    None => visit(Access::TupleIndex(index), field.value()),
+                }
+            }
+        }
+        (Access::AnyElement, ReflectRef::List(list)) => {
+            for (index, element) in list.iter().enumerate() {
+                visit(Access::ListIndex(index), element);
+            }
+        }
+        (Access::AnyElement, ReflectRef::Array(array)) => {
+            for (index, element) in array.iter().enumerate() {
+                visit(Access::ListIndex(index), element);
+            }
+        }
+        (Access::AnyElement, ReflectRef::Map(map)) => {
+            for (key, element) in map.iter() {
+                if let Some(key) = map_key_str(key) {
+                    visit(Access::MapKey(key.to_string().into()), element);
+                }
+            }
+        }
+        (Access::AnyField | Access::AnyElement, _) => {}
+        (access, _) => {
+            if let Ok(element) = access.element(value, *offset) {
+                visit(access.clone(), element);
+            }
+        }
+    }
+}
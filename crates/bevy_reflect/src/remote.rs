@@ -0,0 +1,241 @@
+use crate::Reflect;
+
+/// A trait used to access a remote type through its reflected wrapper.
+///
+/// Due to Rust's orphan rule, [`Reflect`] cannot be implemented for types defined in other crates.
+/// Instead, the [`reflect_remote`] attribute macro can be used to create a `#[repr(transparent)]`
+/// wrapper around the remote type which mirrors its fields and implements reflection on its behalf.
+///
+/// This trait is automatically implemented for such wrappers
+/// and should not need to be implemented manually.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{reflect_remote, Reflect, ReflectRemote, Struct};
+/// mod external_crate {
+///     pub struct Position {
+///         pub x: f32,
+///         pub y: f32,
+///     }
+/// }
+///
+/// #[reflect_remote(external_crate::Position)]
+/// struct PositionWrapper {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(remote = PositionWrapper)]
+///     position: external_crate::Position,
+/// }
+///
+/// let mut player = Player {
+///     position: external_crate::Position { x: 1.0, y: 2.0 },
+/// };
+///
+/// let position = player.field_mut("position").unwrap();
+/// *position.downcast_mut::<PositionWrapper>().unwrap().as_remote_mut() =
+///     external_crate::Position { x: 3.0, y: 4.0 };
+///
+/// assert_eq!(3.0, player.position.x);
+/// assert_eq!(4.0, player.position.y);
+/// ```
+///
+/// [`reflect_remote`]: crate::reflect_remote
+pub trait ReflectRemote: Reflect {
+    /// The remote type this wrapper reflects.
+    type Remote: 'static;
+
+    /// Returns a reference to the wrapped remote value.
+    fn as_remote(&self) -> &Self::Remote;
+    /// Returns a mutable reference to the wrapped remote value.
+    fn as_remote_mut(&mut self) -> &mut Self::Remote;
+    /// Unwraps the remote value.
+    fn into_remote(self) -> Self::Remote
+    where
+        Self: Sized;
+
+    /// Reinterprets a reference to the remote value as a reference to its wrapper.
+    fn as_wrapper(remote: &Self::Remote) -> &Self;
+    /// Reinterprets a mutable reference to the remote value as a mutable reference to its wrapper.
+    fn as_wrapper_mut(remote: &mut Self::Remote) -> &mut Self;
+    /// Wraps the remote value.
+    fn into_wrapper(remote: Self::Remote) -> Self
+    where
+        Self: Sized;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::{
+        reflect_remote, DynamicEnum, DynamicStruct, DynamicTuple, Enum, FromReflect, GetField,
+        GetTupleStructField, Reflect, ReflectRemote, Struct, TypeInfo, Typed,
+    };
+
+    mod external {
+        #[derive(Debug, PartialEq)]
+        pub struct Vec2 {
+            pub x: f32,
+            pub y: f32,
+        }
+
+        #[derive(Debug, PartialEq)]
+        pub struct Id(pub usize);
+
+        #[derive(Debug, PartialEq)]
+        pub enum Shape {
+            Point,
+            Circle(f32),
+            Rect { width: f32, height: f32 },
+        }
+    }
+
+    #[reflect_remote(external::Vec2)]
+    #[derive(Debug)]
+    struct Vec2Wrapper {
+        x: f32,
+        y: f32,
+    }
+
+    #[reflect_remote(external::Id)]
+    struct IdWrapper(usize);
+
+    #[reflect_remote(external::Shape)]
+    enum ShapeWrapper {
+        Point,
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+    }
+
+    #[derive(Reflect, Debug)]
+    struct Player {
+        name: String,
+        #[reflect(remote = Vec2Wrapper)]
+        position: external::Vec2,
+        #[reflect(remote = IdWrapper)]
+        id: external::Id,
+        #[reflect(remote = ShapeWrapper)]
+        shape: external::Shape,
+    }
+
+    #[test]
+    fn should_reflect_remote_struct() {
+        let mut wrapper = Vec2Wrapper(external::Vec2 { x: 1.0, y: 2.0 });
+
+        assert_eq!(Some(&1.0), wrapper.get_field::<f32>("x"));
+        *wrapper.get_field_mut::<f32>("y").unwrap() = 5.0;
+        assert_eq!(external::Vec2 { x: 1.0, y: 5.0 }, wrapper.into_remote());
+
+        let mut id = IdWrapper(external::Id(1));
+        *id.get_field_mut::<usize>(0).unwrap() = 2;
+        assert_eq!(&external::Id(2), id.as_remote());
+
+        let TypeInfo::Struct(info) = Vec2Wrapper::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("x").unwrap().is::<f32>());
+    }
+
+    #[test]
+    fn should_reflect_remote_enum() {
+        let mut shape = ShapeWrapper(external::Shape::Rect {
+            width: 1.0,
+            height: 2.0,
+        });
+
+        assert_eq!("Rect", shape.variant_name());
+        *shape
+            .field_mut("height")
+            .unwrap()
+            .downcast_mut::<f32>()
+            .unwrap() = 3.0;
+        assert_eq!(
+            &external::Shape::Rect {
+                width: 1.0,
+                height: 3.0
+            },
+            shape.as_remote()
+        );
+
+        let mut circle = DynamicTuple::default();
+        circle.insert(4.0f32);
+        shape.apply(&DynamicEnum::new("Circle", circle));
+        assert_eq!(&external::Shape::Circle(4.0), shape.as_remote());
+
+        shape.apply(&DynamicEnum::new("Point", ()));
+        assert_eq!(&external::Shape::Point, shape.as_remote());
+    }
+
+    #[test]
+    fn should_reflect_remote_fields() {
+        let mut player = Player {
+            name: String::from("Bevy"),
+            position: external::Vec2 { x: 1.0, y: 2.0 },
+            id: external::Id(7),
+            shape: external::Shape::Circle(1.0),
+        };
+
+        let position = player.field("position").unwrap();
+        assert!(position.is::<Vec2Wrapper>());
+        assert_eq!(
+            Some(&2.0),
+            position
+                .downcast_ref::<Vec2Wrapper>()
+                .unwrap()
+                .get_field::<f32>("y")
+        );
+
+        let mut patch = DynamicStruct::default();
+        let mut position = DynamicStruct::default();
+        position.insert("x", 10.0f32);
+        patch.insert("position", position);
+        patch.insert(
+            "shape",
+            DynamicEnum::new("Rect", {
+                let mut rect = DynamicStruct::default();
+                rect.insert("width", 2.0f32);
+                rect.insert("height", 3.0f32);
+                rect
+            }),
+        );
+        player.apply(&patch);
+
+        assert_eq!(external::Vec2 { x: 10.0, y: 2.0 }, player.position);
+        assert_eq!(
+            external::Shape::Rect {
+                width: 2.0,
+                height: 3.0
+            },
+            player.shape
+        );
+
+        let TypeInfo::Struct(info) = Player::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("position").unwrap().is::<Vec2Wrapper>());
+        assert!(info.field("id").unwrap().is::<IdWrapper>());
+    }
+
+    #[test]
+    fn should_construct_remote_fields_from_reflect() {
+        let player = Player {
+            name: String::from("Bevy"),
+            position: external::Vec2 { x: 1.0, y: 2.0 },
+            id: external::Id(7),
+            shape: external::Shape::Circle(1.0),
+        };
+
+        let cloned = Player::from_reflect(player.clone_value().as_ref()).unwrap();
+        assert_eq!(player.name, cloned.name);
+        assert_eq!(player.position, cloned.position);
+        assert_eq!(player.id, cloned.id);
+        assert_eq!(player.shape, cloned.shape);
+
+        let id = IdWrapper::from_reflect(&IdWrapper(external::Id(3))).unwrap();
+        assert_eq!(external::Id(3), id.0);
+    }
+}
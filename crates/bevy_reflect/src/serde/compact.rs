@@ -0,0 +1,1241 @@
+use crate::{
+    serde::SerializationData, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct,
+    DynamicTuple, DynamicTupleStruct, DynamicVariant, Enum, EnumInfo, Map, Reflect,
+    ReflectDeserialize, ReflectRef, ReflectSerialize, TypeInfo, TypeRegistration, TypeRegistry,
+    VariantInfo,
+};
+use bevy_utils::{HashMap, HashSet};
+use serde::de::{DeserializeSeed, Error as _, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Error as _, SerializeMap, SerializeSeq, SerializeTuple};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::TypeId;
+use std::fmt::{self, Formatter};
+
+use super::{de::get_registration, ser::get_serializable};
+
+/// Ids and schema hashes for the types serialized in the compact format.
+///
+/// Ids are assigned to types in the order they are [inserted](Self::insert),
+/// and the table is written alongside the data as the [type path] and [schema hash] of each type.
+/// Ids and hashes thus only depend on the types that were actually serialized,
+/// and registries that don't register the same types can still read each other's data.
+///
+/// The [schema hash] of a type covers its path and shape (fields, variants and item types),
+/// and the shape of every registered type it contains.
+/// Data written before one of those types changed is rejected rather than misinterpreted.
+///
+/// [type path]: crate::TypePath::type_path
+/// [schema hash]: Self::schema_hash
+pub struct CompactTypeTable<'a> {
+    registry: &'a TypeRegistry,
+    types: Vec<TypeId>,
+    ids: HashMap<TypeId, u32>,
+}
+
+impl<'a> CompactTypeTable<'a> {
+    /// Creates an empty table for types registered in `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            types: Vec::new(),
+            ids: HashMap::default(),
+        }
+    }
+
+    /// Assigns an id to the type with the given [`TypeId`], if it doesn't have one yet.
+    ///
+    /// Returns `None` if the type isn't registered.
+    pub fn insert(&mut self, type_id: TypeId) -> Option<u32> {
+        if let Some(id) = self.ids.get(&type_id) {
+            return Some(*id);
+        }
+        self.registry.get(type_id)?;
+        let id = self.types.len() as u32;
+        self.types.push(type_id);
+        self.ids.insert(type_id, id);
+        Some(id)
+    }
+
+    /// Returns the registry the types are registered in.
+    pub fn registry(&self) -> &'a TypeRegistry {
+        self.registry
+    }
+
+    /// Returns the id assigned to the type with the given [`TypeId`], if it was inserted.
+    pub fn id(&self, type_id: TypeId) -> Option<u32> {
+        self.ids.get(&type_id).copied()
+    }
+
+    /// Returns the registration of the type assigned the given id.
+    pub fn registration(&self, id: u32) -> Option<&'a TypeRegistration> {
+        let type_id = self.types.get(id as usize)?;
+        self.registry.get(*type_id)
+    }
+
+    /// Returns a hash of the layout of the registered type with the given [`TypeId`],
+    /// including the layout of the registered types it contains.
+    ///
+    /// The hash only depends on these types, and is stable across builds and platforms.
+    pub fn schema_hash(&self, type_id: TypeId) -> Option<u64> {
+        let registration = self.registry.get(type_id)?;
+        let mut hasher = SchemaHasher::default();
+        let mut visited = HashSet::default();
+        hasher.write_type(registration, self.registry, &mut visited);
+        Some(hasher.0)
+    }
+}
+
+/// Serializes the table as a sequence of the type path and schema hash of each type, in id order.
+impl<'a> Serialize for CompactTypeTable<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.types.len()))?;
+        for type_id in &self.types {
+            let registration = self.registry.get(*type_id).unwrap();
+            let schema_hash = self.schema_hash(*type_id).unwrap();
+            state.serialize_element(&(registration.type_info().type_path(), schema_hash))?;
+        }
+        state.end()
+    }
+}
+
+/// A deserializer for a [`CompactTypeTable`],
+/// which looks up the serialized types in a [`TypeRegistry`] by their type path.
+///
+/// Fails if a type isn't registered, or if its schema hash doesn't match the serialized one.
+pub struct CompactTypeTableDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactTypeTableDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactTypeTableDeserializer<'a> {
+    type Value = CompactTypeTable<'a>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactTypeTableDeserializer<'a> {
+    type Value = CompactTypeTable<'a>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of type paths and schema hashes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut types = CompactTypeTable::new(self.registry);
+        while let Some((type_path, schema_hash)) = seq.next_element::<(String, u64)>()? {
+            let registration = self
+                .registry
+                .get_with_type_path(&type_path)
+                .ok_or_else(|| {
+                    A::Error::custom(format_args!("no registration found for type `{type_path}`"))
+                })?;
+            let expected = types.schema_hash(registration.type_id()).unwrap();
+            if schema_hash != expected {
+                return Err(A::Error::custom(format_args!(
+                    "schema hash mismatch for type `{type_path}`: expected {expected:016x} \
+                    but found {schema_hash:016x}, the data was written with a different layout"
+                )));
+            }
+            types.insert(registration.type_id());
+        }
+        Ok(types)
+    }
+}
+
+/// A 64-bit FNV-1a hasher.
+///
+/// The std hashers aren't guaranteed to be stable across releases,
+/// while schema hashes are compared between different builds.
+struct SchemaHasher(u64);
+
+impl Default for SchemaHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl SchemaHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write(value.as_bytes());
+        // Terminate strings so that consecutive strings can't be confused with each other.
+        self.write(&[0xff]);
+    }
+
+    /// Writes `registration` and the registered types it contains,
+    /// skipping types that were already written.
+    fn write_type(
+        &mut self,
+        registration: &TypeRegistration,
+        registry: &TypeRegistry,
+        visited: &mut HashSet<TypeId>,
+    ) {
+        if !visited.insert(registration.type_id()) {
+            return;
+        }
+        self.write_registration(registration);
+        for type_id in contained_types(registration.type_info()) {
+            if let Some(registration) = registry.get(type_id) {
+                self.write_type(registration, registry, visited);
+            }
+        }
+    }
+
+    fn write_registration(&mut self, registration: &TypeRegistration) {
+        let type_info = registration.type_info();
+        let serialization_data = registration.data::<SerializationData>();
+        let is_skipped =
+            |index| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+        self.write_str(type_info.type_path());
+        self.write(&[u8::from(registration.data::<ReflectSerialize>().is_some())]);
+        match type_info {
+            TypeInfo::Struct(info) => {
+                self.write_str("struct");
+                for (index, field) in info.iter().enumerate() {
+                    self.write_str(field.name());
+                    self.write_str(field.type_path());
+                    self.write(&[u8::from(is_skipped(index))]);
+                }
+            }
+            TypeInfo::TupleStruct(info) => {
+                self.write_str("tuple struct");
+                for (index, field) in info.iter().enumerate() {
+                    self.write_str(field.type_path());
+                    self.write(&[u8::from(is_skipped(index))]);
+                }
+            }
+            TypeInfo::Tuple(info) => {
+                self.write_str("tuple");
+                for field in info.iter() {
+                    self.write_str(field.type_path());
+                }
+            }
+            TypeInfo::List(info) => {
+                self.write_str("list");
+                self.write_str(info.item_type_path_table().path());
+            }
+            TypeInfo::Array(info) => {
+                self.write_str("array");
+                self.write_str(info.item_type_path_table().path());
+                self.write(&(info.capacity() as u64).to_le_bytes());
+            }
+            TypeInfo::Map(info) => {
+                self.write_str("map");
+                self.write_str(info.key_type_path_table().path());
+                self.write_str(info.value_type_path_table().path());
+            }
+            TypeInfo::Enum(info) => {
+                self.write_str("enum");
+                for variant in info.iter() {
+                    self.write_str(variant.name());
+                    match variant {
+                        VariantInfo::Struct(variant) => {
+                            self.write_str("struct");
+                            for field in variant.iter() {
+                                self.write_str(field.name());
+                                self.write_str(field.type_path());
+                            }
+                        }
+                        VariantInfo::Tuple(variant) => {
+                            self.write_str("tuple");
+                            for field in variant.iter() {
+                                self.write_str(field.type_path());
+                            }
+                        }
+                        VariantInfo::Unit(_) => self.write_str("unit"),
+                    }
+                }
+            }
+            TypeInfo::Value(_) => self.write_str("value"),
+        }
+    }
+}
+
+/// Returns the types of the fields, variant fields or items of a type.
+fn contained_types(type_info: &TypeInfo) -> Vec<TypeId> {
+    match type_info {
+        TypeInfo::Struct(info) => info.iter().map(|field| field.type_id()).collect(),
+        TypeInfo::TupleStruct(info) => info.iter().map(|field| field.type_id()).collect(),
+        TypeInfo::Tuple(info) => info.iter().map(|field| field.type_id()).collect(),
+        TypeInfo::List(info) => vec![info.item_type_id()],
+        TypeInfo::Array(info) => vec![info.item_type_id()],
+        TypeInfo::Map(info) => vec![info.key_type_id(), info.value_type_id()],
+        TypeInfo::Enum(info) => info
+            .iter()
+            .flat_map(|variant| match variant {
+                VariantInfo::Struct(variant) => {
+                    variant.iter().map(|field| field.type_id()).collect()
+                }
+                VariantInfo::Tuple(variant) => {
+                    variant.iter().map(|field| field.type_id()).collect()
+                }
+                VariantInfo::Unit(_) => Vec::new(),
+            })
+            .collect(),
+        TypeInfo::Value(_) => Vec::new(),
+    }
+}
+
+/// A serializer for reflected types in the compact format.
+///
+/// Unlike [`ReflectSerializer`], which uses type paths and field names,
+/// fields are identified by their index and the type path is only written once in a [`CompactTypeTable`].
+/// This is meant for binary formats such as `bincode` or `postcard`.
+///
+/// The serialized data will take the form of a tuple containing:
+/// 1. The [`CompactTypeTable`], containing only the value's type
+/// 2. The value, serialized with a [`TypedCompactReflectSerializer`]
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+pub struct CompactReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        CompactReflectSerializer { value, registry }
+    }
+}
+
+impl<'a> Serialize for CompactReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let type_info = get_type_info::<S::Error>(self.value)?;
+        let mut types = CompactTypeTable::new(self.registry);
+        types.insert(type_info.type_id()).ok_or_else(|| {
+            S::Error::custom(format_args!(
+                "no registration found for type `{}`",
+                type_info.type_path()
+            ))
+        })?;
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&types)?;
+        state.serialize_element(&TypedCompactReflectSerializer::new(self.value, &types))?;
+        state.end()
+    }
+}
+
+/// A serializer for reflected types in the compact format,
+/// whose type is known and does not need to be included.
+///
+/// Types registering [`ReflectSerialize`] use their own [`Serialize`] implementation.
+/// Otherwise:
+/// - Structs, tuple structs and tuples are tuples of their serialized fields, in order
+/// - Lists are sequences, arrays are tuples and maps are maps
+/// - Options are serialized like with `serde`
+/// - Other enums are a tuple of the variant index and a tuple of the variant's fields
+pub struct TypedCompactReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a> TypedCompactReflectSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect, types: &'a CompactTypeTable<'a>) -> Self {
+        TypedCompactReflectSerializer { value, types }
+    }
+}
+
+impl<'a> Serialize for TypedCompactReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.types.registry();
+
+        // Handle both Value case and types that have a custom `Serialize`
+        let serializable = get_serializable::<S::Error>(self.value, registry);
+        if let Ok(serializable) = serializable {
+            return serializable.borrow().serialize(serializer);
+        }
+
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                let type_info = get_type_info::<S::Error>(self.value)?;
+                serialize_fields(
+                    serializer,
+                    value.iter_fields(),
+                    value.field_len(),
+                    registry.get_type_data::<SerializationData>(type_info.type_id()),
+                    self.types,
+                )
+            }
+            ReflectRef::TupleStruct(value) => {
+                let type_info = get_type_info::<S::Error>(self.value)?;
+                serialize_fields(
+                    serializer,
+                    value.iter_fields(),
+                    value.field_len(),
+                    registry.get_type_data::<SerializationData>(type_info.type_id()),
+                    self.types,
+                )
+            }
+            ReflectRef::Tuple(value) => serialize_fields(
+                serializer,
+                value.iter_fields(),
+                value.field_len(),
+                None,
+                self.types,
+            ),
+            ReflectRef::List(value) => {
+                let mut state = serializer.serialize_seq(Some(value.len()))?;
+                for item in value.iter() {
+                    state
+                        .serialize_element(&TypedCompactReflectSerializer::new(item, self.types))?;
+                }
+                state.end()
+            }
+            ReflectRef::Array(value) => {
+                let mut state = serializer.serialize_tuple(value.len())?;
+                for item in value.iter() {
+                    state
+                        .serialize_element(&TypedCompactReflectSerializer::new(item, self.types))?;
+                }
+                state.end()
+            }
+            ReflectRef::Map(value) => {
+                let mut state = serializer.serialize_map(Some(value.len()))?;
+                for (key, value) in value.iter() {
+                    state.serialize_entry(
+                        &TypedCompactReflectSerializer::new(key, self.types),
+                        &TypedCompactReflectSerializer::new(value, self.types),
+                    )?;
+                }
+                state.end()
+            }
+            ReflectRef::Enum(value) => {
+                if is_option(get_type_info::<S::Error>(self.value)?) {
+                    return match value.field_at(0) {
+                        Some(inner) => serializer
+                            .serialize_some(&TypedCompactReflectSerializer::new(inner, self.types)),
+                        None => serializer.serialize_none(),
+                    };
+                }
+
+                let mut state = serializer.serialize_tuple(2)?;
+                state.serialize_element(&(value.variant_index() as u32))?;
+                state.serialize_element(&VariantFieldsSerializer {
+                    enum_value: value,
+                    types: self.types,
+                })?;
+                state.end()
+            }
+            ReflectRef::Value(_) => Err(serializable.err().unwrap()),
+        }
+    }
+}
+
+/// Serializes the fields of an enum's current variant as a tuple.
+struct VariantFieldsSerializer<'a> {
+    enum_value: &'a dyn Enum,
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a> Serialize for VariantFieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_fields(
+            serializer,
+            self.enum_value.iter_fields().map(|field| field.value()),
+            self.enum_value.field_len(),
+            None,
+            self.types,
+        )
+    }
+}
+
+fn serialize_fields<'r, S: Serializer>(
+    serializer: S,
+    fields: impl Iterator<Item = &'r dyn Reflect>,
+    field_len: usize,
+    serialization_data: Option<&SerializationData>,
+    types: &CompactTypeTable,
+) -> Result<S::Ok, S::Error> {
+    let skipped_len = serialization_data.map_or(0, SerializationData::len);
+    let mut state = serializer.serialize_tuple(field_len - skipped_len)?;
+    for (index, field) in fields.enumerate() {
+        if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+            continue;
+        }
+        state.serialize_element(&TypedCompactReflectSerializer::new(field, types))?;
+    }
+    state.end()
+}
+
+fn is_option(type_info: &TypeInfo) -> bool {
+    type_info.type_path_table().module_path() == Some("core::option")
+        && type_info.type_path_table().ident() == Some("Option")
+}
+
+fn get_type_info<E: serde::ser::Error>(value: &dyn Reflect) -> Result<&'static TypeInfo, E> {
+    value.get_represented_type_info().ok_or_else(|| {
+        E::custom(format_args!(
+            "cannot get type info for {}",
+            value.reflect_type_path()
+        ))
+    })
+}
+
+/// A deserializer for reflected types serialized with a [`CompactReflectSerializer`].
+///
+/// Fails if the value's type isn't registered, or if its schema hash doesn't match.
+/// Like the [`UntypedReflectDeserializer`], this generally returns dynamic types.
+///
+/// [`UntypedReflectDeserializer`]: crate::serde::UntypedReflectDeserializer
+pub struct CompactReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a type table and a compact reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let types = seq
+            .next_element_seed(CompactTypeTableDeserializer::new(self.registry))?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let registration = types
+            .registration(0)
+            .ok_or_else(|| A::Error::custom("missing the type of the value"))?;
+        seq.next_element_seed(TypedCompactReflectDeserializer::new(registration, &types))?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))
+    }
+}
+
+/// A deserializer for a type id of a [`CompactTypeTable`],
+/// returning the registration of the type.
+pub struct CompactTypeRegistrationDeserializer<'a> {
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a> CompactTypeRegistrationDeserializer<'a> {
+    pub fn new(types: &'a CompactTypeTable<'a>) -> Self {
+        Self { types }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactTypeRegistrationDeserializer<'a> {
+    type Value = &'a TypeRegistration;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = u32::deserialize(deserializer)?;
+        self.types.registration(id).ok_or_else(|| {
+            D::Error::custom(format_args!("no registration found for type id `{id}`"))
+        })
+    }
+}
+
+/// A deserializer for reflected types serialized with a [`TypedCompactReflectSerializer`].
+///
+/// Like the [`TypedReflectDeserializer`], this generally returns dynamic types.
+///
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+pub struct TypedCompactReflectDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a> TypedCompactReflectDeserializer<'a> {
+    pub fn new(registration: &'a TypeRegistration, types: &'a CompactTypeTable<'a>) -> Self {
+        Self {
+            registration,
+            types,
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for TypedCompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Handle both Value case and types that have a custom `ReflectDeserialize`
+        if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
+            return deserialize_reflect.deserialize(deserializer);
+        }
+
+        let registry = self.types.registry();
+        let type_info = self.registration.type_info();
+        match type_info {
+            TypeInfo::Struct(info) => {
+                let fields = FieldsDeserializer {
+                    fields: info
+                        .iter()
+                        .map(|field| (field.type_id(), field.type_path()))
+                        .collect(),
+                    serialization_data: self.registration.data::<SerializationData>(),
+                    types: self.types,
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_struct = DynamicStruct::default();
+                for (index, value) in fields {
+                    dynamic_struct.insert_boxed(info.field_at(index).unwrap().name(), value);
+                }
+                dynamic_struct.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_struct))
+            }
+            TypeInfo::TupleStruct(info) => {
+                let fields = FieldsDeserializer {
+                    fields: info
+                        .iter()
+                        .map(|field| (field.type_id(), field.type_path()))
+                        .collect(),
+                    serialization_data: self.registration.data::<SerializationData>(),
+                    types: self.types,
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_tuple_struct = DynamicTupleStruct::default();
+                for (_, value) in fields {
+                    dynamic_tuple_struct.insert_boxed(value);
+                }
+                dynamic_tuple_struct.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_tuple_struct))
+            }
+            TypeInfo::Tuple(info) => {
+                let fields = FieldsDeserializer {
+                    fields: info
+                        .iter()
+                        .map(|field| (field.type_id(), field.type_path()))
+                        .collect(),
+                    serialization_data: None,
+                    types: self.types,
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_tuple = DynamicTuple::default();
+                for (_, value) in fields {
+                    dynamic_tuple.insert_boxed(value);
+                }
+                dynamic_tuple.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_tuple))
+            }
+            TypeInfo::List(info) => {
+                let items = deserializer.deserialize_seq(ItemsVisitor {
+                    registration: get_registration(
+                        info.item_type_id(),
+                        info.item_type_path_table().path(),
+                        registry,
+                    )?,
+                    types: self.types,
+                })?;
+                let mut dynamic_list = DynamicList::default();
+                for item in items {
+                    dynamic_list.push_box(item);
+                }
+                dynamic_list.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_list))
+            }
+            TypeInfo::Array(info) => {
+                let items = deserializer.deserialize_tuple(
+                    info.capacity(),
+                    ItemsVisitor {
+                        registration: get_registration(
+                            info.item_type_id(),
+                            info.item_type_path_table().path(),
+                            registry,
+                        )?,
+                        types: self.types,
+                    },
+                )?;
+                if items.len() != info.capacity() {
+                    return Err(D::Error::invalid_length(
+                        items.len(),
+                        &info.capacity().to_string().as_str(),
+                    ));
+                }
+                let mut dynamic_array = DynamicArray::new(items.into_boxed_slice());
+                dynamic_array.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_array))
+            }
+            TypeInfo::Map(info) => {
+                let entries = deserializer.deserialize_map(EntriesVisitor {
+                    key_registration: get_registration(
+                        info.key_type_id(),
+                        info.key_type_path_table().path(),
+                        registry,
+                    )?,
+                    value_registration: get_registration(
+                        info.value_type_id(),
+                        info.value_type_path_table().path(),
+                        registry,
+                    )?,
+                    types: self.types,
+                })?;
+                let mut dynamic_map = DynamicMap::default();
+                for (key, value) in entries {
+                    dynamic_map.insert_boxed(key, value);
+                }
+                dynamic_map.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_map))
+            }
+            TypeInfo::Enum(info) => {
+                let visitor = EnumVisitor {
+                    enum_info: info,
+                    types: self.types,
+                };
+                let mut dynamic_enum = if is_option(type_info) {
+                    deserializer.deserialize_option(visitor)?
+                } else {
+                    deserializer.deserialize_tuple(2, visitor)?
+                };
+                dynamic_enum.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_enum))
+            }
+            TypeInfo::Value(_) => {
+                // This case should already be handled
+                Err(D::Error::custom(format_args!(
+                    "the TypeRegistration for {} doesn't have ReflectDeserialize",
+                    type_info.type_path()
+                )))
+            }
+        }
+    }
+}
+
+/// Deserializes a tuple of fields, returning the value of each field along with its index.
+///
+/// Skipped fields are filled in with their default value, if they have one.
+struct FieldsDeserializer<'a> {
+    fields: Vec<(TypeId, &'static str)>,
+    serialization_data: Option<&'a SerializationData>,
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for FieldsDeserializer<'a> {
+    type Value = Vec<(usize, Box<dyn Reflect>)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let skipped_len = self.serialization_data.map_or(0, SerializationData::len);
+        deserializer.deserialize_tuple(self.fields.len() - skipped_len, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for FieldsDeserializer<'a> {
+    type Value = Vec<(usize, Box<dyn Reflect>)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("compact reflected fields")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(self.fields.len());
+        for (index, (type_id, type_path)) in self.fields.iter().enumerate() {
+            if let Some(data) = self
+                .serialization_data
+                .filter(|data| data.is_field_skipped(index))
+            {
+                if let Some(value) = data.generate_default(index) {
+                    values.push((index, value));
+                }
+                continue;
+            }
+
+            let registration = get_registration(*type_id, type_path, self.types.registry())?;
+            let value = seq
+                .next_element_seed(TypedCompactReflectDeserializer::new(
+                    registration,
+                    self.types,
+                ))?
+                .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+            values.push((index, value));
+        }
+        Ok(values)
+    }
+}
+
+struct ItemsVisitor<'a> {
+    registration: &'a TypeRegistration,
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a, 'de> Visitor<'de> for ItemsVisitor<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("compact reflected list or array value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(item) = seq.next_element_seed(TypedCompactReflectDeserializer::new(
+            self.registration,
+            self.types,
+        ))? {
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
+
+struct EntriesVisitor<'a> {
+    key_registration: &'a TypeRegistration,
+    value_registration: &'a TypeRegistration,
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a, 'de> Visitor<'de> for EntriesVisitor<'a> {
+    type Value = Vec<(Box<dyn Reflect>, Box<dyn Reflect>)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("compact reflected map value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(key) = map.next_key_seed(TypedCompactReflectDeserializer::new(
+            self.key_registration,
+            self.types,
+        ))? {
+            let value = map.next_value_seed(TypedCompactReflectDeserializer::new(
+                self.value_registration,
+                self.types,
+            ))?;
+            entries.push((key, value));
+        }
+        Ok(entries)
+    }
+}
+
+struct EnumVisitor<'a> {
+    enum_info: &'static EnumInfo,
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("compact reflected enum value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let variant_index: u32 = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let variant_info = self
+            .enum_info
+            .variant_at(variant_index as usize)
+            .ok_or_else(|| {
+                A::Error::custom(format_args!(
+                    "no variant found at index `{}` on enum `{}`",
+                    variant_index,
+                    self.enum_info.type_path()
+                ))
+            })?;
+
+        let field_types: Vec<_> = match variant_info {
+            VariantInfo::Struct(info) => info
+                .iter()
+                .map(|field| (field.type_id(), field.type_path()))
+                .collect(),
+            VariantInfo::Tuple(info) => info
+                .iter()
+                .map(|field| (field.type_id(), field.type_path()))
+                .collect(),
+            VariantInfo::Unit(_) => Vec::new(),
+        };
+        let fields = seq
+            .next_element_seed(FieldsDeserializer {
+                fields: field_types,
+                serialization_data: None,
+                types: self.types,
+            })?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+        let variant: DynamicVariant = match variant_info {
+            VariantInfo::Struct(info) => {
+                let mut dynamic_struct = DynamicStruct::default();
+                for (index, value) in fields {
+                    dynamic_struct.insert_boxed(info.field_at(index).unwrap().name(), value);
+                }
+                dynamic_struct.into()
+            }
+            VariantInfo::Tuple(_) => {
+                let mut dynamic_tuple = DynamicTuple::default();
+                for (_, value) in fields {
+                    dynamic_tuple.insert_boxed(value);
+                }
+                dynamic_tuple.into()
+            }
+            VariantInfo::Unit(_) => ().into(),
+        };
+        Ok(DynamicEnum::new_with_index(
+            variant_index as usize,
+            variant_info.name(),
+            variant,
+        ))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let index = self.enum_info.index_of("None").unwrap_or_default();
+        Ok(DynamicEnum::new_with_index(index, "None", ()))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Some(VariantInfo::Tuple(info)) = self.enum_info.variant("Some") else {
+            return Err(D::Error::custom(format_args!(
+                "expected `Some` tuple variant on `{}`",
+                self.enum_info.type_path()
+            )));
+        };
+        let field = info.field_at(0).unwrap();
+        let registration =
+            get_registration(field.type_id(), field.type_path(), self.types.registry())?;
+        let mut value = DynamicTuple::default();
+        value.insert_boxed(
+            TypedCompactReflectDeserializer::new(registration, self.types)
+                .deserialize(deserializer)?,
+        );
+        let index = self.enum_info.index_of("Some").unwrap_or_default();
+        Ok(DynamicEnum::new_with_index(index, "Some", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_reflect,
+        serde::{ReflectSerializer, UntypedReflectDeserializer},
+        FromReflect,
+    };
+    use bincode::Options;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: Option<u32>,
+        nickname: Option<String>,
+        position: (f32, f32),
+        inventory: Vec<Item>,
+        hotbar: [u8; 3],
+        stats: HashMap<String, i32>,
+        tag: Tag,
+        marker: Marker,
+        #[reflect(skip_serializing)]
+        #[reflect(default = "default_cached")]
+        cached: u64,
+    }
+
+    fn default_cached() -> u64 {
+        7
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Item {
+        id: u32,
+        kind: ItemKind,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum ItemKind {
+        Empty,
+        Stack(u8),
+        Weapon {
+            damage: f32,
+            durability: Option<u32>,
+        },
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Tag(String);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Marker;
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Item>();
+        registry.register::<ItemKind>();
+        registry.register::<Tag>();
+        registry.register::<Marker>();
+        registry.register::<Option<u32>>();
+        registry.register::<Option<String>>();
+        registry.register_type_data::<Option<String>, ReflectDeserialize>();
+        registry.register::<(f32, f32)>();
+        registry.register::<Vec<Item>>();
+        registry.register::<[u8; 3]>();
+        registry.register::<HashMap<String, i32>>();
+        registry
+    }
+
+    fn player() -> Player {
+        let mut stats = HashMap::default();
+        stats.insert("strength".to_string(), 12);
+        stats.insert("speed".to_string(), -3);
+        Player {
+            name: "Ferris".to_string(),
+            health: Some(100),
+            nickname: None,
+            position: (1.5, -2.0),
+            inventory: vec![
+                Item {
+                    id: 1,
+                    kind: ItemKind::Empty,
+                },
+                Item {
+                    id: 2,
+                    kind: ItemKind::Stack(64),
+                },
+                Item {
+                    id: 3,
+                    kind: ItemKind::Weapon {
+                        damage: 4.5,
+                        durability: None,
+                    },
+                },
+            ],
+            hotbar: [3, 1, 2],
+            stats,
+            tag: Tag("hero".to_string()),
+            marker: Marker,
+            cached: 123,
+        }
+    }
+
+    fn bincode_options() -> impl Options {
+        bincode::DefaultOptions::new().with_fixint_encoding()
+    }
+
+    #[test]
+    fn should_roundtrip_bincode() {
+        let registry = get_registry();
+        let player = player();
+
+        let bytes = bincode_options()
+            .serialize(&CompactReflectSerializer::new(&player, &registry))
+            .unwrap();
+        let output = bincode_options()
+            .deserialize_seed(CompactReflectDeserializer::new(&registry), &bytes)
+            .unwrap();
+
+        // Skipped fields are replaced by their default value.
+        let expected = Player {
+            cached: default_cached(),
+            ..player
+        };
+        assert_eq!(Player::from_reflect(output.as_ref()).unwrap(), expected);
+    }
+
+    #[test]
+    fn should_roundtrip_self_describing_formats() {
+        let registry = get_registry();
+        let player = Player {
+            cached: default_cached(),
+            ..player()
+        };
+
+        let json =
+            serde_json::to_string(&CompactReflectSerializer::new(&player, &registry)).unwrap();
+        let output = CompactReflectDeserializer::new(&registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert!(output.reflect_partial_eq(&player).unwrap());
+
+        let bytes = rmp_serde::to_vec(&CompactReflectSerializer::new(&player, &registry)).unwrap();
+        let output = CompactReflectDeserializer::new(&registry)
+            .deserialize(&mut rmp_serde::Deserializer::new(bytes.as_slice()))
+            .unwrap();
+        assert!(output.reflect_partial_eq(&player).unwrap());
+    }
+
+    #[test]
+    fn should_match_existing_serializers() {
+        let registry = get_registry();
+        let player = player();
+
+        let ron = ron::to_string(&ReflectSerializer::new(&player, &registry)).unwrap();
+        let from_ron = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut ron::Deserializer::from_str(&ron).unwrap())
+            .unwrap();
+
+        let compact_bytes = bincode_options()
+            .serialize(&CompactReflectSerializer::new(&player, &registry))
+            .unwrap();
+        let from_compact = bincode_options()
+            .deserialize_seed(CompactReflectDeserializer::new(&registry), &compact_bytes)
+            .unwrap();
+
+        assert!(from_compact.reflect_partial_eq(from_ron.as_ref()).unwrap());
+        assert_eq!(
+            Player::from_reflect(from_compact.as_ref()),
+            Player::from_reflect(from_ron.as_ref())
+        );
+
+        // Field and variant names aren't written.
+        let json = serde_json::to_string(&ReflectSerializer::new(&player, &registry)).unwrap();
+        let compact_json =
+            serde_json::to_string(&CompactReflectSerializer::new(&player, &registry)).unwrap();
+        assert!(compact_json.len() < json.len());
+    }
+
+    #[test]
+    fn should_roundtrip_dynamic_values() {
+        let registry = get_registry();
+        let dynamic = Player {
+            cached: default_cached(),
+            ..player()
+        }
+        .clone_value();
+
+        let bytes = bincode_options()
+            .serialize(&CompactReflectSerializer::new(dynamic.as_ref(), &registry))
+            .unwrap();
+        let output = bincode_options()
+            .deserialize_seed(CompactReflectDeserializer::new(&registry), &bytes)
+            .unwrap();
+        assert!(output.reflect_partial_eq(dynamic.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn should_only_depend_on_serialized_types() {
+        let registry = get_registry();
+        let bytes = bincode_options()
+            .serialize(&CompactReflectSerializer::new(&player(), &registry))
+            .unwrap();
+
+        // Types that weren't serialized don't affect the ids or schema hashes.
+        let mut reversed = TypeRegistry::empty();
+        let mut registrations: Vec<_> = registry.iter().cloned().collect();
+        registrations.reverse();
+        for registration in registrations {
+            reversed.add_registration(registration);
+        }
+        reversed.register::<Option<Item>>();
+        reversed.register::<(u8, u8)>();
+
+        let types = CompactTypeTable::new(&registry);
+        let reversed_types = CompactTypeTable::new(&reversed);
+        let player_id = TypeId::of::<Player>();
+        assert_eq!(
+            types.schema_hash(player_id),
+            reversed_types.schema_hash(player_id)
+        );
+        let output = bincode_options()
+            .deserialize_seed(CompactReflectDeserializer::new(&reversed), &bytes)
+            .unwrap();
+        assert!(output.represents::<Player>());
+    }
+
+    #[test]
+    fn should_reject_mismatched_schema() {
+        #[derive(Reflect)]
+        #[type_path = "compact_tests"]
+        #[type_name = "Armor"]
+        struct Armor {
+            defense: u32,
+        }
+
+        mod changed {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "compact_tests"]
+            #[type_name = "Armor"]
+            pub struct Armor {
+                pub defense: u32,
+                pub weight: f32,
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Armor>();
+        let bytes = bincode_options()
+            .serialize(&CompactReflectSerializer::new(
+                &Armor { defense: 3 },
+                &registry,
+            ))
+            .unwrap();
+
+        let mut other_registry = TypeRegistry::default();
+        other_registry.register::<changed::Armor>();
+        let types = CompactTypeTable::new(&registry);
+        let other_types = CompactTypeTable::new(&other_registry);
+        assert_ne!(
+            types.schema_hash(TypeId::of::<Armor>()),
+            other_types.schema_hash(TypeId::of::<changed::Armor>())
+        );
+
+        let error = bincode_options()
+            .deserialize_seed(CompactReflectDeserializer::new(&other_registry), &bytes)
+            .unwrap_err();
+        assert!(error.to_string().contains("schema hash mismatch"));
+    }
+}
@@ -1,9 +1,13 @@
-use crate::serde::SerializationData;
+use crate::serde::{
+    SerializationData, EDIT_VARIANTS, LIST_DIFF_NAME, MAP_DIFF_NAME, REFLECT_DIFF_NAME,
+    REFLECT_DIFF_VARIANTS,
+};
 use crate::{
     ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, TupleInfo, TupleStructInfo,
-    TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, VariantInfo,
+    DynamicTupleStruct, DynamicVariant, EnumInfo, ListDiff, ListInfo, Map, MapDiff, MapInfo,
+    NamedField, Reflect, ReflectDeserialize, ReflectDiff, ReflectFromReflect, StructInfo,
+    StructVariantInfo, TupleInfo, TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration,
+    TypeRegistry, UnnamedField, VariantInfo,
 };
 use erased_serde::Deserializer;
 use serde::de::{
@@ -13,6 +17,7 @@ use serde::Deserialize;
 use std::any::TypeId;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
 use std::slice::Iter;
 
 pub trait DeserializeValue {
@@ -1087,7 +1092,352 @@ where
     Ok(dynamic_struct)
 }
 
-fn get_registration<'a, E: Error>(
+/// A deserializer for [`ReflectDiff`] values serialized with a [`ReflectDiffSerializer`].
+///
+/// Replaced and inserted values are deserialized like with an [`UntypedReflectDeserializer`],
+/// so they will generally be dynamic types.
+/// Map keys are converted back into their concrete type if it registers [`ReflectFromReflect`],
+/// so that they can be hashed when the diff is applied.
+///
+/// [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+#[derive(Clone, Copy)]
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'a> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(REFLECT_DIFF_NAME, REFLECT_DIFF_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ReflectDiffDeserializer<'a> {
+    type Value = ReflectDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflect diff")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.registry;
+        let (variant, access) =
+            data.variant_seed(VariantIndexDeserializer(REFLECT_DIFF_VARIANTS))?;
+        match variant {
+            0 => access.unit_variant().map(|_| ReflectDiff::Unchanged),
+            1 => access
+                .newtype_variant_seed(UntypedReflectDeserializer::new(registry))
+                .map(ReflectDiff::Replaced),
+            2 => access
+                .newtype_variant_seed(DiffFieldsDeserializer { registry })
+                .map(ReflectDiff::Fields),
+            3 => access
+                .newtype_variant_seed(DiffSeqDeserializer(DiffPairDeserializer(
+                    PhantomData::<usize>,
+                    self,
+                )))
+                .map(ReflectDiff::Elements),
+            4 => access
+                .newtype_variant_seed(DiffSeqDeserializer(ListDiffDeserializer { registry }))
+                .map(ReflectDiff::List),
+            _ => access
+                .newtype_variant_seed(DiffSeqDeserializer(MapDiffDeserializer { registry }))
+                .map(ReflectDiff::Map),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ListDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ListDiffDeserializer<'a> {
+    type Value = ListDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(LIST_DIFF_NAME, EDIT_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListDiffDeserializer<'a> {
+    type Value = ListDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("list diff")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.registry;
+        let (variant, access) = data.variant_seed(VariantIndexDeserializer(EDIT_VARIANTS))?;
+        match variant {
+            0 => access
+                .tuple_variant(
+                    2,
+                    DiffPairDeserializer(PhantomData, ReflectDiffDeserializer::new(registry)),
+                )
+                .map(|(index, diff)| ListDiff::Modified(index, diff)),
+            1 => access
+                .tuple_variant(
+                    2,
+                    DiffPairDeserializer(PhantomData, UntypedReflectDeserializer::new(registry)),
+                )
+                .map(|(index, value)| ListDiff::Inserted(index, value)),
+            _ => access.newtype_variant().map(ListDiff::Removed),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct MapDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MapDiffDeserializer<'a> {
+    type Value = MapDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(MAP_DIFF_NAME, EDIT_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapDiffDeserializer<'a> {
+    type Value = MapDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map diff")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.registry;
+        let key = MapKeyDeserializer { registry };
+        let (variant, access) = data.variant_seed(VariantIndexDeserializer(EDIT_VARIANTS))?;
+        match variant {
+            0 => access
+                .tuple_variant(
+                    2,
+                    DiffPairDeserializer(key, ReflectDiffDeserializer::new(registry)),
+                )
+                .map(|(key, diff)| MapDiff::Modified(key, diff)),
+            1 => access
+                .tuple_variant(
+                    2,
+                    DiffPairDeserializer(key, UntypedReflectDeserializer::new(registry)),
+                )
+                .map(|(key, value)| MapDiff::Inserted(key, value)),
+            _ => access.newtype_variant_seed(key).map(MapDiff::Removed),
+        }
+    }
+}
+
+/// Deserializes the names and diffs of changed struct fields.
+struct DiffFieldsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DiffFieldsDeserializer<'a> {
+    type Value = Vec<(String, ReflectDiff)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for DiffFieldsDeserializer<'a> {
+    type Value = Vec<(String, ReflectDiff)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map of field diffs")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut fields = Vec::new();
+        while let Some(name) = map.next_key::<String>()? {
+            let diff = map.next_value_seed(ReflectDiffDeserializer::new(self.registry))?;
+            fields.push((name, diff));
+        }
+        Ok(fields)
+    }
+}
+
+/// Deserializes a map key, converting it to its concrete type if possible.
+#[derive(Clone, Copy)]
+struct MapKeyDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MapKeyDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let key = UntypedReflectDeserializer::new(self.registry).deserialize(deserializer)?;
+        let concrete = key
+            .get_represented_type_info()
+            .filter(|_| key.is_dynamic())
+            .and_then(|info| {
+                self.registry
+                    .get_type_data::<ReflectFromReflect>(info.type_id())
+            })
+            .and_then(|from_reflect| from_reflect.from_reflect(key.as_ref()));
+        Ok(concrete.unwrap_or(key))
+    }
+}
+
+/// Deserializes a sequence of values using the wrapped seed.
+#[derive(Clone, Copy)]
+struct DiffSeqDeserializer<S>(S);
+
+impl<'de, S: DeserializeSeed<'de> + Clone> DeserializeSeed<'de> for DiffSeqDeserializer<S> {
+    type Value = Vec<S::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de> + Clone> Visitor<'de> for DiffSeqDeserializer<S> {
+    type Value = Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of diffs")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(self.0.clone())? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a two-element tuple using the wrapped seeds.
+#[derive(Clone, Copy)]
+struct DiffPairDeserializer<A, B>(A, B);
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> DeserializeSeed<'de>
+    for DiffPairDeserializer<A, B>
+{
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> Visitor<'de>
+    for DiffPairDeserializer<A, B>
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("tuple of two values")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| Error::invalid_length(0, &"tuple of two values"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| Error::invalid_length(1, &"tuple of two values"))?;
+        Ok((first, second))
+    }
+}
+
+/// Deserializes the index of an enum variant from either its index or its name.
+struct VariantIndexDeserializer(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantIndexDeserializer {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIndexDeserializer {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("expected either a variant index or variant name")
+    }
+
+    fn visit_u64<E>(self, variant_index: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let index = variant_index as usize;
+        if index < self.0.len() {
+            Ok(index)
+        } else {
+            Err(Error::custom(format_args!(
+                "no variant found at index `{variant_index}`"
+            )))
+        }
+    }
+
+    fn visit_str<E>(self, variant_name: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.0
+            .iter()
+            .position(|name| *name == variant_name)
+            .ok_or_else(|| Error::unknown_variant(variant_name, self.0))
+    }
+}
+
+pub(super) fn get_registration<'a, E: Error>(
     type_id: TypeId,
     type_path: &str,
     registry: &'a TypeRegistry,
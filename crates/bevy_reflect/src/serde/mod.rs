@@ -1,11 +1,23 @@
+mod compact;
 mod de;
+mod schema;
 mod ser;
 mod type_data;
 
+pub use compact::*;
 pub use de::*;
+pub use schema::*;
 pub use ser::*;
 pub use type_data::*;
 
+// The names and variants used when (de)serializing a `ReflectDiff`
+const REFLECT_DIFF_NAME: &str = "ReflectDiff";
+const REFLECT_DIFF_VARIANTS: &[&str] =
+    &["Unchanged", "Replaced", "Fields", "Elements", "List", "Map"];
+const LIST_DIFF_NAME: &str = "ListDiff";
+const MAP_DIFF_NAME: &str = "MapDiff";
+const EDIT_VARIANTS: &[&str] = &["Modified", "Inserted", "Removed"];
+
 #[cfg(test)]
 mod tests {
     use crate::{self as bevy_reflect, DynamicTupleStruct, Struct};
@@ -181,4 +193,65 @@ mod tests {
 
         assert!(expected.reflect_partial_eq(&result).unwrap());
     }
+
+    #[test]
+    fn test_diff_serialization() {
+        use crate::serde::{ReflectDiffDeserializer, ReflectDiffSerializer};
+        use crate::{apply_diff, diff};
+        use bevy_utils::HashMap;
+        use bincode::Options;
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        enum Shape {
+            Circle { radius: f32 },
+            Point,
+        }
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        struct TestStruct {
+            a: i32,
+            list: Vec<i32>,
+            map: HashMap<String, i32>,
+            shape: Shape,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestStruct>();
+        registry.register::<Shape>();
+        registry.register::<Vec<i32>>();
+        registry.register::<HashMap<String, i32>>();
+
+        let old = TestStruct {
+            a: 1,
+            list: vec![1, 2, 3],
+            map: HashMap::from([(String::from("x"), 1), (String::from("y"), 2)]),
+            shape: Shape::Point,
+        };
+        let new = TestStruct {
+            a: 2,
+            list: vec![1, 4],
+            map: HashMap::from([(String::from("y"), 3), (String::from("z"), 4)]),
+            shape: Shape::Circle { radius: 2.0 },
+        };
+        let diff = diff(&old, &new).unwrap();
+
+        let serializer = ReflectDiffSerializer::new(&diff, &registry);
+        let ron = ron::ser::to_string(&serializer).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let from_ron = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let from_bincode = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(ReflectDiffDeserializer::new(&registry), &bytes)
+            .unwrap();
+
+        for diff in [from_ron, from_bincode] {
+            let mut value = old.clone();
+            apply_diff(&mut value, &diff).unwrap();
+            assert_eq!(new, value);
+        }
+    }
 }
@@ -0,0 +1,673 @@
+use crate::{NamedField, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, VariantInfo};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use std::any::TypeId;
+use std::path::PathBuf;
+
+use super::SerializationData;
+
+/// The JSON Schema dialect used by [`JsonSchemaExporter`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A JSON value that is part of a [`JsonSchema`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonSchemaValue {
+    Bool(bool),
+    Number(i64),
+    String(String),
+    Array(Vec<JsonSchemaValue>),
+    Object(JsonSchema),
+}
+
+impl From<bool> for JsonSchemaValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for JsonSchemaValue {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for JsonSchemaValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for JsonSchemaValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Vec<JsonSchemaValue>> for JsonSchemaValue {
+    fn from(value: Vec<JsonSchemaValue>) -> Self {
+        Self::Array(value)
+    }
+}
+
+impl From<JsonSchema> for JsonSchemaValue {
+    fn from(value: JsonSchema) -> Self {
+        Self::Object(value)
+    }
+}
+
+impl Serialize for JsonSchemaValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Number(value) => serializer.serialize_i64(*value),
+            Self::String(value) => serializer.serialize_str(value),
+            Self::Array(values) => {
+                let mut state = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    state.serialize_element(value)?;
+                }
+                state.end()
+            }
+            Self::Object(schema) => schema.serialize(serializer),
+        }
+    }
+}
+
+/// A JSON object describing a [JSON Schema], with its keys kept in insertion order.
+///
+/// This implements [`Serialize`], so it can be written out with any `serde` format (typically `serde_json`).
+///
+/// [JSON Schema]: https://json-schema.org
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonSchema {
+    entries: Vec<(String, JsonSchemaValue)>,
+}
+
+impl JsonSchema {
+    /// Creates an empty schema, which accepts any value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `key` to `value`, replacing any previous value.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<JsonSchemaValue>) {
+        let key = key.into();
+        let value = value.into();
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, current)) => *current = value,
+            None => self.entries.push((key, value)),
+        }
+    }
+
+    /// Sets `key` to `value`, returning the schema.
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<JsonSchemaValue>) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Returns the value of `key`, if it is set.
+    pub fn get(&self, key: &str) -> Option<&JsonSchemaValue> {
+        self.entries
+            .iter()
+            .find_map(|(k, value)| (k == key).then_some(value))
+    }
+
+    /// Iterates over the keys and values of the schema, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &JsonSchemaValue)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Returns the number of keys in the schema.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the schema has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Serialize for JsonSchema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
+        for (key, value) in &self.entries {
+            state.serialize_entry(key, value)?;
+        }
+        state.end()
+    }
+}
+
+/// Exports the types registered in a [`TypeRegistry`] as a [JSON Schema] document.
+///
+/// The schemas describe the JSON produced by [`ReflectSerializer`] and [`TypedReflectSerializer`]:
+/// * Structs are objects. Fields marked with `#[reflect(skip_serializing)]` are left out, all other fields are required.
+/// * Tuple structs, tuples and arrays are arrays with a fixed number of items, and lists are arrays.
+/// * Maps are objects.
+/// * Enums are tagged unions: unit variants are strings, and other variants are objects with the variant name as their only key.
+/// * `Option` is either `null` or its inner value.
+///
+/// Every registered type is added to `$defs`, keyed by its [full type path](crate::TypePath::type_path),
+/// or by its [short type path](crate::TypePath::short_type_path) when [using short type paths](Self::with_short_type_paths)
+/// (falling back to the full path when the short path is ambiguous). Field types refer to these definitions with `$ref`.
+/// Primitive types are described inline, and unregistered types accept any value.
+///
+/// The root of the document validates the output of [`ReflectSerializer`]: an object with a single key,
+/// the full type path, whose value is described by the type's schema.
+///
+/// With the `documentation` feature enabled, doc comments are exported as `description`s.
+///
+/// Types with [`ReflectSerialize`] type data are serialized by their own [`Serialize`] impl, which may not match their
+/// reflected structure. Their schemas describe the reflected structure.
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, serde::JsonSchemaExporter};
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Player>();
+///
+/// let exporter = JsonSchemaExporter::new(&registry).with_short_type_paths(true);
+/// let schema = exporter.export_type(std::any::TypeId::of::<Player>()).unwrap();
+/// assert!(schema.get("properties").is_some());
+///
+/// // The whole document can be written with any `serde` format, such as JSON
+/// let document = exporter.export();
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+pub struct JsonSchemaExporter<'a> {
+    registry: &'a TypeRegistry,
+    short_type_paths: bool,
+}
+
+impl<'a> JsonSchemaExporter<'a> {
+    /// Creates an exporter for the types in `registry`, which uses full type paths.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            short_type_paths: false,
+        }
+    }
+
+    /// Sets whether types are named by their short type paths instead of their full type paths.
+    pub fn with_short_type_paths(mut self, short_type_paths: bool) -> Self {
+        self.short_type_paths = short_type_paths;
+        self
+    }
+
+    /// Exports a schema document containing every registered type.
+    pub fn export(&self) -> JsonSchema {
+        let mut registrations = self.registry.iter().collect::<Vec<_>>();
+        registrations.sort_by_key(|registration| registration.type_info().type_path());
+
+        let mut defs = JsonSchema::new();
+        let mut properties = JsonSchema::new();
+        for registration in registrations {
+            let info = registration.type_info();
+            defs.insert(self.type_name(info), self.registration_schema(registration));
+            properties.insert(info.type_path(), self.reference(info));
+        }
+
+        JsonSchema::new()
+            .with("$schema", JSON_SCHEMA_DIALECT)
+            .with("type", "object")
+            .with("properties", properties)
+            .with("minProperties", 1)
+            .with("maxProperties", 1)
+            .with("additionalProperties", false)
+            .with("$defs", defs)
+    }
+
+    /// Exports the schema of the registered type with the given [`TypeId`], if it exists.
+    ///
+    /// References to other types point to the `$defs` of the document created by [`JsonSchemaExporter::export`].
+    pub fn export_type(&self, type_id: TypeId) -> Option<JsonSchema> {
+        self.registry
+            .get(type_id)
+            .map(|registration| self.registration_schema(registration))
+    }
+
+    /// The name of the type in `$defs`.
+    fn type_name(&self, info: &TypeInfo) -> &'static str {
+        let table = info.type_path_table();
+        if self.short_type_paths
+            && self
+                .registry
+                .get_with_short_type_path(table.short_path())
+                .is_some()
+        {
+            table.short_path()
+        } else {
+            table.path()
+        }
+    }
+
+    fn reference(&self, info: &TypeInfo) -> JsonSchema {
+        JsonSchema::new().with(
+            "$ref",
+            format!("#/$defs/{}", encode_pointer(self.type_name(info))),
+        )
+    }
+
+    /// The schema of a field (or item) of the given type.
+    fn field_schema(&self, type_id: TypeId) -> JsonSchema {
+        if let Some(schema) = primitive_schema(type_id) {
+            return schema;
+        }
+        match self.registry.get_type_info(type_id) {
+            Some(info) => self.reference(info),
+            None => JsonSchema::new(),
+        }
+    }
+
+    fn registration_schema(&self, registration: &TypeRegistration) -> JsonSchema {
+        let info = registration.type_info();
+        let serialization_data = registration.data::<SerializationData>();
+        let is_skipped =
+            |index: usize| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+        let schema = match info {
+            TypeInfo::Struct(info) => self.struct_schema(
+                info.iter()
+                    .enumerate()
+                    .filter(|(index, _)| !is_skipped(*index))
+                    .map(|(_, field)| field),
+            ),
+            TypeInfo::TupleStruct(info) => self.tuple_schema(
+                info.iter()
+                    .enumerate()
+                    .filter(|(index, _)| !is_skipped(*index))
+                    .map(|(_, field)| field),
+            ),
+            TypeInfo::Tuple(info) => self.tuple_schema(info.iter()),
+            TypeInfo::List(info) => JsonSchema::new()
+                .with("type", "array")
+                .with("items", self.field_schema(info.item_type_id())),
+            TypeInfo::Array(info) => JsonSchema::new()
+                .with("type", "array")
+                .with("items", self.field_schema(info.item_type_id()))
+                .with("minItems", info.capacity() as i64)
+                .with("maxItems", info.capacity() as i64),
+            TypeInfo::Map(info) => JsonSchema::new().with("type", "object").with(
+                "additionalProperties",
+                self.field_schema(info.value_type_id()),
+            ),
+            TypeInfo::Enum(enum_info) => {
+                let table = enum_info.type_path_table();
+                if table.module_path() == Some("core::option") && table.ident() == Some("Option") {
+                    let some = enum_info
+                        .variant("Some")
+                        .and_then(|variant| match variant {
+                            VariantInfo::Tuple(variant) => variant.field_at(0),
+                            _ => None,
+                        })
+                        .map(|field| self.field_schema(field.type_id()))
+                        .unwrap_or_default();
+                    JsonSchema::new().with(
+                        "oneOf",
+                        vec![JsonSchema::new().with("type", "null").into(), some.into()],
+                    )
+                } else {
+                    let variants = enum_info
+                        .iter()
+                        .map(|variant| self.variant_schema(variant).into())
+                        .collect::<Vec<_>>();
+                    JsonSchema::new().with("oneOf", variants)
+                }
+            }
+            TypeInfo::Value(info) => primitive_schema(info.type_id()).unwrap_or_default(),
+        };
+
+        let schema = schema.with("title", info.type_path_table().short_path());
+        #[cfg(feature = "documentation")]
+        let schema = with_description(schema, info.docs());
+        schema
+    }
+
+    fn struct_schema<'f>(&self, fields: impl Iterator<Item = &'f NamedField>) -> JsonSchema {
+        let mut properties = JsonSchema::new();
+        let mut required = Vec::new();
+        for field in fields {
+            let schema = self.field_schema(field.type_id());
+            #[cfg(feature = "documentation")]
+            let schema = with_description(schema, field.docs());
+            properties.insert(field.name(), schema);
+            required.push(field.name().into());
+        }
+        JsonSchema::new()
+            .with("type", "object")
+            .with("properties", properties)
+            .with("required", required)
+            .with("additionalProperties", false)
+    }
+
+    fn tuple_schema<'f>(&self, fields: impl Iterator<Item = &'f UnnamedField>) -> JsonSchema {
+        let items = fields
+            .map(|field| self.field_schema(field.type_id()).into())
+            .collect::<Vec<JsonSchemaValue>>();
+        let len = items.len() as i64;
+        JsonSchema::new()
+            .with("type", "array")
+            .with("prefixItems", items)
+            .with("minItems", len)
+            .with("maxItems", len)
+    }
+
+    fn variant_schema(&self, variant: &VariantInfo) -> JsonSchema {
+        let content = match variant {
+            VariantInfo::Unit(_) => None,
+            VariantInfo::Tuple(info) if info.field_len() == 1 => {
+                Some(self.field_schema(info.field_at(0).unwrap().type_id()))
+            }
+            VariantInfo::Tuple(info) => Some(self.tuple_schema(info.iter())),
+            VariantInfo::Struct(info) => Some(self.struct_schema(info.iter())),
+        };
+
+        let schema = match content {
+            None => JsonSchema::new().with("const", variant.name()),
+            Some(content) => JsonSchema::new()
+                .with("type", "object")
+                .with(
+                    "properties",
+                    JsonSchema::new().with(variant.name(), content),
+                )
+                .with("required", vec![variant.name().into()])
+                .with("additionalProperties", false),
+        };
+        #[cfg(feature = "documentation")]
+        let schema = with_description(schema, variant.docs());
+        schema
+    }
+}
+
+/// Adds the given doc comment to `schema` as its `description`.
+#[cfg(feature = "documentation")]
+fn with_description(mut schema: JsonSchema, docs: Option<&str>) -> JsonSchema {
+    if let Some(docs) = docs {
+        schema.insert("description", docs.trim());
+    }
+    schema
+}
+
+/// Returns the inline schema of primitive types.
+fn primitive_schema(type_id: TypeId) -> Option<JsonSchema> {
+    fn integer(minimum: Option<i64>, maximum: Option<i64>) -> JsonSchema {
+        let mut schema = JsonSchema::new().with("type", "integer");
+        if let Some(minimum) = minimum {
+            schema.insert("minimum", minimum);
+        }
+        if let Some(maximum) = maximum {
+            schema.insert("maximum", maximum);
+        }
+        schema
+    }
+
+    macro_rules! match_primitive {
+        ($($ty:ty => $schema:expr,)*) => {
+            $(if type_id == TypeId::of::<$ty>() {
+                return Some($schema);
+            })*
+        };
+    }
+
+    match_primitive! {
+        bool => JsonSchema::new().with("type", "boolean"),
+        char => JsonSchema::new().with("type", "string").with("minLength", 1).with("maxLength", 1),
+        String => JsonSchema::new().with("type", "string"),
+        &'static str => JsonSchema::new().with("type", "string"),
+        PathBuf => JsonSchema::new().with("type", "string"),
+        f32 => JsonSchema::new().with("type", "number"),
+        f64 => JsonSchema::new().with("type", "number"),
+        u8 => integer(Some(0), Some(u8::MAX.into())),
+        u16 => integer(Some(0), Some(u16::MAX.into())),
+        u32 => integer(Some(0), Some(u32::MAX.into())),
+        u64 => integer(Some(0), None),
+        u128 => integer(Some(0), None),
+        usize => integer(Some(0), None),
+        i8 => integer(Some(i8::MIN.into()), Some(i8::MAX.into())),
+        i16 => integer(Some(i16::MIN.into()), Some(i16::MAX.into())),
+        i32 => integer(Some(i32::MIN.into()), Some(i32::MAX.into())),
+        i64 => integer(None, None),
+        i128 => integer(None, None),
+        isize => integer(None, None),
+    }
+    None
+}
+
+/// Encodes `name` as a JSON Pointer token inside a URI fragment.
+fn encode_pointer(name: &str) -> String {
+    let mut encoded = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '~' => encoded.push_str("~0"),
+            '/' => encoded.push_str("~1"),
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' | '_' | ':' => encoded.push(c),
+            c => {
+                let mut bytes = [0; 4];
+                for byte in c.encode_utf8(&mut bytes).bytes() {
+                    encoded.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::Reflect;
+    use bevy_utils::HashMap;
+    use serde_json::json;
+
+    #[derive(Reflect)]
+    struct Player {
+        name: String,
+        health: u8,
+        position: Position,
+        #[reflect(skip_serializing)]
+        cached: f32,
+        inventory: Vec<Item>,
+        stats: HashMap<String, i32>,
+        pet: Option<Pet>,
+    }
+
+    #[derive(Reflect)]
+    struct Position(f32, f32);
+
+    #[derive(Reflect)]
+    enum Item {
+        Empty,
+        Coins(u32),
+        Pair(u8, u8),
+        Weapon { damage: f32 },
+    }
+
+    #[derive(Reflect)]
+    struct Pet;
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        registry.register::<Position>();
+        registry.register::<Item>();
+        registry.register::<Pet>();
+        registry.register::<Vec<Item>>();
+        registry.register::<HashMap<String, i32>>();
+        registry.register::<Option<Pet>>();
+        registry
+    }
+
+    fn export(registry: &TypeRegistry, short: bool, type_id: TypeId) -> serde_json::Value {
+        let exporter = JsonSchemaExporter::new(registry).with_short_type_paths(short);
+        serde_json::to_value(exporter.export_type(type_id).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn should_export_struct_schema() {
+        let registry = registry();
+        let schema = export(&registry, true, TypeId::of::<Player>());
+
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["title"], "Player");
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(
+            schema["required"],
+            json!(["name", "health", "position", "inventory", "stats", "pet"])
+        );
+        let properties = &schema["properties"];
+        assert!(properties.get("cached").is_none());
+        assert_eq!(properties["name"], json!({ "type": "string" }));
+        assert_eq!(
+            properties["health"],
+            json!({ "type": "integer", "minimum": 0, "maximum": 255 })
+        );
+        assert_eq!(
+            properties["position"],
+            json!({ "$ref": "#/$defs/Position" })
+        );
+        assert_eq!(
+            properties["inventory"],
+            json!({ "$ref": "#/$defs/Vec%3CItem%3E" })
+        );
+    }
+
+    #[test]
+    fn should_export_full_type_paths() {
+        let registry = registry();
+        let schema = export(&registry, false, TypeId::of::<Player>());
+        assert_eq!(
+            schema["properties"]["position"]["$ref"],
+            "#/$defs/bevy_reflect::serde::schema::tests::Position"
+        );
+
+        let document = serde_json::to_value(JsonSchemaExporter::new(&registry).export()).unwrap();
+        assert_eq!(document["$schema"], JSON_SCHEMA_DIALECT);
+        assert!(document["$defs"]
+            .get("bevy_reflect::serde::schema::tests::Player")
+            .is_some());
+        assert_eq!(
+            document["properties"]["bevy_reflect::serde::schema::tests::Player"]["$ref"],
+            "#/$defs/bevy_reflect::serde::schema::tests::Player"
+        );
+        assert_eq!(document["maxProperties"], 1);
+    }
+
+    #[test]
+    fn should_export_containers() {
+        let registry = registry();
+
+        let position = export(&registry, true, TypeId::of::<Position>());
+        assert_eq!(position["type"], "array");
+        assert_eq!(
+            position["prefixItems"],
+            json!([{ "type": "number" }, { "type": "number" }])
+        );
+        assert_eq!(position["minItems"], 2);
+        assert_eq!(position["maxItems"], 2);
+
+        let list = export(&registry, true, TypeId::of::<Vec<Item>>());
+        assert_eq!(list["type"], "array");
+        assert_eq!(list["items"], json!({ "$ref": "#/$defs/Item" }));
+
+        let map = export(&registry, true, TypeId::of::<HashMap<String, i32>>());
+        assert_eq!(map["type"], "object");
+        assert_eq!(map["additionalProperties"]["type"], "integer");
+
+        let option = export(&registry, true, TypeId::of::<Option<Pet>>());
+        assert_eq!(
+            option["oneOf"],
+            json!([{ "type": "null" }, { "$ref": "#/$defs/Pet" }])
+        );
+    }
+
+    #[test]
+    fn should_export_enum_as_tagged_union() {
+        let registry = registry();
+        let schema = export(&registry, true, TypeId::of::<Item>());
+        let variants = schema["oneOf"].as_array().unwrap();
+        assert_eq!(variants.len(), 4);
+        assert_eq!(variants[0], json!({ "const": "Empty" }));
+        assert_eq!(
+            variants[1]["properties"]["Coins"],
+            json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX })
+        );
+        assert_eq!(variants[1]["required"], json!(["Coins"]));
+        assert_eq!(variants[2]["properties"]["Pair"]["type"], "array");
+        assert_eq!(variants[2]["properties"]["Pair"]["maxItems"], 2);
+        assert_eq!(
+            variants[3]["properties"]["Weapon"]["properties"]["damage"],
+            json!({ "type": "number" })
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_full_path_for_ambiguous_short_paths() {
+        mod a {
+            use crate::{self as bevy_reflect, Reflect};
+            #[derive(Reflect)]
+            pub struct Same;
+        }
+        mod b {
+            use crate::{self as bevy_reflect, Reflect};
+            #[derive(Reflect)]
+            pub struct Same;
+        }
+
+        #[derive(Reflect)]
+        struct Both {
+            a: a::Same,
+            b: b::Same,
+            unregistered: Pet,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Both>();
+        registry.register::<a::Same>();
+        registry.register::<b::Same>();
+
+        let schema = export(&registry, true, TypeId::of::<Both>());
+        assert_eq!(
+            schema["properties"]["a"]["$ref"],
+            "#/$defs/bevy_reflect::serde::schema::tests::a::Same"
+        );
+        assert_eq!(schema["properties"]["unregistered"], json!({}));
+    }
+
+    #[cfg(feature = "documentation")]
+    #[test]
+    fn should_export_docs() {
+        /// A documented type.
+        #[derive(Reflect)]
+        struct Documented {
+            /// A documented field.
+            field: u32,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Documented>();
+        let schema = export(&registry, true, TypeId::of::<Documented>());
+        assert_eq!(schema["description"], "A documented type.");
+        assert_eq!(
+            schema["properties"]["field"]["description"],
+            "A documented field."
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
-    TypeInfo, TypeRegistry, VariantInfo, VariantType,
+    Array, Enum, List, ListDiff, Map, MapDiff, Reflect, ReflectDiff, ReflectRef, ReflectSerialize,
+    Struct, Tuple, TupleStruct, TypeInfo, TypeRegistry, VariantInfo, VariantType,
 };
 use serde::ser::{
     Error, SerializeStruct, SerializeStructVariant, SerializeTuple, SerializeTupleStruct,
@@ -11,7 +11,7 @@ use serde::{
     Serialize,
 };
 
-use super::SerializationData;
+use super::{SerializationData, LIST_DIFF_NAME, MAP_DIFF_NAME, REFLECT_DIFF_NAME};
 
 pub enum Serializable<'a> {
     Owned(Box<dyn erased_serde::Serialize + 'a>),
@@ -28,7 +28,7 @@ impl<'a> Serializable<'a> {
     }
 }
 
-fn get_serializable<'a, E: Error>(
+pub(super) fn get_serializable<'a, E: Error>(
     reflect_value: &'a dyn Reflect,
     type_registry: &TypeRegistry,
 ) -> Result<Serializable<'a>, E> {
@@ -461,6 +461,201 @@ impl<'a> Serialize for ArraySerializer<'a> {
     }
 }
 
+/// A serializer for [`ReflectDiff`] values.
+///
+/// Replaced values, inserted values, and map keys are serialized like a [`ReflectSerializer`],
+/// so their types must be registered in the given [`TypeRegistry`].
+pub struct ReflectDiffSerializer<'a> {
+    pub diff: &'a ReflectDiff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        ReflectDiffSerializer { diff, registry }
+    }
+}
+
+impl<'a> Serialize for ReflectDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        const NAME: &str = REFLECT_DIFF_NAME;
+        let registry = self.registry;
+        match self.diff {
+            ReflectDiff::Unchanged => serializer.serialize_unit_variant(NAME, 0, "Unchanged"),
+            ReflectDiff::Replaced(value) => serializer.serialize_newtype_variant(
+                NAME,
+                1,
+                "Replaced",
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            ReflectDiff::Fields(fields) => serializer.serialize_newtype_variant(
+                NAME,
+                2,
+                "Fields",
+                &DiffFieldsSerializer { fields, registry },
+            ),
+            ReflectDiff::Elements(elements) => serializer.serialize_newtype_variant(
+                NAME,
+                3,
+                "Elements",
+                &DiffElementsSerializer { elements, registry },
+            ),
+            ReflectDiff::List(edits) => serializer.serialize_newtype_variant(
+                NAME,
+                4,
+                "List",
+                &ListDiffSerializer { edits, registry },
+            ),
+            ReflectDiff::Map(edits) => serializer.serialize_newtype_variant(
+                NAME,
+                5,
+                "Map",
+                &MapDiffSerializer { edits, registry },
+            ),
+        }
+    }
+}
+
+struct DiffFieldsSerializer<'a> {
+    fields: &'a [(String, ReflectDiff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for DiffFieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.fields.len()))?;
+        for (name, diff) in self.fields {
+            state.serialize_entry(name, &ReflectDiffSerializer::new(diff, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+struct DiffElementsSerializer<'a> {
+    elements: &'a [(usize, ReflectDiff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for DiffElementsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.elements.len()))?;
+        for (index, diff) in self.elements {
+            state.serialize_element(&(index, ReflectDiffSerializer::new(diff, self.registry)))?;
+        }
+        state.end()
+    }
+}
+
+struct ListDiffSerializer<'a> {
+    edits: &'a [ListDiff],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        struct Edit<'a>(&'a ListDiff, &'a TypeRegistry);
+
+        impl<'a> Serialize for Edit<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                const NAME: &str = LIST_DIFF_NAME;
+                let Edit(edit, registry) = *self;
+                match edit {
+                    ListDiff::Modified(index, diff) => {
+                        let mut state =
+                            serializer.serialize_tuple_variant(NAME, 0, "Modified", 2)?;
+                        state.serialize_field(index)?;
+                        state.serialize_field(&ReflectDiffSerializer::new(diff, registry))?;
+                        state.end()
+                    }
+                    ListDiff::Inserted(index, value) => {
+                        let mut state =
+                            serializer.serialize_tuple_variant(NAME, 1, "Inserted", 2)?;
+                        state.serialize_field(index)?;
+                        state.serialize_field(&ReflectSerializer::new(value.as_ref(), registry))?;
+                        state.end()
+                    }
+                    ListDiff::Removed(index) => {
+                        serializer.serialize_newtype_variant(NAME, 2, "Removed", index)
+                    }
+                }
+            }
+        }
+
+        let mut state = serializer.serialize_seq(Some(self.edits.len()))?;
+        for edit in self.edits {
+            state.serialize_element(&Edit(edit, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+struct MapDiffSerializer<'a> {
+    edits: &'a [MapDiff],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        struct Edit<'a>(&'a MapDiff, &'a TypeRegistry);
+
+        impl<'a> Serialize for Edit<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                const NAME: &str = MAP_DIFF_NAME;
+                let Edit(edit, registry) = *self;
+                match edit {
+                    MapDiff::Modified(key, diff) => {
+                        let mut state =
+                            serializer.serialize_tuple_variant(NAME, 0, "Modified", 2)?;
+                        state.serialize_field(&ReflectSerializer::new(key.as_ref(), registry))?;
+                        state.serialize_field(&ReflectDiffSerializer::new(diff, registry))?;
+                        state.end()
+                    }
+                    MapDiff::Inserted(key, value) => {
+                        let mut state =
+                            serializer.serialize_tuple_variant(NAME, 1, "Inserted", 2)?;
+                        state.serialize_field(&ReflectSerializer::new(key.as_ref(), registry))?;
+                        state.serialize_field(&ReflectSerializer::new(value.as_ref(), registry))?;
+                        state.end()
+                    }
+                    MapDiff::Removed(key) => serializer.serialize_newtype_variant(
+                        NAME,
+                        2,
+                        "Removed",
+                        &ReflectSerializer::new(key.as_ref(), registry),
+                    ),
+                }
+            }
+        }
+
+        let mut state = serializer.serialize_seq(Some(self.edits.len()))?;
+        for edit in self.edits {
+            state.serialize_element(&Edit(edit, self.registry))?;
+        }
+        state.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::serde::ReflectSerializer;
@@ -1,3 +1,4 @@
+use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
 use bevy_reflect_derive::impl_type_path;
 
 use crate::{
@@ -7,6 +8,7 @@ use crate::{
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Formatter};
 use std::slice::Iter;
+use std::sync::Arc;
 
 /// A trait used to power [tuple struct-like] operations via [reflection].
 ///
@@ -59,6 +61,7 @@ pub struct TupleStructInfo {
     type_path: TypePathTable,
     type_id: TypeId,
     fields: Box<[UnnamedField]>,
+    custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -75,6 +78,7 @@ impl TupleStructInfo {
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
+            custom_attributes: Arc::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -86,6 +90,8 @@ impl TupleStructInfo {
         Self { docs, ..self }
     }
 
+    impl_custom_attribute_methods!(custom_attributes, "tuple struct");
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
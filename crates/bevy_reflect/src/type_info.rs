@@ -1,9 +1,11 @@
+use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
 use crate::{
     ArrayInfo, EnumInfo, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo, TupleStructInfo,
     TypePath, TypePathTable,
 };
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
+use std::sync::Arc;
 
 /// A static accessor to compile-time type information.
 ///
@@ -170,6 +172,19 @@ impl TypeInfo {
             Self::Value(info) => info.docs(),
         }
     }
+
+    /// The custom attributes of the underlying type, if it supports them.
+    ///
+    /// Only structs, tuple structs, enums, and values can have custom attributes.
+    pub fn custom_attributes(&self) -> Option<&CustomAttributes> {
+        match self {
+            Self::Struct(info) => Some(info.custom_attributes()),
+            Self::TupleStruct(info) => Some(info.custom_attributes()),
+            Self::Enum(info) => Some(info.custom_attributes()),
+            Self::Value(info) => Some(info.custom_attributes()),
+            _ => None,
+        }
+    }
 }
 
 /// A container for compile-time info related to general value types, including primitives.
@@ -184,6 +199,7 @@ impl TypeInfo {
 pub struct ValueInfo {
     type_path: TypePathTable,
     type_id: TypeId,
+    custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -193,6 +209,7 @@ impl ValueInfo {
         Self {
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
+            custom_attributes: Arc::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -204,6 +221,8 @@ impl ValueInfo {
         Self { docs: doc, ..self }
     }
 
+    impl_custom_attribute_methods!(custom_attributes, "value");
+
     /// A representation of the type path of the value.
     ///
     /// Provides dynamic access to all methods on [`TypePath`].
@@ -1,10 +1,16 @@
-use crate::{serde::Serializable, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    func::{IntoFunction, ReflectMethods},
+    serde::Serializable,
+    Reflect, TypeInfo, TypePath, Typed,
+};
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use serde::Deserialize;
 use std::{
+    alloc::Layout,
     any::TypeId,
+    borrow::Cow,
     fmt::Debug,
     sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
@@ -135,6 +141,22 @@ impl TypeRegistry {
             .insert(registration.type_id(), registration);
     }
 
+    /// Removes the registration of the type with the given [`TypeId`], returning it.
+    ///
+    /// This is used to replace a type's registration, for example after reloading the code that
+    /// defines it. Short type paths that were made ambiguous by this type stay ambiguous.
+    pub fn remove(&mut self, type_id: TypeId) -> Option<TypeRegistration> {
+        let registration = self.registrations.remove(&type_id)?;
+        let type_path_table = registration.type_info().type_path_table();
+        if self.short_path_to_id.get(type_path_table.short_path()) == Some(&type_id) {
+            self.short_path_to_id.remove(type_path_table.short_path());
+        }
+        if self.type_path_to_id.get(type_path_table.path()) == Some(&type_id) {
+            self.type_path_to_id.remove(type_path_table.path());
+        }
+        Some(registration)
+    }
+
     /// Registers the type data `D` for type `T`.
     ///
     /// Most of the time [`TypeRegistry::register`] can be used instead to register a type you derived [`Reflect`] for.
@@ -162,6 +184,29 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers `method` as a method of the type `T`, adding it to the type's [`ReflectMethods`] type data.
+    ///
+    /// See [`TypeRegistration::register_method`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered, or if the receiver of `method` is not of type `T`.
+    ///
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    pub fn register_method<T: Reflect + TypePath, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: impl IntoFunction<Marker>,
+    ) {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_method` for type `{T}` without registering `{T}` first",
+                T = T::type_path(),
+            )
+        });
+        registration.register_method(name, method);
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given [`TypeId`].
     ///
@@ -320,6 +365,7 @@ impl TypeRegistryArc {
 pub struct TypeRegistration {
     data: HashMap<TypeId, Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
+    layout: Layout,
 }
 
 impl Debug for TypeRegistration {
@@ -338,6 +384,47 @@ impl TypeRegistration {
         self.type_info.type_id()
     }
 
+    /// Returns the memory [`Layout`] of the type.
+    #[inline]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Registers `method` as a method of this type, adding it to the [`ReflectMethods`] type data.
+    /// The method's [name](crate::func::FunctionInfo::name) is set to `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the first argument of `method` (its receiver) is not of this type.
+    ///
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    pub fn register_method<Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: impl IntoFunction<Marker>,
+    ) {
+        let name = name.into();
+        let method = method.into_function().with_name(name.clone());
+        match method.info().args().first() {
+            Some(receiver) if receiver.type_id() == self.type_id() => {}
+            Some(receiver) => panic!(
+                "attempted to register method `{name}` for type `{T}`, but its receiver is of type `{R}`",
+                T = self.type_info.type_path(),
+                R = receiver.type_path(),
+            ),
+            None => panic!(
+                "attempted to register method `{name}` for type `{T}`, but it does not take a receiver",
+                T = self.type_info.type_path(),
+            ),
+        }
+        if self.data::<ReflectMethods>().is_none() {
+            self.insert(ReflectMethods::default());
+        }
+        self.data_mut::<ReflectMethods>()
+            .unwrap()
+            .insert(name, method);
+    }
+
     /// Returns a reference to the value of type `T` in this registration's type
     /// data.
     ///
@@ -375,6 +462,7 @@ impl TypeRegistration {
         Self {
             data: HashMap::default(),
             type_info: T::type_info(),
+            layout: Layout::new::<T>(),
         }
     }
 }
@@ -389,6 +477,7 @@ impl Clone for TypeRegistration {
         TypeRegistration {
             data,
             type_info: self.type_info,
+            layout: self.layout,
         }
     }
 }
@@ -596,8 +685,9 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
 
 #[cfg(test)]
 mod test {
-    use crate::{GetTypeRegistration, ReflectFromPtr};
+    use crate::{GetTypeRegistration, ReflectFromPtr, TypePath, TypeRegistry};
     use bevy_ptr::{Ptr, PtrMut};
+    use std::any::TypeId;
 
     use crate as bevy_reflect;
     use crate::Reflect;
@@ -642,4 +732,24 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn remove_registration() {
+        #[derive(Reflect)]
+        struct Foo;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        let type_path = Foo::type_path();
+        assert!(registry.get_with_type_path(type_path).is_some());
+
+        let registration = registry.remove(TypeId::of::<Foo>()).unwrap();
+        assert_eq!(type_path, registration.type_info().type_path());
+        assert!(registry.get(TypeId::of::<Foo>()).is_none());
+        assert!(registry.get_with_type_path(type_path).is_none());
+        assert!(registry.get_with_short_type_path("Foo").is_none());
+
+        registry.register::<Foo>();
+        assert!(registry.get_with_short_type_path("Foo").is_some());
+    }
 }
@@ -19,6 +19,7 @@ impl Plugin for MeshPlugin {
         app.init_asset::<Mesh>()
             .init_asset::<skinning::SkinnedMeshInverseBindposes>()
             .register_asset_reflect::<Mesh>()
+            .register_asset_partial_eq::<Mesh>()
             .register_type::<Option<Handle<Image>>>()
             .register_type::<Option<Vec<String>>>()
             .register_type::<Option<Indices>>()
@@ -87,7 +87,8 @@ impl Plugin for ImagePlugin {
         app.add_plugins(RenderAssetPlugin::<Image>::default())
             .register_type::<Image>()
             .init_asset::<Image>()
-            .register_asset_reflect::<Image>();
+            .register_asset_reflect::<Image>()
+            .register_asset_partial_eq::<Image>();
         app.world
             .resource_mut::<Assets<Image>>()
             .insert(Handle::default(), Image::default());
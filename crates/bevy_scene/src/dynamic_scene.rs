@@ -1,16 +1,18 @@
-use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
+use crate::{ron, DynamicSceneBuilder, Scene, SceneEntityChange, SceneEntityId, SceneSpawnError};
 use bevy_ecs::{
     entity::Entity,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
     world::World,
 };
-use bevy_reflect::{Reflect, TypePath, TypeRegistryArc};
-use bevy_utils::{EntityHashMap, HashMap};
+use bevy_reflect::{
+    FromReflect, GetPath, Reflect, TypePath, TypeRegistration, TypeRegistry, TypeRegistryArc,
+};
+use bevy_utils::{EntityHashMap, EntityHashSet, HashMap};
 use std::any::TypeId;
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
-use bevy_asset::Asset;
+use bevy_asset::{Asset, AssetPath};
 use bevy_ecs::reflect::ReflectResource;
 #[cfg(feature = "serialize")]
 use serde::Serialize;
@@ -31,6 +33,8 @@ pub struct DynamicScene {
     pub resources: Vec<Box<dyn Reflect>>,
     /// Entities contained in the dynamic scene.
     pub entities: Vec<DynamicEntity>,
+    /// Instances of other scenes placed in the dynamic scene.
+    pub prefabs: Vec<DynamicPrefab>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -44,6 +48,107 @@ pub struct DynamicEntity {
     pub components: Vec<Box<dyn Reflect>>,
 }
 
+/// An instance of another scene placed in a [`DynamicScene`], with some of its values overridden.
+///
+/// When a scene is spawned by the [`SceneSpawner`](crate::SceneSpawner), the referenced scene is
+/// spawned as a child of [`entity`](Self::entity), then its [`overrides`](Self::overrides) are applied.
+/// Overrides are applied again whenever the referenced scene is reloaded, so that instances keep
+/// them while following the changes made to the referenced scene.
+pub struct DynamicPrefab {
+    /// The identifier of the entity the referenced scene is spawned under, unique within a scene.
+    ///
+    /// This entity doesn't need to be part of the scene's entities,
+    /// in which case an empty entity is spawned for it.
+    pub entity: Entity,
+    /// The asset path of the referenced scene.
+    pub scene: AssetPath<'static>,
+    /// The values of the referenced scene replaced in this instance.
+    pub overrides: Vec<PrefabOverride>,
+}
+
+/// A value replacing part of a component of an entity in a [`DynamicPrefab`].
+pub struct PrefabOverride {
+    /// The identifier of the overridden entity, in the referenced scene.
+    pub entity: Entity,
+    /// The type path of the overridden component.
+    pub component: String,
+    /// The [path](bevy_reflect::GetPath) of the overridden value within the component.
+    ///
+    /// An empty path overrides the whole component.
+    pub path: String,
+    /// The value replacing the overridden one.
+    pub value: Box<dyn Reflect>,
+}
+
+impl DynamicPrefab {
+    /// Apply the overrides of this prefab to an instance of its referenced scene.
+    ///
+    /// `entity_map` maps the entities of the referenced scene to the entities of the instance.
+    ///
+    /// This method will return a [`SceneSpawnError`] if an override targets an entity,
+    /// component or value which doesn't exist in the instance, or if the value has a different type.
+    pub fn apply_overrides(
+        &self,
+        world: &mut World,
+        entity_map: &EntityHashMap<Entity, Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        let type_registry = type_registry.read();
+
+        for item in &self.overrides {
+            let invalid = |reason: String| SceneSpawnError::InvalidPrefabOverride {
+                scene: self.scene.to_string(),
+                component: item.component.clone(),
+                path: item.path.clone(),
+                reason,
+            };
+
+            let entity = entity_map
+                .get(&item.entity)
+                .and_then(|&entity| world.get_entity_mut(entity));
+            let Some(mut entity) = entity else {
+                return Err(invalid(format!("entity {:?} doesn't exist", item.entity)));
+            };
+            let registration = type_registry
+                .get_with_type_path(&item.component)
+                .ok_or_else(|| SceneSpawnError::UnregisteredButReflectedType {
+                    type_path: item.component.clone(),
+                })?;
+            let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredComponent {
+                    type_path: item.component.clone(),
+                }
+            })?;
+            let Some(mut component) = reflect_component.reflect_mut(&mut entity) else {
+                return Err(invalid(
+                    "the entity doesn't have this component".to_string(),
+                ));
+            };
+            let target = component
+                .reflect_path_mut(item.path.as_str())
+                .map_err(|err| invalid(err.to_string()))?;
+
+            let target_type = target
+                .get_represented_type_info()
+                .map(|info| info.type_id());
+            let value_type = item
+                .value
+                .get_represented_type_info()
+                .map(|info| info.type_id());
+            if target_type.is_none() || target_type != value_type {
+                return Err(invalid(format!(
+                    "expected a value of type `{}`, found `{}`",
+                    target.reflect_type_path(),
+                    item.value.reflect_type_path()
+                )));
+            }
+            target.apply(&*item.value);
+        }
+
+        Ok(())
+    }
+}
+
 impl DynamicScene {
     /// Create a new dynamic scene from a given scene.
     pub fn from_scene(scene: &Scene) -> Self {
@@ -60,6 +165,9 @@ impl DynamicScene {
 
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
     ///
+    /// Dynamic entities missing from `entity_map` which have a [`SceneEntityId`] are written to the
+    /// entity of the world with the same id, if there is one.
+    ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
     /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
@@ -99,6 +207,8 @@ impl DynamicScene {
         // of the actual entities in the world.
         let mut scene_mappings: HashMap<TypeId, Vec<Entity>> = HashMap::default();
 
+        self.map_entity_ids(world, entity_map);
+
         for scene_entity in &self.entities {
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
@@ -170,6 +280,185 @@ impl DynamicScene {
         self.write_to_world_with(world, entity_map, &registry)
     }
 
+    /// Update an instance of the `previous` version of this scene to match this one.
+    ///
+    /// Unlike [`write_to_world_with`](Self::write_to_world_with), only the resources, entities and
+    /// components which differ between both versions are written to the world: entities and
+    /// components new to this version are added, the changed ones are updated and the ones
+    /// missing from this version are removed. Everything else is left untouched, including the
+    /// components added at runtime to the entities of the instance.
+    ///
+    /// Values are compared with [`Reflect::reflect_partial_eq`], and considered changed when
+    /// they can't be compared.
+    ///
+    /// Returns the entities of the instance which were added, updated or removed.
+    pub fn update_world_with(
+        &self,
+        previous: &DynamicScene,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity, Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<Vec<(Entity, SceneEntityChange)>, SceneSpawnError> {
+        let type_registry = type_registry.read();
+
+        for resource in &self.resources {
+            if contains_value(&previous.resources, &**resource) {
+                continue;
+            }
+            let registration = get_registration(&type_registry, &**resource)?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_path: registration.type_info().type_path().to_string(),
+                }
+            })?;
+            reflect_resource.apply_or_insert(world, &**resource);
+        }
+
+        let previous_entities: EntityHashMap<Entity, &DynamicEntity> = previous
+            .entities
+            .iter()
+            .map(|scene_entity| (scene_entity.entity, scene_entity))
+            .collect();
+        let mut changes = Vec::new();
+        let mut scene_mappings: HashMap<TypeId, Vec<Entity>> = HashMap::default();
+
+        for scene_entity in &self.entities {
+            let previous_entity = previous_entities.get(&scene_entity.entity);
+            let entity = *entity_map
+                .entry(scene_entity.entity)
+                .or_insert_with(|| world.spawn_empty().id());
+            // Entities of the instance despawned by something else stay despawned.
+            let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                continue;
+            };
+
+            let previous_components = previous_entity
+                .map(|previous_entity| &previous_entity.components[..])
+                .unwrap_or_default();
+            let mut changed = false;
+
+            for component in &scene_entity.components {
+                if contains_value(previous_components, &**component) {
+                    continue;
+                }
+                let registration = get_registration(&type_registry, &**component)?;
+                let reflect_component = get_reflect_component(registration)?;
+                if registration.data::<ReflectMapEntities>().is_some() {
+                    scene_mappings
+                        .entry(registration.type_id())
+                        .or_default()
+                        .push(entity);
+                }
+                reflect_component.apply_or_insert(&mut entity_mut, &**component);
+                changed = true;
+            }
+
+            for previous_component in previous_components {
+                let type_id = represented_type_id(&**previous_component);
+                if scene_entity
+                    .components
+                    .iter()
+                    .any(|component| represented_type_id(&**component) == type_id)
+                {
+                    continue;
+                }
+                let registration = get_registration(&type_registry, &**previous_component)?;
+                get_reflect_component(registration)?.remove(&mut entity_mut);
+                changed = true;
+            }
+
+            if previous_entity.is_none() {
+                changes.push((entity, SceneEntityChange::Added));
+            } else if changed {
+                changes.push((entity, SceneEntityChange::Updated));
+            }
+        }
+
+        let scene_entities: EntityHashSet<Entity> = self
+            .entities
+            .iter()
+            .map(|scene_entity| scene_entity.entity)
+            .collect();
+        for previous_entity in &previous.entities {
+            if scene_entities.contains(&previous_entity.entity) {
+                continue;
+            }
+            if let Some(entity) = entity_map.remove(&previous_entity.entity) {
+                if world.despawn(entity) {
+                    changes.push((entity, SceneEntityChange::Removed));
+                }
+            }
+        }
+
+        // Updates references to entities in the scene to entities in the world,
+        // for the components which were written.
+        for (type_id, entities) in scene_mappings.into_iter() {
+            let registration = type_registry.get(type_id).expect(
+                "we should be getting TypeId from this TypeRegistration in the first place",
+            );
+            if let Some(map_entities_reflect) = registration.data::<ReflectMapEntities>() {
+                map_entities_reflect.map_entities(world, entity_map, &entities);
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Map the dynamic entities with a [`SceneEntityId`] which aren't in `entity_map` yet
+    /// to the entities of the world with the same id.
+    fn map_entity_ids(&self, world: &mut World, entity_map: &mut EntityHashMap<Entity, Entity>) {
+        let mut world_ids: Option<HashMap<SceneEntityId, Entity>> = None;
+
+        for scene_entity in &self.entities {
+            if entity_map.contains_key(&scene_entity.entity) {
+                continue;
+            }
+            let id = scene_entity.components.iter().find_map(|component| {
+                (represented_type_id(&**component) == Some(TypeId::of::<SceneEntityId>()))
+                    .then(|| SceneEntityId::from_reflect(&**component))
+                    .flatten()
+            });
+            let Some(id) = id else {
+                continue;
+            };
+
+            let world_ids = world_ids.get_or_insert_with(|| {
+                world
+                    .query::<(Entity, &SceneEntityId)>()
+                    .iter(world)
+                    .map(|(entity, &id)| (id, entity))
+                    .collect()
+            });
+            if let Some(&entity) = world_ids.get(&id) {
+                entity_map.insert(scene_entity.entity, entity);
+            }
+        }
+    }
+
+    /// Clone the resources and entities of this scene, to compare it with a later version.
+    pub(crate) fn snapshot(&self) -> DynamicScene {
+        DynamicScene {
+            resources: self
+                .resources
+                .iter()
+                .map(|resource| resource.clone_value())
+                .collect(),
+            entities: self
+                .entities
+                .iter()
+                .map(|scene_entity| DynamicEntity {
+                    entity: scene_entity.entity,
+                    components: scene_entity
+                        .components
+                        .iter()
+                        .map(|component| component.clone_value())
+                        .collect(),
+                })
+                .collect(),
+            prefabs: Vec::new(),
+        }
+    }
+
     // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into rust object notation (ron).
     #[cfg(feature = "serialize")]
@@ -178,6 +467,48 @@ impl DynamicScene {
     }
 }
 
+fn represented_type_id(value: &dyn Reflect) -> Option<TypeId> {
+    value
+        .get_represented_type_info()
+        .map(|type_info| type_info.type_id())
+}
+
+/// Returns true if `values` contains a value of the same type which is equal to `value`.
+fn contains_value(values: &[Box<dyn Reflect>], value: &dyn Reflect) -> bool {
+    let type_id = represented_type_id(value);
+    values.iter().any(|other| {
+        represented_type_id(&**other) == type_id
+            && other.reflect_partial_eq(value).unwrap_or_default()
+    })
+}
+
+fn get_registration<'a>(
+    type_registry: &'a TypeRegistry,
+    value: &dyn Reflect,
+) -> Result<&'a TypeRegistration, SceneSpawnError> {
+    let type_info =
+        value
+            .get_represented_type_info()
+            .ok_or_else(|| SceneSpawnError::NoRepresentedType {
+                type_path: value.reflect_type_path().to_string(),
+            })?;
+    type_registry.get(type_info.type_id()).ok_or_else(|| {
+        SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_info.type_path().to_string(),
+        }
+    })
+}
+
+fn get_reflect_component(
+    registration: &TypeRegistration,
+) -> Result<&ReflectComponent, SceneSpawnError> {
+    registration
+        .data::<ReflectComponent>()
+        .ok_or_else(|| SceneSpawnError::UnregisteredComponent {
+            type_path: registration.type_info().type_path().to_string(),
+        })
+}
+
 /// Serialize a given Rust data structure into rust object notation (ron).
 #[cfg(feature = "serialize")]
 pub fn serialize_ron<S>(serialize: S) -> Result<String, ron::Error>
@@ -197,6 +528,7 @@ mod tests {
     use bevy_utils::EntityHashMap;
 
     use crate::dynamic_scene_builder::DynamicSceneBuilder;
+    use crate::SceneEntityId;
 
     #[test]
     fn components_not_defined_in_scene_should_not_be_affected_by_scene_entity_map() {
@@ -274,4 +606,60 @@ mod tests {
             "something is wrong with the this test or the code reloading scenes since the relationship between scene entities is broken"
         );
     }
+
+    #[test]
+    fn entities_with_ids_should_be_written_to_existing_entities() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let registry = world.resource::<AppTypeRegistry>();
+            let mut registry = registry.write();
+            registry.register::<Parent>();
+            registry.register::<SceneEntityId>();
+        }
+
+        // A first scene is spawned, with ids.
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .with_entity_ids()
+            .extract_entities([first, second].into_iter())
+            .build();
+        world.clear_entities();
+        let mut entity_map = EntityHashMap::default();
+        scene.write_to_world(&mut world, &mut entity_map).unwrap();
+        assert_eq!(2, world.entities().len());
+        let first = entity_map[&first];
+
+        // Writing it again with a new entity map reuses the entities with the same ids.
+        let mut new_entity_map = EntityHashMap::default();
+        scene
+            .write_to_world(&mut world, &mut new_entity_map)
+            .unwrap();
+        assert_eq!(2, world.entities().len());
+        assert_eq!(entity_map, new_entity_map);
+
+        // Another scene references the first entity by its id.
+        let mut other_world = World::new();
+        other_world.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        let reference = other_world.spawn(SceneEntityId(0)).id();
+        let child = other_world.spawn_empty().id();
+        PushChild {
+            parent: reference,
+            child,
+        }
+        .apply(&mut other_world);
+        let other_scene = DynamicSceneBuilder::from_world(&other_world)
+            .extract_entities([reference, child].into_iter())
+            .build();
+
+        let mut other_entity_map = EntityHashMap::default();
+        other_scene
+            .write_to_world(&mut world, &mut other_entity_map)
+            .unwrap();
+        assert_eq!(first, other_entity_map[&reference]);
+        let child = other_entity_map[&child];
+        assert_eq!(first, world.get::<Parent>(child).unwrap().get());
+        assert_eq!(3, world.entities().len());
+    }
 }
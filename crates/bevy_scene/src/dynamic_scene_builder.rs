@@ -1,4 +1,4 @@
-use crate::{DynamicEntity, DynamicScene, SceneFilter};
+use crate::{DynamicEntity, DynamicScene, SceneEntityId, SceneFilter};
 use bevy_ecs::component::{Component, ComponentId};
 use bevy_ecs::system::Resource;
 use bevy_ecs::{
@@ -8,6 +8,7 @@ use bevy_ecs::{
 };
 use bevy_reflect::Reflect;
 use bevy_utils::default;
+use std::any::TypeId;
 use std::collections::BTreeMap;
 
 /// A [`DynamicScene`] builder, used to build a scene from a [`World`] by extracting some entities and resources.
@@ -30,6 +31,12 @@ use std::collections::BTreeMap;
 ///
 /// Extraction happens immediately and uses the filter as it exists during the time of extraction.
 ///
+/// # Entity Ids
+///
+/// By default, entities are only identified by their [`Entity`] id.
+/// Use [`with_entity_ids`](DynamicSceneBuilder::with_entity_ids) to give every extracted entity
+/// a stable [`SceneEntityId`] as well.
+///
 /// # Entity Order
 ///
 /// Extracted entities will always be stored in ascending order based on their [index](Entity::index).
@@ -57,6 +64,7 @@ pub struct DynamicSceneBuilder<'w> {
     extracted_scene: BTreeMap<Entity, DynamicEntity>,
     component_filter: SceneFilter,
     resource_filter: SceneFilter,
+    with_entity_ids: bool,
     original_world: &'w World,
 }
 
@@ -68,6 +76,7 @@ impl<'w> DynamicSceneBuilder<'w> {
             extracted_scene: default(),
             component_filter: SceneFilter::default(),
             resource_filter: SceneFilter::default(),
+            with_entity_ids: false,
             original_world: world,
         }
     }
@@ -86,6 +95,19 @@ impl<'w> DynamicSceneBuilder<'w> {
         self
     }
 
+    /// Gives a [`SceneEntityId`] component to every entity of the built scene.
+    ///
+    /// Entities which have a `SceneEntityId` in the builder's [`World`] keep it,
+    /// even if the component is denied by the filter.
+    /// The others are given the next ids unused in the world, in entity order.
+    /// These new ids are only added to the scene: they become stable once the scene is spawned,
+    /// or once they are inserted in the world the scene was built from.
+    #[must_use]
+    pub fn with_entity_ids(mut self) -> Self {
+        self.with_entity_ids = true;
+        self
+    }
+
     /// Allows the given component type, `T`, to be included in the generated scene.
     ///
     /// This method may be called multiple times for any number of components.
@@ -183,10 +205,49 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// To make sure the dynamic scene doesn't contain entities without any components, call
     /// [`Self::remove_empty_entities`] before building the scene.
     #[must_use]
-    pub fn build(self) -> DynamicScene {
+    pub fn build(mut self) -> DynamicScene {
+        if self.with_entity_ids {
+            self.add_entity_ids();
+        }
+
         DynamicScene {
             resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_scene.into_values().collect(),
+            prefabs: Vec::new(),
+        }
+    }
+
+    fn add_entity_ids(&mut self) {
+        let mut next_id = self
+            .original_world
+            .iter_entities()
+            .filter_map(|entity| entity.get::<SceneEntityId>())
+            .map(|id| id.0 + 1)
+            .max()
+            .unwrap_or_default();
+
+        for (&entity, scene_entity) in self.extracted_scene.iter_mut() {
+            let has_id = scene_entity.components.iter().any(|component| {
+                component
+                    .get_represented_type_info()
+                    .is_some_and(|type_info| type_info.type_id() == TypeId::of::<SceneEntityId>())
+            });
+            if has_id {
+                continue;
+            }
+
+            let id = match self
+                .original_world
+                .get_entity(entity)
+                .and_then(|entity| entity.get::<SceneEntityId>())
+            {
+                Some(&id) => id,
+                None => {
+                    next_id += 1;
+                    SceneEntityId(next_id - 1)
+                }
+            };
+            scene_entity.components.push(Box::new(id));
         }
     }
 
@@ -359,6 +420,7 @@ mod tests {
     use bevy_reflect::Reflect;
 
     use super::DynamicSceneBuilder;
+    use crate::SceneEntityId;
 
     #[derive(Component, Reflect, Default, Eq, PartialEq, Debug)]
     #[reflect(Component)]
@@ -653,4 +715,50 @@ mod tests {
         assert_eq!(scene.resources.len(), 1);
         assert!(scene.resources[0].represents::<ResourceB>());
     }
+
+    #[test]
+    fn should_add_entity_ids() {
+        let mut world = World::default();
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<ComponentA>();
+            register.register::<SceneEntityId>();
+        }
+        world.insert_resource(atr);
+
+        let entity_a = world.spawn(ComponentA).id();
+        let entity_b = world.spawn((ComponentA, SceneEntityId(5))).id();
+        let entity_c = world.spawn(ComponentA).id();
+        world.spawn(SceneEntityId(2));
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .deny::<SceneEntityId>()
+            .with_entity_ids()
+            .extract_entities([entity_a, entity_b, entity_c].into_iter())
+            .build();
+
+        let ids: Vec<_> = scene
+            .entities
+            .iter()
+            .map(|scene_entity| {
+                assert_eq!(2, scene_entity.components.len());
+                (
+                    scene_entity.entity,
+                    *scene_entity.components[1]
+                        .downcast_ref::<SceneEntityId>()
+                        .unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (entity_a, SceneEntityId(6)),
+                (entity_b, SceneEntityId(5)),
+                (entity_c, SceneEntityId(7)),
+            ],
+            ids
+        );
+    }
 }
@@ -10,6 +10,7 @@ mod bundle;
 mod dynamic_scene;
 mod dynamic_scene_builder;
 mod scene;
+mod scene_entity_id;
 mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
@@ -25,6 +26,7 @@ pub use bundle::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
 pub use scene::*;
+pub use scene_entity_id::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
@@ -52,6 +54,8 @@ impl Plugin for ScenePlugin {
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
             .add_event::<SceneInstanceReady>()
+            .add_event::<SceneEntityChanged>()
+            .register_type::<SceneEntityId>()
             .init_resource::<SceneSpawner>()
             .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
     }
@@ -0,0 +1,20 @@
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::Reflect;
+
+/// A stable identifier of an entity, shared by the scenes it's saved to and loaded from.
+///
+/// [`Entity`](bevy_ecs::entity::Entity) ids are only meaningful within the world or scene
+/// they come from, and change every time a scene is spawned. This component identifies "the same"
+/// entity across saves instead, and should be unique within a world.
+///
+/// When a [`DynamicScene`](crate::DynamicScene) is written to a world, its entities with a
+/// `SceneEntityId` are mapped to the entities of the world which have the same id, rather than spawned.
+/// This means that writing a scene to a world which already contains an instance of it updates
+/// that instance, and that a scene can reference the entities of another scene by including
+/// their id.
+///
+/// Use [`DynamicSceneBuilder::with_entity_ids`](crate::DynamicSceneBuilder::with_entity_ids)
+/// to give an id to every extracted entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[reflect(Component, PartialEq, Hash)]
+pub struct SceneEntityId(pub u64);
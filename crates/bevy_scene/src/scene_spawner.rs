@@ -1,5 +1,5 @@
 use crate::{DynamicScene, Scene};
-use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
+use bevy_asset::{AssetEvent, AssetId, AssetServer, Assets, Handle};
 use bevy_ecs::{
     entity::Entity,
     event::{Event, Events, ManualEventReader},
@@ -21,6 +21,31 @@ pub struct SceneInstanceReady {
     pub parent: Entity,
 }
 
+/// Emitted for each entity of a scene instance changed by [`SceneSpawner::update_spawned_scenes`]
+/// after its dynamic scene was modified.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Event)]
+pub struct SceneEntityChanged {
+    /// The instance the entity belongs to.
+    pub instance_id: InstanceId,
+    /// The changed entity, in the world.
+    pub entity: Entity,
+    /// How the entity changed.
+    pub change: SceneEntityChange,
+}
+
+/// How an entity of a scene instance changed when its scene was updated.
+///
+/// See [`DynamicScene::update_world_with`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SceneEntityChange {
+    /// The entity is new to the scene, and was spawned.
+    Added,
+    /// Some components of the entity were added, updated or removed.
+    Updated,
+    /// The entity was removed from the scene, and despawned.
+    Removed,
+}
+
 /// Information about a scene instance.
 #[derive(Debug)]
 pub struct InstanceInfo {
@@ -28,6 +53,19 @@ pub struct InstanceInfo {
     pub entity_map: EntityHashMap<Entity, Entity>,
 }
 
+/// A scene instance spawned for a [`DynamicPrefab`](crate::DynamicPrefab).
+#[derive(Debug)]
+struct PrefabInstance {
+    /// Keeps the referenced scene loaded while the instance exists.
+    handle: Handle<DynamicScene>,
+    /// The scene containing the prefab.
+    scene: AssetId<DynamicScene>,
+    /// The index of the prefab in `scene`.
+    index: usize,
+    /// The instance of `scene` this instance was spawned for.
+    parent: InstanceId,
+}
+
 /// Unique id identifying a scene instance.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct InstanceId(Uuid);
@@ -68,6 +106,9 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    prefab_instances: HashMap<InstanceId, PrefabInstance>,
+    /// The version of each dynamic scene its instances were last written from.
+    scene_snapshots: HashMap<AssetId<DynamicScene>, DynamicScene>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -123,6 +164,30 @@ pub enum SceneSpawnError {
         /// Id of the non-existent scene.
         id: AssetId<Scene>,
     },
+    /// Scene contains prefabs but the world has no [`AssetServer`] to load them with.
+    #[error("scene contains prefabs but there is no `AssetServer` to load `{scene}` with")]
+    NoAssetServer {
+        /// Path of the scene referenced by the prefab.
+        scene: String,
+    },
+    /// Scene contains a prefab that references itself, directly or through other prefabs.
+    #[error("prefab of `{scene}` is nested in an instance of itself")]
+    PrefabCycle {
+        /// Path of the scene referenced by the prefab.
+        scene: String,
+    },
+    /// Scene contains a prefab override that can't be applied.
+    #[error("could not override `{component}` at path `{path}` in prefab of `{scene}`: {reason}")]
+    InvalidPrefabOverride {
+        /// Path of the scene referenced by the prefab.
+        scene: String,
+        /// Type path of the overridden component.
+        component: String,
+        /// Path of the overridden value within the component.
+        path: String,
+        /// Why the override can't be applied.
+        reason: String,
+    },
 }
 
 impl SceneSpawner {
@@ -176,7 +241,9 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id.into()) {
+        let id = id.into();
+        self.scene_snapshots.remove(&id);
+        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
@@ -185,15 +252,33 @@ impl SceneSpawner {
     }
 
     /// Immediately despawns a scene instance, removing all its entities from the world.
+    ///
+    /// Instances spawned for the prefabs of the scene are despawned as well.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
             for &entity in instance.entity_map.values() {
                 let _ = world.despawn(entity);
             }
         }
+        self.prefab_instances.remove(instance_id);
+        self.dynamic_scenes_to_spawn
+            .retain(|(_, queued)| queued != instance_id);
+
+        let nested_instances: Vec<_> = self
+            .prefab_instances
+            .iter()
+            .filter(|(_, prefab_instance)| prefab_instance.parent == *instance_id)
+            .map(|(&nested, _)| nested)
+            .collect();
+        for nested in nested_instances {
+            self.despawn_instance_sync(world, &nested);
+        }
     }
 
     /// Immediately spawns a new instance of the provided dynamic scene.
+    ///
+    /// The [prefabs](DynamicScene::prefabs) of the scene are queued, and spawned by the
+    /// next call to [`spawn_queued_scenes`](Self::spawn_queued_scenes) once loaded.
     pub fn spawn_dynamic_sync(
         &mut self,
         world: &mut World,
@@ -207,9 +292,22 @@ impl SceneSpawner {
             .insert(instance_id, InstanceInfo { entity_map });
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.push(instance_id);
+        self.snapshot_scene(world, id);
+        self.queue_prefabs(world, id, instance_id)?;
         Ok(instance_id)
     }
 
+    /// Keeps a copy of the dynamic scene `id` if it has none yet,
+    /// so that its instances can be updated incrementally when it's modified.
+    fn snapshot_scene(&mut self, world: &World, id: AssetId<DynamicScene>) {
+        if self.scene_snapshots.contains_key(&id) {
+            return;
+        }
+        if let Some(scene) = world.resource::<Assets<DynamicScene>>().get(id) {
+            self.scene_snapshots.insert(id, scene.snapshot());
+        }
+    }
+
     fn spawn_dynamic_internal(
         world: &mut World,
         id: AssetId<DynamicScene>,
@@ -223,6 +321,95 @@ impl SceneSpawner {
         })
     }
 
+    /// Queues the spawn of an instance of each prefab of the scene `id`,
+    /// as children of their entity in the scene instance `instance_id`.
+    fn queue_prefabs(
+        &mut self,
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        instance_id: InstanceId,
+    ) -> Result<(), SceneSpawnError> {
+        world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+            let Some(scene) = scenes.get(id) else {
+                return Ok(());
+            };
+
+            for (index, prefab) in scene.prefabs.iter().enumerate() {
+                let asset_server = world.get_resource::<AssetServer>().ok_or_else(|| {
+                    SceneSpawnError::NoAssetServer {
+                        scene: prefab.scene.to_string(),
+                    }
+                })?;
+                let handle: Handle<DynamicScene> = asset_server.load(prefab.scene.clone());
+
+                // Walk up the prefab instances this one would be nested in,
+                // to make sure none of them is an instance of the same scene.
+                let mut ancestor_scene = id;
+                let mut ancestor = instance_id;
+                loop {
+                    if ancestor_scene == handle.id() {
+                        return Err(SceneSpawnError::PrefabCycle {
+                            scene: prefab.scene.to_string(),
+                        });
+                    }
+                    let Some(prefab_instance) = self.prefab_instances.get(&ancestor) else {
+                        break;
+                    };
+                    ancestor_scene = prefab_instance.scene;
+                    ancestor = prefab_instance.parent;
+                }
+
+                let Some(instance) = self.spawned_instances.get_mut(&instance_id) else {
+                    return Ok(());
+                };
+                let parent = *instance
+                    .entity_map
+                    .entry(prefab.entity)
+                    .or_insert_with(|| world.spawn_empty().id());
+
+                let prefab_instance_id = InstanceId::new();
+                self.dynamic_scenes_to_spawn
+                    .push((handle.clone(), prefab_instance_id));
+                self.scenes_with_parent.push((prefab_instance_id, parent));
+                self.prefab_instances.insert(
+                    prefab_instance_id,
+                    PrefabInstance {
+                        handle,
+                        scene: id,
+                        index,
+                        parent: instance_id,
+                    },
+                );
+            }
+            Ok(())
+        })
+    }
+
+    /// Applies the overrides of the prefab the instance `instance_id` was spawned for, if any.
+    fn apply_prefab_overrides(
+        &self,
+        world: &mut World,
+        instance_id: InstanceId,
+    ) -> Result<(), SceneSpawnError> {
+        let (Some(prefab_instance), Some(instance)) = (
+            self.prefab_instances.get(&instance_id),
+            self.spawned_instances.get(&instance_id),
+        ) else {
+            return Ok(());
+        };
+
+        world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+            let prefab = scenes
+                .get(prefab_instance.scene)
+                .and_then(|scene| scene.prefabs.get(prefab_instance.index));
+            let Some(prefab) = prefab else {
+                return Ok(());
+            };
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            prefab.apply_overrides(world, &instance.entity_map, &type_registry)
+        })
+    }
+
     /// Immediately spawns a new instance of the provided scene.
     pub fn spawn_sync(
         &mut self,
@@ -256,19 +443,78 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    /// Only the entities and components which changed since the instances were last written are
+    /// updated, see [`DynamicScene::update_world_with`], and a [`SceneEntityChanged`] event is sent
+    /// for each of them.
+    ///
+    /// Prefab overrides are applied again to the updated instances, as well as to the prefab
+    /// instances of the updated scenes.
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_ids: &[AssetId<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
-        for id in scene_ids {
-            if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
-                for instance_id in spawned_instances {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
+        for &id in scene_ids {
+            let Some(spawned_instances) = self.spawned_dynamic_scenes.get(&id) else {
+                continue;
+            };
+            let previous = self.scene_snapshots.get(&id);
+
+            // The snapshot is only replaced once all instances are updated, so a failed update is
+            // retried from the same snapshot.
+            let snapshot = world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+                let scene = scenes
+                    .get(id)
+                    .ok_or(SceneSpawnError::NonExistentScene { id })?;
+                let type_registry = world.resource::<AppTypeRegistry>().clone();
+
+                for &instance_id in spawned_instances {
+                    let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) else {
+                        continue;
+                    };
+                    let Some(previous) = previous else {
+                        scene.write_to_world_with(
+                            world,
+                            &mut instance_info.entity_map,
+                            &type_registry,
+                        )?;
+                        continue;
+                    };
+
+                    let changes = scene.update_world_with(
+                        previous,
+                        world,
+                        &mut instance_info.entity_map,
+                        &type_registry,
+                    )?;
+                    if let Some(mut events) = world.get_resource_mut::<Events<SceneEntityChanged>>()
+                    {
+                        events.send_batch(changes.into_iter().map(|(entity, change)| {
+                            SceneEntityChanged {
+                                instance_id,
+                                entity,
+                                change,
+                            }
+                        }));
                     }
                 }
-            }
+
+                Ok(scene.snapshot())
+            })?;
+            self.scene_snapshots.insert(id, snapshot);
+        }
+
+        let overridden_instances: Vec<_> = self
+            .prefab_instances
+            .iter()
+            .filter(|(_, prefab_instance)| {
+                scene_ids.contains(&prefab_instance.handle.id())
+                    || scene_ids.contains(&prefab_instance.scene)
+            })
+            .map(|(&instance_id, _)| instance_id)
+            .collect();
+        for instance_id in overridden_instances {
+            self.apply_prefab_overrides(world, instance_id)?;
         }
         Ok(())
     }
@@ -293,28 +539,48 @@ impl SceneSpawner {
     }
 
     /// Immediately spawns all scenes scheduled for spawn.
-    pub fn spawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
-        let scenes_to_spawn = std::mem::take(&mut self.dynamic_scenes_to_spawn);
+    ///
+    /// This includes the prefabs of the spawned scenes, once they are loaded.
+    /// Scenes that aren't loaded yet stay queued, while instances that fail to spawn are logged
+    /// and discarded, without affecting the other instances.
+    pub fn spawn_queued_scenes(&mut self, world: &mut World) {
+        let mut not_loaded = Vec::new();
+
+        // Spawning a scene queues its prefabs, so keep going until they are all spawned or loading.
+        loop {
+            let scenes_to_spawn = std::mem::take(&mut self.dynamic_scenes_to_spawn);
+            if scenes_to_spawn.is_empty() {
+                break;
+            }
 
-        for (handle, instance_id) in scenes_to_spawn {
-            let mut entity_map = EntityHashMap::default();
+            for (handle, instance_id) in scenes_to_spawn {
+                let mut entity_map = EntityHashMap::default();
+                let result = Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map);
+                if let Err(SceneSpawnError::NonExistentScene { .. }) = result {
+                    not_loaded.push((handle, instance_id));
+                    continue;
+                }
 
-            match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
-                Ok(_) => {
-                    self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                // Keep track of the spawned entities, so they are despawned if the spawn fails.
+                self.spawned_instances
+                    .insert(instance_id, InstanceInfo { entity_map });
+                let result = result.and_then(|_| {
                     let spawned = self
                         .spawned_dynamic_scenes
                         .entry(handle.id())
                         .or_insert_with(Vec::new);
                     spawned.push(instance_id);
+                    self.snapshot_scene(world, handle.id());
+                    self.apply_prefab_overrides(world, instance_id)?;
+                    self.queue_prefabs(world, handle.id(), instance_id)
+                });
+                if let Err(err) = result {
+                    error!("Failed to spawn an instance of {handle:?}: {err}");
+                    self.discard_instance(world, instance_id);
                 }
-                Err(SceneSpawnError::NonExistentScene { .. }) => {
-                    self.dynamic_scenes_to_spawn.push((handle, instance_id));
-                }
-                Err(err) => return Err(err),
             }
         }
+        self.dynamic_scenes_to_spawn = not_loaded;
 
         let scenes_to_spawn = std::mem::take(&mut self.scenes_to_spawn);
 
@@ -324,11 +590,19 @@ impl SceneSpawner {
                 Err(SceneSpawnError::NonExistentRealScene { .. }) => {
                     self.scenes_to_spawn.push((scene_handle, instance_id));
                 }
-                Err(err) => return Err(err),
+                Err(err) => {
+                    error!("Failed to spawn an instance of {scene_handle:?}: {err}");
+                    self.discard_instance(world, instance_id);
+                }
             }
         }
+    }
 
-        Ok(())
+    /// Despawns an instance that failed to spawn, and stops waiting for it to be parented.
+    fn discard_instance(&mut self, world: &mut World, instance_id: InstanceId) {
+        self.despawn_instance_sync(world, &instance_id);
+        self.scenes_with_parent
+            .retain(|(instance, _)| *instance != instance_id);
     }
 
     pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
@@ -425,12 +699,10 @@ pub fn scene_spawner_system(world: &mut World) {
 
         scene_spawner.despawn_queued_scenes(world).unwrap();
         scene_spawner.despawn_queued_instances(world);
-        scene_spawner
-            .spawn_queued_scenes(world)
-            .unwrap_or_else(|err| panic!("{}", err));
-        scene_spawner
-            .update_spawned_scenes(world, &updated_spawned_scenes)
-            .unwrap();
+        scene_spawner.spawn_queued_scenes(world);
+        if let Err(err) = scene_spawner.update_spawned_scenes(world, &updated_spawned_scenes) {
+            error!("Failed to update spawned scenes: {err}");
+        }
         scene_spawner.set_scene_instance_parent_sync(world);
     });
 }
@@ -444,8 +716,19 @@ mod tests {
     use bevy_ecs::query::With;
     use bevy_ecs::{reflect::AppTypeRegistry, world::World};
 
-    use crate::DynamicSceneBuilder;
-    use bevy_reflect::Reflect;
+    use crate::{DynamicEntity, DynamicPrefab, DynamicSceneBuilder, PrefabOverride, ScenePlugin};
+    use bevy_app::App;
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin,
+    };
+    use bevy_core::TaskPoolPlugin;
+    use bevy_hierarchy::Children;
+    use bevy_reflect::{Reflect, TypePath};
+    use std::path::Path;
 
     #[derive(Reflect, Component, Debug, PartialEq, Eq, Clone, Copy, Default)]
     #[reflect(Component)]
@@ -497,4 +780,372 @@ mod tests {
             .unwrap();
         assert_eq!(old_a, new_a);
     }
+
+    #[derive(Reflect, Component, Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[reflect(Component)]
+    struct B(usize);
+
+    #[derive(Component)]
+    struct RuntimeMarker;
+
+    fn scene_of(entities: Vec<(u32, Vec<Box<dyn Reflect>>)>) -> DynamicScene {
+        DynamicScene {
+            entities: entities
+                .into_iter()
+                .map(|(index, components)| DynamicEntity {
+                    entity: Entity::from_raw(index),
+                    components,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn update_spawned_scenes_incrementally() {
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<A>();
+        atr.write().register::<B>();
+        world.insert_resource(atr);
+        world.insert_resource(Assets::<DynamicScene>::default());
+        world.init_resource::<Events<SceneEntityChanged>>();
+
+        let scene = scene_of(vec![
+            (0, vec![Box::new(A(1))]),
+            (1, vec![Box::new(A(2)), Box::new(B(2))]),
+            (2, vec![Box::new(A(3))]),
+        ]);
+        let scene_id = world.resource_mut::<Assets<DynamicScene>>().add(scene).id();
+        let mut scene_spawner = SceneSpawner::default();
+        let instance_id = scene_spawner
+            .spawn_dynamic_sync(&mut world, scene_id)
+            .unwrap();
+        let entity_map = scene_spawner.spawned_instances[&instance_id]
+            .entity_map
+            .clone();
+        let [unchanged, updated, removed] =
+            [0, 1, 2].map(|index| entity_map[&Entity::from_raw(index)]);
+
+        // Runtime changes to the instance.
+        world.entity_mut(unchanged).insert((A(100), RuntimeMarker));
+
+        let scene = scene_of(vec![
+            (0, vec![Box::new(A(1))]),
+            (1, vec![Box::new(A(20))]),
+            (3, vec![Box::new(A(4))]),
+        ]);
+        world
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(scene_id, scene);
+        scene_spawner
+            .update_spawned_scenes(&mut world, &[scene_id])
+            .unwrap();
+
+        // Unchanged entities and components are left untouched.
+        assert_eq!(Some(&A(100)), world.get::<A>(unchanged));
+        assert!(world.get::<RuntimeMarker>(unchanged).is_some());
+        // Changed ones are updated, and removed ones despawned.
+        assert_eq!(Some(&A(20)), world.get::<A>(updated));
+        assert_eq!(None, world.get::<B>(updated));
+        assert!(world.get_entity(removed).is_none());
+        let added = scene_spawner.spawned_instances[&instance_id].entity_map[&Entity::from_raw(3)];
+        assert_eq!(Some(&A(4)), world.get::<A>(added));
+
+        let events = world.resource::<Events<SceneEntityChanged>>();
+        let changes: Vec<_> = ManualEventReader::default()
+            .read(events)
+            .map(|event| {
+                assert_eq!(instance_id, event.instance_id);
+                (event.entity, event.change)
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (updated, SceneEntityChange::Updated),
+                (added, SceneEntityChange::Added),
+                (removed, SceneEntityChange::Removed),
+            ],
+            changes
+        );
+    }
+
+    #[test]
+    fn failed_update_keeps_scene_snapshot() {
+        #[derive(Reflect, Component, Default)]
+        #[reflect(Component)]
+        struct Unregistered;
+
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<A>();
+        world.insert_resource(atr);
+        world.insert_resource(Assets::<DynamicScene>::default());
+
+        let scene = scene_of(vec![(0, vec![Box::new(A(1))])]);
+        let scene_id = world.resource_mut::<Assets<DynamicScene>>().add(scene).id();
+        let mut scene_spawner = SceneSpawner::default();
+        let instance_id = scene_spawner
+            .spawn_dynamic_sync(&mut world, scene_id)
+            .unwrap();
+        let entity = scene_spawner.spawned_instances[&instance_id].entity_map[&Entity::from_raw(0)];
+        world.entity_mut(entity).insert(A(100));
+
+        let scene = scene_of(vec![
+            (0, vec![Box::new(A(1))]),
+            (1, vec![Box::new(Unregistered)]),
+        ]);
+        world
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(scene_id, scene);
+        assert!(scene_spawner
+            .update_spawned_scenes(&mut world, &[scene_id])
+            .is_err());
+
+        // The update is retried from the previous snapshot, so unchanged components are kept.
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Unregistered>();
+        scene_spawner
+            .update_spawned_scenes(&mut world, &[scene_id])
+            .unwrap();
+        assert_eq!(Some(&A(100)), world.get::<A>(entity));
+    }
+
+    #[derive(Reflect, Component, Debug, PartialEq, Clone, Default)]
+    #[reflect(Component)]
+    struct Stats {
+        health: u32,
+        speed: f32,
+    }
+
+    fn prefab_app(dir: Dir) -> App {
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Stats>();
+        app
+    }
+
+    fn run_app_until(app: &mut App, mut predicate: impl FnMut(&mut World) -> bool) {
+        for _ in 0..10000 {
+            app.update();
+            if predicate(&mut app.world) {
+                return;
+            }
+        }
+        panic!("Ran out of loops to return `true` from `predicate`");
+    }
+
+    fn stats(world: &mut World) -> Vec<Stats> {
+        world.query::<&Stats>().iter(world).cloned().collect()
+    }
+
+    fn stats_scene(stats: Stats) -> DynamicScene {
+        DynamicScene {
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(stats)],
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn level_scene(health: u32) -> DynamicScene {
+        DynamicScene {
+            prefabs: vec![DynamicPrefab {
+                entity: Entity::from_raw(0),
+                scene: "base.scn.ron".into(),
+                overrides: vec![PrefabOverride {
+                    entity: Entity::from_raw(0),
+                    component: Stats::type_path().to_string(),
+                    path: ".health".to_string(),
+                    value: Box::new(health),
+                }],
+            }],
+            ..Default::default()
+        }
+    }
+
+    const BASE_SCENE: &str = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::scene_spawner::tests::Stats": (
+          health: 10,
+          speed: 1.0,
+        ),
+      },
+    ),
+  },
+)"#;
+
+    #[test]
+    fn spawn_prefab_with_overrides() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("base.scn.ron"), BASE_SCENE);
+        let mut app = prefab_app(dir);
+
+        let level = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(level_scene(50));
+        let instance_id = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(level.clone());
+
+        run_app_until(&mut app, |world| !stats(world).is_empty());
+        assert_eq!(
+            vec![Stats {
+                health: 50,
+                speed: 1.0
+            }],
+            stats(&mut app.world)
+        );
+
+        // The base scene is spawned under the prefab entity of the level instance.
+        let prefab_entity = app
+            .world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance_id)
+            .next()
+            .unwrap();
+        let base_entity = app
+            .world
+            .query_filtered::<Entity, With<Stats>>()
+            .single(&app.world);
+        assert_eq!(
+            &[base_entity],
+            &**app.world.get::<Children>(prefab_entity).unwrap()
+        );
+
+        // Reloading the base scene updates the instance, but keeps its overrides.
+        let base = app
+            .world
+            .resource::<AssetServer>()
+            .load::<DynamicScene>("base.scn.ron");
+        app.world.resource_mut::<Assets<DynamicScene>>().insert(
+            &base,
+            stats_scene(Stats {
+                health: 20,
+                speed: 2.0,
+            }),
+        );
+        run_app_until(&mut app, |world| stats(world)[0].speed == 2.0);
+        assert_eq!(
+            vec![Stats {
+                health: 50,
+                speed: 2.0
+            }],
+            stats(&mut app.world)
+        );
+
+        // Reloading the level applies its new overrides.
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&level, level_scene(60));
+        run_app_until(&mut app, |world| stats(world)[0].health == 60);
+        assert_eq!(
+            vec![Stats {
+                health: 60,
+                speed: 2.0
+            }],
+            stats(&mut app.world)
+        );
+
+        // Despawning the level despawns the prefab instance.
+        app.world
+            .resource_mut::<SceneSpawner>()
+            .despawn_instance(instance_id);
+        app.update();
+        assert!(stats(&mut app.world).is_empty());
+    }
+
+    #[test]
+    fn prefab_cycle_is_an_error() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("loop.scn.ron"),
+            r#"(
+  resources: {},
+  entities: {},
+  prefabs: {
+    4294967296: (
+      scene: "loop.scn.ron",
+      overrides: [],
+    ),
+  },
+)"#,
+        );
+        let mut app = prefab_app(dir);
+
+        let handle = app
+            .world
+            .resource::<AssetServer>()
+            .load::<DynamicScene>("loop.scn.ron");
+        run_app_until(&mut app, |world| {
+            world.resource::<Assets<DynamicScene>>().contains(&handle)
+        });
+
+        let result = app
+            .world
+            .resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
+                scene_spawner.spawn_dynamic_sync(world, &handle)
+            });
+        assert!(matches!(result, Err(SceneSpawnError::PrefabCycle { .. })));
+    }
+
+    #[test]
+    fn failed_instances_do_not_stop_other_spawns() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("base.scn.ron"), BASE_SCENE);
+        dir.insert_asset_text(
+            Path::new("loop.scn.ron"),
+            r#"(
+  resources: {},
+  entities: {},
+  prefabs: {
+    4294967296: (
+      scene: "loop.scn.ron",
+      overrides: [],
+    ),
+  },
+)"#,
+        );
+        let mut app = prefab_app(dir);
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let looping = asset_server.load::<DynamicScene>("loop.scn.ron");
+        let base = asset_server.load::<DynamicScene>("base.scn.ron");
+        run_app_until(&mut app, |world| {
+            let scenes = world.resource::<Assets<DynamicScene>>();
+            scenes.contains(&looping) && scenes.contains(&base)
+        });
+
+        let mut scene_spawner = app.world.resource_mut::<SceneSpawner>();
+        let looping_instance = scene_spawner.spawn_dynamic(looping);
+        let base_instance = scene_spawner.spawn_dynamic(base.clone());
+        let not_loaded_instance = scene_spawner.spawn_dynamic(Handle::<DynamicScene>::default());
+        app.update();
+
+        let scene_spawner = app.world.resource::<SceneSpawner>();
+        assert!(!scene_spawner.instance_is_ready(looping_instance));
+        assert!(scene_spawner.instance_is_ready(base_instance));
+        assert!(scene_spawner
+            .dynamic_scenes_to_spawn
+            .iter()
+            .any(|(_, instance)| *instance == not_loaded_instance));
+        assert_eq!(1, stats(&mut app.world).len());
+    }
 }
@@ -1,14 +1,19 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicPrefab, DynamicScene, PrefabOverride};
+use bevy_asset::AssetPath;
 use bevy_ecs::entity::Entity;
-use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use bevy_reflect::serde::{
+    CompactTypeRegistrationDeserializer, CompactTypeTable, CompactTypeTableDeserializer,
+    TypedCompactReflectDeserializer, TypedCompactReflectSerializer, TypedReflectDeserializer,
+    TypedReflectSerializer,
+};
 use bevy_reflect::{
-    serde::{TypeRegistrationDeserializer, UntypedReflectDeserializer},
+    serde::{ReflectSerializer, TypeRegistrationDeserializer, UntypedReflectDeserializer},
     Reflect, TypeRegistry, TypeRegistryArc,
 };
 use bevy_utils::HashSet;
-use serde::ser::SerializeMap;
+use serde::ser::{Error as _, SerializeMap, SerializeSeq, SerializeTuple};
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
     ser::SerializeStruct,
@@ -22,14 +27,37 @@ pub const SCENE_STRUCT: &str = "Scene";
 pub const SCENE_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a scene struct.
 pub const SCENE_ENTITIES: &str = "entities";
+/// Name of the serialized prefabs field in a scene struct.
+pub const SCENE_PREFABS: &str = "prefabs";
 
 /// Name of the serialized entity struct type.
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized prefab struct type.
+pub const PREFAB_STRUCT: &str = "Prefab";
+/// Name of the serialized referenced scene field in a prefab struct.
+pub const PREFAB_FIELD_SCENE: &str = "scene";
+/// Name of the serialized overrides field in a prefab struct.
+pub const PREFAB_FIELD_OVERRIDES: &str = "overrides";
+
+/// Name of the serialized prefab override struct type.
+pub const OVERRIDE_STRUCT: &str = "Override";
+/// Name of the serialized entity field in a prefab override struct.
+pub const OVERRIDE_FIELD_ENTITY: &str = "entity";
+/// Name of the serialized component field in a prefab override struct.
+pub const OVERRIDE_FIELD_COMPONENT: &str = "component";
+/// Name of the serialized path field in a prefab override struct.
+pub const OVERRIDE_FIELD_PATH: &str = "path";
+/// Name of the serialized value field in a prefab override struct.
+pub const OVERRIDE_FIELD_VALUE: &str = "value";
+
 /// Handles serialization of a scene as a struct containing its entities and resources.
 ///
+/// The prefabs of the scene are serialized as a map of entity id to serialized prefab.
+/// Human-readable formats omit them when the scene has none.
+///
 /// # Examples
 ///
 /// ```
@@ -90,7 +118,9 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        let skip_prefabs = self.scene.prefabs.is_empty() && serializer.is_human_readable();
+        let len = if skip_prefabs { 2 } else { 3 };
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, len)?;
         state.serialize_field(
             SCENE_RESOURCES,
             &SceneMapSerializer {
@@ -105,6 +135,17 @@ impl<'a> Serialize for SceneSerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if skip_prefabs {
+            state.skip_field(SCENE_PREFABS)?;
+        } else {
+            state.serialize_field(
+                SCENE_PREFABS,
+                &PrefabsSerializer {
+                    prefabs: &self.scene.prefabs,
+                    values: ValueSerializer::Reflect(self.registry),
+                },
+            )?;
+        }
         state.end()
     }
 }
@@ -194,6 +235,7 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
 enum SceneField {
     Resources,
     Entities,
+    Prefabs,
 }
 
 #[derive(Deserialize)]
@@ -217,7 +259,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     {
         deserializer.deserialize_struct(
             SCENE_STRUCT,
-            &[SCENE_RESOURCES, SCENE_ENTITIES],
+            &[SCENE_RESOURCES, SCENE_ENTITIES, SCENE_PREFABS],
             SceneVisitor {
                 type_registry: self.type_registry,
             },
@@ -252,9 +294,16 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
+        let prefabs = seq
+            .next_element_seed(PrefabsDeserializer {
+                values: ValueDeserializer::Reflect(self.type_registry),
+            })?
+            .unwrap_or_default();
+
         Ok(DynamicScene {
             resources,
             entities,
+            prefabs,
         })
     }
 
@@ -264,6 +313,7 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     {
         let mut resources = None;
         let mut entities = None;
+        let mut prefabs = None;
         while let Some(key) = map.next_key()? {
             match key {
                 SceneField::Resources => {
@@ -282,15 +332,26 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                         type_registry: self.type_registry,
                     })?);
                 }
+                SceneField::Prefabs => {
+                    if prefabs.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PREFABS));
+                    }
+                    prefabs = Some(map.next_value_seed(PrefabsDeserializer {
+                        values: ValueDeserializer::Reflect(self.type_registry),
+                    })?);
+                }
             }
         }
 
         let resources = resources.ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
         let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        // Prefabs are optional, to keep loading scenes written before they were introduced.
+        let prefabs = prefabs.unwrap_or_default();
 
         Ok(DynamicScene {
             resources,
             entities,
+            prefabs,
         })
     }
 }
@@ -494,17 +555,779 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     }
 }
 
+/// Handles serialization of a scene in the compact reflection format.
+///
+/// Fields are identified by their index rather than their name,
+/// and values by their id in a [`CompactTypeTable`] of the types used in the scene.
+/// This is meant for binary formats such as `bincode` or `postcard`.
+///
+/// The scene is serialized as a tuple containing:
+/// 1. The [`CompactTypeTable`] of the resources, components and override values types
+/// 2. The resources, as a sequence of type id and value pairs
+/// 3. The entities, as a sequence of entity and components pairs,
+///    where components are serialized like resources
+/// 4. The prefabs, as a map of entity to prefab,
+///    where override values are serialized like resources
+///
+/// Use a [`CompactSceneDeserializer`] to deserialize it.
+pub struct CompactSceneSerializer<'a> {
+    /// The scene to serialize.
+    pub scene: &'a DynamicScene,
+    /// Type registry in which the components and resources types used in the scene are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactSceneSerializer<'a> {
+    /// Creates a compact scene serializer.
+    pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
+        CompactSceneSerializer { scene, registry }
+    }
+}
+
+impl<'a> Serialize for CompactSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut types = CompactTypeTable::new(self.registry);
+        let values = self
+            .scene
+            .resources
+            .iter()
+            .chain(
+                self.scene
+                    .entities
+                    .iter()
+                    .flat_map(|entity| &entity.components),
+            )
+            .chain(
+                self.scene
+                    .prefabs
+                    .iter()
+                    .flat_map(|prefab| &prefab.overrides)
+                    .map(|item| &item.value),
+            );
+        for value in values {
+            let type_info = value.get_represented_type_info().unwrap();
+            types.insert(type_info.type_id()).ok_or_else(|| {
+                S::Error::custom(format_args!(
+                    "no registration found for type `{}`",
+                    type_info.type_path()
+                ))
+            })?;
+        }
+
+        let mut state = serializer.serialize_tuple(4)?;
+        state.serialize_element(&types)?;
+        state.serialize_element(&CompactValuesSerializer {
+            values: &self.scene.resources,
+            types: &types,
+        })?;
+        state.serialize_element(&CompactEntitiesSerializer {
+            entities: &self.scene.entities,
+            types: &types,
+        })?;
+        state.serialize_element(&PrefabsSerializer {
+            prefabs: &self.scene.prefabs,
+            values: ValueSerializer::Compact(&types),
+        })?;
+        state.end()
+    }
+}
+
+struct CompactEntitiesSerializer<'a> {
+    entities: &'a [DynamicEntity],
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a> Serialize for CompactEntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.entities.len()))?;
+        for entity in self.entities {
+            state.serialize_element(&(
+                entity.entity,
+                CompactValuesSerializer {
+                    values: &entity.components,
+                    types: self.types,
+                },
+            ))?;
+        }
+        state.end()
+    }
+}
+
+struct CompactValuesSerializer<'a> {
+    values: &'a [Box<dyn Reflect>],
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a> Serialize for CompactValuesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.values.len()))?;
+        for value in self.values {
+            state.serialize_element(&CompactValueSerializer {
+                value: &**value,
+                types: self.types,
+            })?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes a type id and value pair.
+struct CompactValueSerializer<'a> {
+    value: &'a dyn Reflect,
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a> Serialize for CompactValueSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let type_info = self.value.get_represented_type_info().unwrap();
+        let id = self.types.id(type_info.type_id()).ok_or_else(|| {
+            S::Error::custom(format_args!(
+                "no registration found for type `{}`",
+                type_info.type_path()
+            ))
+        })?;
+        (
+            id,
+            TypedCompactReflectSerializer::new(self.value, self.types),
+        )
+            .serialize(serializer)
+    }
+}
+
+/// Handles deserialization of a scene serialized with a [`CompactSceneSerializer`].
+///
+/// Fails if a type used in the scene isn't registered, or if its [schema hash] doesn't match.
+///
+/// [schema hash]: CompactTypeTable::schema_hash
+pub struct CompactSceneDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactSceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(4, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactSceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("compact scene")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let types = seq
+            .next_element_seed(CompactTypeTableDeserializer::new(self.registry))?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let resources = seq
+            .next_element_seed(CompactValuesDeserializer { types: &types })?
+            .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+        let entities = seq
+            .next_element_seed(CompactEntitiesDeserializer { types: &types })?
+            .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        let prefabs = seq
+            .next_element_seed(PrefabsDeserializer {
+                values: ValueDeserializer::Compact(&types),
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_PREFABS))?;
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+            prefabs,
+        })
+    }
+}
+
+struct CompactEntitiesDeserializer<'a> {
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence of compact entities")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entities = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(entity) =
+            seq.next_element_seed(CompactEntityDeserializer { types: self.types })?
+        {
+            entities.push(entity);
+        }
+        Ok(entities)
+    }
+}
+
+struct CompactEntityDeserializer<'a> {
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("compact entity")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entity = seq
+            .next_element::<Entity>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let components = seq
+            .next_element_seed(CompactValuesDeserializer { types: self.types })?
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        Ok(DynamicEntity { entity, components })
+    }
+}
+
+struct CompactValuesDeserializer<'a> {
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence of compact reflect values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) =
+            seq.next_element_seed(CompactValueDeserializer { types: self.types })?
+        {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a type id and value pair.
+struct CompactValueDeserializer<'a> {
+    types: &'a CompactTypeTable<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactValueDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactValueDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("compact reflect value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let registration = seq
+            .next_element_seed(CompactTypeRegistrationDeserializer::new(self.types))?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        seq.next_element_seed(TypedCompactReflectDeserializer::new(
+            registration,
+            self.types,
+        ))?
+        .ok_or_else(|| Error::invalid_length(1, &self))
+    }
+}
+
+/// How the override values of prefabs are serialized.
+#[derive(Clone, Copy)]
+enum ValueSerializer<'a> {
+    /// As a map of type path to value, like values of a [`SceneSerializer`].
+    Reflect(&'a TypeRegistryArc),
+    /// As a type id and value pair, like values of a [`CompactSceneSerializer`].
+    Compact(&'a CompactTypeTable<'a>),
+}
+
+/// Handles serialization of prefabs as a map of entity id to prefab struct.
+struct PrefabsSerializer<'a> {
+    prefabs: &'a [DynamicPrefab],
+    values: ValueSerializer<'a>,
+}
+
+impl<'a> Serialize for PrefabsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.prefabs.len()))?;
+        for prefab in self.prefabs {
+            state.serialize_entry(
+                &prefab.entity,
+                &PrefabSerializer {
+                    prefab,
+                    values: self.values,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct PrefabSerializer<'a> {
+    prefab: &'a DynamicPrefab,
+    values: ValueSerializer<'a>,
+}
+
+impl<'a> Serialize for PrefabSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(PREFAB_STRUCT, 2)?;
+        state.serialize_field(PREFAB_FIELD_SCENE, &self.prefab.scene)?;
+        state.serialize_field(
+            PREFAB_FIELD_OVERRIDES,
+            &OverridesSerializer {
+                overrides: &self.prefab.overrides,
+                values: self.values,
+            },
+        )?;
+        state.end()
+    }
+}
+
+struct OverridesSerializer<'a> {
+    overrides: &'a [PrefabOverride],
+    values: ValueSerializer<'a>,
+}
+
+impl<'a> Serialize for OverridesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.overrides.len()))?;
+        for item in self.overrides {
+            state.serialize_element(&OverrideSerializer {
+                item,
+                values: self.values,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct OverrideSerializer<'a> {
+    item: &'a PrefabOverride,
+    values: ValueSerializer<'a>,
+}
+
+impl<'a> Serialize for OverrideSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(OVERRIDE_STRUCT, 4)?;
+        state.serialize_field(OVERRIDE_FIELD_ENTITY, &self.item.entity)?;
+        state.serialize_field(OVERRIDE_FIELD_COMPONENT, &self.item.component)?;
+        state.serialize_field(OVERRIDE_FIELD_PATH, &self.item.path)?;
+        match self.values {
+            ValueSerializer::Reflect(registry) => state.serialize_field(
+                OVERRIDE_FIELD_VALUE,
+                &ReflectSerializer::new(&*self.item.value, &registry.read()),
+            )?,
+            ValueSerializer::Compact(types) => state.serialize_field(
+                OVERRIDE_FIELD_VALUE,
+                &CompactValueSerializer {
+                    value: &*self.item.value,
+                    types,
+                },
+            )?,
+        }
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum PrefabField {
+    Scene,
+    Overrides,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum OverrideField {
+    Entity,
+    Component,
+    Path,
+    Value,
+}
+
+/// How the override values of prefabs are deserialized, matching [`ValueSerializer`].
+#[derive(Clone, Copy)]
+enum ValueDeserializer<'a> {
+    Reflect(&'a TypeRegistry),
+    Compact(&'a CompactTypeTable<'a>),
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ValueDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self {
+            ValueDeserializer::Reflect(registry) => {
+                UntypedReflectDeserializer::new(registry).deserialize(deserializer)
+            }
+            ValueDeserializer::Compact(types) => {
+                CompactValueDeserializer { types }.deserialize(deserializer)
+            }
+        }
+    }
+}
+
+struct PrefabsDeserializer<'a> {
+    values: ValueDeserializer<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for PrefabsDeserializer<'a> {
+    type Value = Vec<DynamicPrefab>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for PrefabsDeserializer<'a> {
+    type Value = Vec<DynamicPrefab>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of prefabs")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut prefabs = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            prefabs.push(map.next_value_seed(PrefabDeserializer {
+                entity,
+                values: self.values,
+            })?);
+        }
+        Ok(prefabs)
+    }
+}
+
+struct PrefabDeserializer<'a> {
+    entity: Entity,
+    values: ValueDeserializer<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for PrefabDeserializer<'a> {
+    type Value = DynamicPrefab;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            PREFAB_STRUCT,
+            &[PREFAB_FIELD_SCENE, PREFAB_FIELD_OVERRIDES],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for PrefabDeserializer<'a> {
+    type Value = DynamicPrefab;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("prefab struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let scene = seq
+            .next_element::<AssetPath<'static>>()?
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_SCENE))?;
+        let overrides = seq
+            .next_element_seed(OverridesDeserializer {
+                values: self.values,
+            })?
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_OVERRIDES))?;
+
+        Ok(DynamicPrefab {
+            entity: self.entity,
+            scene,
+            overrides,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut scene = None;
+        let mut overrides = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                PrefabField::Scene => {
+                    if scene.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_SCENE));
+                    }
+                    scene = Some(map.next_value::<AssetPath<'static>>()?);
+                }
+                PrefabField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(OverridesDeserializer {
+                        values: self.values,
+                    })?);
+                }
+            }
+        }
+
+        let scene = scene.ok_or_else(|| Error::missing_field(PREFAB_FIELD_SCENE))?;
+        Ok(DynamicPrefab {
+            entity: self.entity,
+            scene,
+            overrides: overrides.unwrap_or_default(),
+        })
+    }
+}
+
+struct OverridesDeserializer<'a> {
+    values: ValueDeserializer<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for OverridesDeserializer<'a> {
+    type Value = Vec<PrefabOverride>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for OverridesDeserializer<'a> {
+    type Value = Vec<PrefabOverride>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence of prefab overrides")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut overrides = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(item) = seq.next_element_seed(OverrideDeserializer {
+            values: self.values,
+        })? {
+            overrides.push(item);
+        }
+        Ok(overrides)
+    }
+}
+
+struct OverrideDeserializer<'a> {
+    values: ValueDeserializer<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for OverrideDeserializer<'a> {
+    type Value = PrefabOverride;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            OVERRIDE_STRUCT,
+            &[
+                OVERRIDE_FIELD_ENTITY,
+                OVERRIDE_FIELD_COMPONENT,
+                OVERRIDE_FIELD_PATH,
+                OVERRIDE_FIELD_VALUE,
+            ],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for OverrideDeserializer<'a> {
+    type Value = PrefabOverride;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("prefab override struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entity = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_ENTITY))?;
+        let component = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_COMPONENT))?;
+        let path = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_PATH))?;
+        let value = seq
+            .next_element_seed(self.values)?
+            .ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_VALUE))?;
+
+        Ok(PrefabOverride {
+            entity,
+            component,
+            path,
+            value,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entity = None;
+        let mut component = None;
+        let mut path = None;
+        let mut value = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                OverrideField::Entity => {
+                    if entity.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_ENTITY));
+                    }
+                    entity = Some(map.next_value()?);
+                }
+                OverrideField::Component => {
+                    if component.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_COMPONENT));
+                    }
+                    component = Some(map.next_value()?);
+                }
+                OverrideField::Path => {
+                    if path.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_PATH));
+                    }
+                    path = Some(map.next_value()?);
+                }
+                OverrideField::Value => {
+                    if value.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_VALUE));
+                    }
+                    value = Some(map.next_value_seed(self.values)?);
+                }
+            }
+        }
+
+        Ok(PrefabOverride {
+            entity: entity.ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_ENTITY))?,
+            component: component.ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_COMPONENT))?,
+            // The whole component is overridden when no path is given.
+            path: path.unwrap_or_default(),
+            value: value.ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_VALUE))?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ron;
-    use crate::serde::{SceneDeserializer, SceneSerializer};
+    use crate::serde::{
+        CompactSceneDeserializer, CompactSceneSerializer, SceneDeserializer, SceneSerializer,
+    };
     use crate::{DynamicScene, DynamicSceneBuilder};
+    use bevy_asset::AssetPath;
     use bevy_ecs::entity::{Entity, EntityMapper, MapEntities};
     use bevy_ecs::prelude::{Component, ReflectComponent, ReflectResource, Resource, World};
     use bevy_ecs::query::{With, Without};
     use bevy_ecs::reflect::{AppTypeRegistry, ReflectMapEntities};
     use bevy_ecs::world::FromWorld;
-    use bevy_reflect::{Reflect, ReflectSerialize};
+    use bevy_reflect::{Reflect, ReflectSerialize, TypeRegistry};
     use bevy_utils::EntityHashMap;
     use bincode::Options;
     use serde::de::DeserializeSeed;
@@ -766,7 +1589,7 @@ mod tests {
                 0, 1, 128, 128, 128, 128, 16, 1, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101,
                 58, 58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121,
                 67, 111, 109, 112, 111, 110, 101, 110, 116, 1, 2, 3, 102, 102, 166, 63, 205, 204,
-                108, 64, 1, 12, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                108, 64, 1, 12, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 0
             ],
             serialized_scene
         );
@@ -803,11 +1626,11 @@ mod tests {
 
         assert_eq!(
             vec![
-                146, 128, 129, 207, 0, 0, 0, 1, 0, 0, 0, 0, 145, 129, 217, 37, 98, 101, 118, 121,
+                147, 128, 129, 207, 0, 0, 0, 1, 0, 0, 0, 0, 145, 129, 217, 37, 98, 101, 118, 121,
                 95, 115, 99, 101, 110, 101, 58, 58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115,
                 116, 115, 58, 58, 77, 121, 67, 111, 109, 112, 111, 110, 101, 110, 116, 147, 147, 1,
                 2, 3, 146, 202, 63, 166, 102, 102, 202, 64, 108, 204, 205, 129, 165, 84, 117, 112,
-                108, 101, 172, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                108, 101, 172, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 128
             ],
             buf
         );
@@ -849,7 +1672,8 @@ mod tests {
                 58, 58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121,
                 67, 111, 109, 112, 111, 110, 101, 110, 116, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
                 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 102, 102, 166, 63, 205, 204, 108, 64, 1, 0, 0, 0,
-                12, 0, 0, 0, 0, 0, 0, 0, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                12, 0, 0, 0, 0, 0, 0, 0, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33, 0,
+                0, 0, 0, 0, 0, 0, 0
             ],
             serialized_scene
         );
@@ -867,6 +1691,183 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_compact() {
+        let mut world = create_world();
+
+        world.spawn(MyComponent {
+            foo: [1, 2, 3],
+            bar: (1.3, 3.7),
+            baz: MyEnum::Struct { value: 42 },
+        });
+        // Type paths are only written once, so repeated types take less space.
+        for i in 0..4 {
+            world.spawn((Foo(i), Bar(i)));
+        }
+        world.insert_resource(MyResource { foo: 123 });
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities(world.iter_entities().map(|entity| entity.id()))
+            .extract_resources()
+            .build();
+
+        let scene_serializer = CompactSceneSerializer::new(&scene, &registry);
+        let compact_bincode = bincode::serialize(&scene_serializer).unwrap();
+        let compact_postcard = postcard::to_allocvec(&scene_serializer).unwrap();
+
+        let from_bincode = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                CompactSceneDeserializer {
+                    registry: &registry,
+                },
+                &compact_bincode,
+            )
+            .unwrap();
+        let from_postcard = CompactSceneDeserializer {
+            registry: &registry,
+        }
+        .deserialize(&mut postcard::Deserializer::from_bytes(&compact_postcard))
+        .unwrap();
+
+        // Compare with the output of the existing scene serializer.
+        let registry_arc = &world.resource::<AppTypeRegistry>().0;
+        let bincode = bincode::serialize(&SceneSerializer::new(&scene, registry_arc)).unwrap();
+        let from_existing = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                SceneDeserializer {
+                    type_registry: &registry,
+                },
+                &bincode,
+            )
+            .unwrap();
+        assert!(compact_bincode.len() < bincode.len());
+
+        for deserialized_scene in [from_bincode, from_postcard] {
+            assert_eq!(5, deserialized_scene.entities.len());
+            assert_eq!(1, deserialized_scene.resources.len());
+            assert_scene_eq(&scene, &deserialized_scene);
+            assert_scene_eq(&from_existing, &deserialized_scene);
+            assert!(scene.resources[0]
+                .reflect_partial_eq(deserialized_scene.resources[0].as_ref())
+                .unwrap_or_default());
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_prefabs() {
+        let world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<f32>();
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (1),
+      },
+    ),
+  },
+  prefabs: {
+    4294967296: (
+      scene: "base.scn.ron",
+      overrides: [
+        (
+          entity: 4294967297,
+          component: "bevy_scene::serde::tests::MyComponent",
+          path: ".bar.0",
+          value: {
+            "f32": 2.5,
+          },
+        ),
+      ],
+    ),
+  },
+)"#;
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        assert_eq!(1, scene.prefabs.len());
+        let prefab = &scene.prefabs[0];
+        assert_eq!(Entity::from_raw(0), prefab.entity);
+        assert_eq!(AssetPath::from("base.scn.ron"), prefab.scene);
+        assert_eq!(1, prefab.overrides.len());
+        assert_eq!(Entity::from_raw(1), prefab.overrides[0].entity);
+        assert_eq!(".bar.0", prefab.overrides[0].path);
+
+        let output = scene
+            .serialize_ron(&world.resource::<AppTypeRegistry>().0)
+            .unwrap();
+        assert_eq!(input, output);
+
+        let compact =
+            postcard::to_allocvec(&CompactSceneSerializer::new(&scene, &registry)).unwrap();
+        let from_compact = CompactSceneDeserializer {
+            registry: &registry,
+        }
+        .deserialize(&mut postcard::Deserializer::from_bytes(&compact))
+        .unwrap();
+
+        let compact_prefab = &from_compact.prefabs[0];
+        assert_eq!(prefab.entity, compact_prefab.entity);
+        assert_eq!(prefab.scene, compact_prefab.scene);
+        let (item, compact_item) = (&prefab.overrides[0], &compact_prefab.overrides[0]);
+        assert_eq!(item.entity, compact_item.entity);
+        assert_eq!(item.component, compact_item.component);
+        assert_eq!(item.path, compact_item.path);
+        assert_eq!(Some(&2.5), compact_item.value.downcast_ref::<f32>());
+    }
+
+    #[test]
+    fn compact_should_only_depend_on_scene_types() {
+        mod changed {
+            use bevy_ecs::prelude::{Component, ReflectComponent};
+            use bevy_reflect::Reflect;
+
+            #[derive(Component, Reflect, Default)]
+            #[reflect(Component)]
+            #[type_path = "bevy_scene::serde::tests"]
+            #[type_name = "Foo"]
+            pub struct Foo(i32, i32);
+        }
+
+        let mut world = create_world();
+        world.spawn(Foo(1));
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let scene = DynamicScene::from_world(&world);
+        let serialized_scene =
+            bincode::serialize(&CompactSceneSerializer::new(&scene, &registry)).unwrap();
+        let deserialize = |registry: &TypeRegistry| {
+            bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .deserialize_seed(CompactSceneDeserializer { registry }, &serialized_scene)
+        };
+
+        let other_world = create_world();
+        let mut other_registry = other_world.resource::<AppTypeRegistry>().write();
+        other_registry.register::<Option<Foo>>();
+        let other_scene = deserialize(&other_registry).unwrap();
+        assert_scene_eq(&scene, &other_scene);
+
+        let mut changed_registry = TypeRegistry::default();
+        changed_registry.register::<changed::Foo>();
+        let Err(error) = deserialize(&changed_registry) else {
+            panic!("expected a schema hash mismatch");
+        };
+        assert!(error.to_string().contains("schema hash mismatch"));
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
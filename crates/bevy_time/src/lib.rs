@@ -24,7 +24,7 @@ pub mod prelude {
     pub use crate::{Fixed, Real, Time, Timer, TimerMode, Virtual};
 }
 
-use bevy_app::{prelude::*, RunFixedMainLoop};
+use bevy_app::{prelude::*, AppHarness, RunFixedMainLoop};
 use bevy_ecs::event::{event_queue_update_system, EventUpdateSignal};
 use bevy_ecs::prelude::*;
 use bevy_utils::{tracing::warn, Duration, Instant};
@@ -97,6 +97,39 @@ pub enum TimeUpdateStrategy {
     ManualDuration(Duration),
 }
 
+/// Extension trait to run an [`AppHarness`] with a deterministic frame time.
+pub trait TimeHarnessExt {
+    /// Advances [`Time`] by `frame_time` on each frame, using
+    /// [`TimeUpdateStrategy::ManualDuration`] instead of the system clock.
+    /// As with the system clock, time doesn't advance on the very first frame.
+    ///
+    /// ```
+    /// # use bevy_app::{App, AppHarness};
+    /// # use bevy_time::{prelude::*, TimeHarnessExt, TimePlugin};
+    /// # use bevy_utils::Duration;
+    /// let mut app = App::new();
+    /// app.add_plugins(TimePlugin);
+    ///
+    /// let mut harness = AppHarness::new(app);
+    /// harness
+    ///     .set_frame_time(Duration::from_millis(100))
+    ///     .run_frames(5);
+    /// assert_eq!(
+    ///     harness.world().resource::<Time>().elapsed(),
+    ///     Duration::from_millis(400)
+    /// );
+    /// ```
+    fn set_frame_time(&mut self, frame_time: Duration) -> &mut Self;
+}
+
+impl TimeHarnessExt for AppHarness {
+    fn set_frame_time(&mut self, frame_time: Duration) -> &mut Self {
+        self.world_mut()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(frame_time));
+        self
+    }
+}
+
 /// Channel resource used to receive time from the render world.
 #[derive(Resource)]
 pub struct TimeReceiver(pub Receiver<Instant>);
@@ -0,0 +1,303 @@
+//! Smooth rendering of entities moved in [`FixedUpdate`].
+//!
+//! Entities moved in the fixed timestep only change position when a fixed step runs, which
+//! doesn't happen every frame, so they stutter when the frame rate and the fixed rate differ.
+//! Adding a [`TransformInterpolation`] to an entity records its [`Transform`] before and after each
+//! fixed step, and its [`GlobalTransform`] is then blended between them every frame according
+//! to [`Time::<Fixed>::overstep_fraction`].
+//!
+//! Only the [`GlobalTransform`] is blended: the [`Transform`] stays the simulated value, and the
+//! children of the entity follow its blended position.
+
+use crate::components::{GlobalTransform, Transform};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::{Fixed, Time};
+
+/// How a [`TransformInterpolation`] blends the transforms of the last fixed step.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum InterpolationMode {
+    /// Blends from the transform before the last fixed step to the transform after it.
+    ///
+    /// This is always smooth, but shows the entity up to a fixed step behind its simulated
+    /// position.
+    #[default]
+    Interpolate,
+    /// Continues the movement of the last fixed step past the transform after it.
+    ///
+    /// This shows the entity where it's predicted to be, but it overshoots when the movement
+    /// changes.
+    Extrapolate,
+}
+
+/// Blends the [`GlobalTransform`] of an entity moved in [`FixedUpdate`] between fixed steps.
+///
+/// See the [module docs](crate::interpolation) for details.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct TransformInterpolation {
+    /// How the transforms are blended.
+    pub mode: InterpolationMode,
+    #[reflect(ignore)]
+    previous: Option<Transform>,
+    #[reflect(ignore)]
+    current: Option<Transform>,
+}
+
+impl TransformInterpolation {
+    /// Interpolates the transform, see [`InterpolationMode::Interpolate`].
+    pub const INTERPOLATE: Self = Self::new(InterpolationMode::Interpolate);
+
+    /// Extrapolates the transform, see [`InterpolationMode::Extrapolate`].
+    pub const EXTRAPOLATE: Self = Self::new(InterpolationMode::Extrapolate);
+
+    /// Creates a [`TransformInterpolation`] blending with `mode`.
+    pub const fn new(mode: InterpolationMode) -> Self {
+        Self {
+            mode,
+            previous: None,
+            current: None,
+        }
+    }
+
+    /// Returns the [`Transform`] at the start of the last fixed step.
+    pub fn previous(&self) -> Option<Transform> {
+        self.previous
+    }
+
+    /// Returns the [`Transform`] at the end of the last fixed step.
+    pub fn current(&self) -> Option<Transform> {
+        self.current
+    }
+
+    /// Forgets the recorded transforms, so the entity isn't blended until the next fixed step.
+    ///
+    /// Use this when teleporting the entity, so it doesn't slide to its new position.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.current = None;
+    }
+
+    /// Returns the [`Transform`] blended according to `overstep_fraction`, the fraction of a
+    /// fixed step elapsed since the last one, or `None` if no fixed step ran yet.
+    pub fn blend(&self, overstep_fraction: f32) -> Option<Transform> {
+        let (previous, current) = (self.previous?, self.current?);
+        let s = match self.mode {
+            InterpolationMode::Interpolate => overstep_fraction,
+            InterpolationMode::Extrapolate => 1.0 + overstep_fraction,
+        };
+        Some(Transform {
+            translation: previous.translation.lerp(current.translation, s),
+            rotation: previous.rotation.slerp(current.rotation, s),
+            scale: previous.scale.lerp(current.scale, s),
+        })
+    }
+}
+
+/// Adds the systems of [`TransformInterpolation`]. This is part of the
+/// [`TransformPlugin`](crate::TransformPlugin).
+pub(crate) fn build(app: &mut App) {
+    app.register_type::<TransformInterpolation>()
+        .register_type::<InterpolationMode>()
+        .add_systems(FixedFirst, record_previous_transforms)
+        .add_systems(FixedLast, record_current_transforms);
+}
+
+/// Records the [`Transform`] of interpolated entities before each fixed step.
+pub fn record_previous_transforms(mut query: Query<(&Transform, &mut TransformInterpolation)>) {
+    for (transform, mut interpolation) in &mut query {
+        interpolation.previous = Some(*transform);
+    }
+}
+
+/// Records the [`Transform`] of interpolated entities after each fixed step.
+pub fn record_current_transforms(mut query: Query<(&Transform, &mut TransformInterpolation)>) {
+    for (transform, mut interpolation) in &mut query {
+        interpolation.current = Some(*transform);
+    }
+}
+
+/// Updates the [`GlobalTransform`] of interpolated entities and their descendants with the
+/// blended [`Transform`] of the interpolated entities.
+///
+/// This runs after [`propagate_transforms`](crate::systems::propagate_transforms) and
+/// [`sync_simple_transforms`](crate::systems::sync_simple_transforms), and overrides the
+/// [`GlobalTransform`] they computed.
+pub fn interpolate_global_transforms(
+    fixed_time: Option<Res<Time<Fixed>>>,
+    interpolated: Query<(Entity, Option<&Parent>), With<TransformInterpolation>>,
+    mut transforms: Query<(
+        &Transform,
+        &mut GlobalTransform,
+        Option<&TransformInterpolation>,
+    )>,
+    children: Query<&Children>,
+    parents: Query<&Parent>,
+) {
+    let Some(fixed_time) = fixed_time else {
+        return;
+    };
+    let overstep_fraction = fixed_time.overstep_fraction();
+
+    for (entity, parent) in &interpolated {
+        // Interpolated descendants are updated with their interpolated ancestor.
+        let mut ancestor = parent.map(Parent::get);
+        while let Some(entity) = ancestor {
+            if interpolated.contains(entity) {
+                break;
+            }
+            ancestor = parents.get(entity).ok().map(Parent::get);
+        }
+        if ancestor.is_some() {
+            continue;
+        }
+        let parent = parent.and_then(|parent| transforms.get(parent.get()).ok());
+        let parent = parent.map(|(_, global_transform, _)| *global_transform);
+        propagate_interpolated(
+            entity,
+            parent,
+            overstep_fraction,
+            &mut transforms,
+            &children,
+        );
+    }
+}
+
+fn propagate_interpolated(
+    entity: Entity,
+    parent: Option<GlobalTransform>,
+    overstep_fraction: f32,
+    transforms: &mut Query<(
+        &Transform,
+        &mut GlobalTransform,
+        Option<&TransformInterpolation>,
+    )>,
+    children: &Query<&Children>,
+) {
+    let Ok((transform, mut global_transform, interpolation)) = transforms.get_mut(entity) else {
+        return;
+    };
+    let transform = interpolation
+        .and_then(|interpolation| interpolation.blend(overstep_fraction))
+        .unwrap_or(*transform);
+    *global_transform = match parent {
+        Some(parent) => parent.mul_transform(transform),
+        None => GlobalTransform::from(transform),
+    };
+    let global_transform = *global_transform;
+
+    for &child in children.get(entity).into_iter().flatten() {
+        propagate_interpolated(
+            child,
+            Some(global_transform),
+            overstep_fraction,
+            transforms,
+            children,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransformInterpolation;
+    use crate::{components::*, TransformBundle, TransformPlugin};
+    use bevy_app::{prelude::*, AppHarness};
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::{BuildWorldChildren, Parent};
+    use bevy_math::{Quat, Vec3};
+    use bevy_time::{Fixed, Time, TimeHarnessExt, TimePlugin};
+    use std::time::Duration;
+
+    #[test]
+    fn blend_transforms() {
+        let mut interpolation = TransformInterpolation::INTERPOLATE;
+        assert_eq!(None, interpolation.blend(0.5));
+
+        interpolation.previous = Some(Transform::from_xyz(0.0, 0.0, 0.0));
+        interpolation.current = Some(
+            Transform::from_xyz(2.0, 0.0, 0.0)
+                .with_rotation(Quat::from_rotation_z(1.0))
+                .with_scale(Vec3::splat(3.0)),
+        );
+        let blended = interpolation.blend(0.5).unwrap();
+        assert_eq!(Vec3::new(1.0, 0.0, 0.0), blended.translation);
+        assert!(blended
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(0.5), 1e-6));
+        assert_eq!(Vec3::splat(2.0), blended.scale);
+
+        interpolation.mode = super::InterpolationMode::Extrapolate;
+        let blended = interpolation.blend(0.5).unwrap();
+        assert_eq!(Vec3::new(3.0, 0.0, 0.0), blended.translation);
+
+        interpolation.reset();
+        assert_eq!(None, interpolation.blend(0.5));
+    }
+
+    #[test]
+    fn interpolate_between_fixed_steps() {
+        fn move_right(mut query: Query<&mut Transform, Without<Parent>>) {
+            for mut transform in &mut query {
+                transform.translation.x += 1.0;
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin))
+            .insert_resource(Time::<Fixed>::from_seconds(0.1))
+            .add_systems(FixedUpdate, move_right);
+        let interpolated = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                TransformInterpolation::INTERPOLATE,
+            ))
+            .id();
+        let extrapolated = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                TransformInterpolation::EXTRAPOLATE,
+            ))
+            .id();
+        let child = app
+            .world
+            .spawn(TransformBundle::from_transform(Transform::from_xyz(
+                0.0, 1.0, 0.0,
+            )))
+            .set_parent(interpolated)
+            .id();
+        let mut harness = AppHarness::new(app);
+        harness.set_frame_time(Duration::from_millis(75));
+
+        let translation = |harness: &AppHarness, entity| {
+            let global_transform = harness.world().get::<GlobalTransform>(entity).unwrap();
+            global_transform.translation()
+        };
+        let assert_x = |harness: &AppHarness, entity, x: f32| {
+            let translation = translation(harness, entity);
+            assert!(
+                (translation.x - x).abs() < 1e-4,
+                "expected x = {x}, got {translation}"
+            );
+        };
+
+        // 150ms elapsed: a step ran and half of the next one elapsed.
+        harness.run_frames(3);
+        assert_x(&harness, interpolated, 0.5);
+        assert_x(&harness, extrapolated, 1.5);
+        assert_x(&harness, child, 0.5);
+        assert_eq!(1.0, translation(&harness, child).y);
+
+        // 225ms elapsed: a second step ran and a quarter of the next one elapsed.
+        harness.run_frames(1);
+        assert_x(&harness, interpolated, 1.25);
+        assert_x(&harness, extrapolated, 2.25);
+        assert_x(&harness, child, 1.25);
+        let transform = harness.world().get::<Transform>(interpolated).unwrap();
+        assert_eq!(2.0, transform.translation.x);
+    }
+}
@@ -5,6 +5,7 @@ pub mod commands;
 /// The basic components of the transform crate
 pub mod components;
 pub mod helper;
+pub mod interpolation;
 /// Systems responsible for transform propagation
 pub mod systems;
 
@@ -12,7 +13,10 @@ pub mod systems;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        commands::BuildChildrenTransformExt, components::*, helper::TransformHelper,
+        commands::BuildChildrenTransformExt,
+        components::*,
+        helper::TransformHelper,
+        interpolation::{InterpolationMode, TransformInterpolation},
         TransformBundle, TransformPlugin, TransformPoint,
     };
 }
@@ -22,6 +26,7 @@ use bevy_ecs::prelude::*;
 use bevy_hierarchy::ValidParentCheckPlugin;
 use bevy_math::{Affine3A, Mat4, Vec3};
 
+use interpolation::interpolate_global_transforms;
 use prelude::{GlobalTransform, Transform};
 use systems::{propagate_transforms, sync_simple_transforms};
 
@@ -129,8 +134,13 @@ impl Plugin for TransformPlugin {
                         .in_set(TransformSystem::TransformPropagate)
                         .ambiguous_with(PropagateTransformsSet),
                     propagate_transforms.in_set(PropagateTransformsSet),
+                    interpolate_global_transforms
+                        .in_set(TransformSystem::TransformPropagate)
+                        .after(sync_simple_transforms)
+                        .after(PropagateTransformsSet),
                 ),
             );
+        interpolation::build(app);
     }
 }
 
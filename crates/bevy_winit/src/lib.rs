@@ -299,7 +299,10 @@ impl Default for WinitAppRunnerState {
 ///
 /// Overriding the app's [runner](bevy_app::App::runner) while using `WinitPlugin` will bypass the
 /// `EventLoop`.
-pub fn winit_runner(mut app: App) {
+///
+/// Returns the [`AppExit`] that stopped the event loop, or [`AppExit::error`] if the event loop
+/// itself failed.
+pub fn winit_runner(mut app: App) -> AppExit {
     if app.plugins_state() == PluginsState::Ready {
         app.finish();
         app.cleanup();
@@ -316,7 +319,8 @@ pub fn winit_runner(mut app: App) {
     let mut runner_state = WinitAppRunnerState::default();
 
     // prepare structures to access data in the world
-    let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
+    let mut app_exit = None;
+    let app_exit_slot = &mut app_exit;
     let mut redraw_event_reader = ManualEventReader::<RequestRedraw>::default();
 
     let mut focused_windows_state: SystemState<(Res<WinitSettings>, Query<&Window>)> =
@@ -354,11 +358,10 @@ pub fn winit_runner(mut app: App) {
             }
             runner_state.redraw_requested = true;
 
-            if let Some(app_exit_events) = app.world.get_resource::<Events<AppExit>>() {
-                if app_exit_event_reader.read(app_exit_events).last().is_some() {
-                    event_loop.exit();
-                    return;
-                }
+            if let Some(exit) = app.should_exit() {
+                *app_exit_slot = Some(exit);
+                event_loop.exit();
+                return;
             }
         }
 
@@ -645,7 +648,7 @@ pub fn winit_runner(mut app: App) {
                             &mut focused_windows_state,
                             event_loop,
                             &mut create_window_system_state,
-                            &mut app_exit_event_reader,
+                            app_exit_slot,
                             &mut redraw_event_reader,
                         );
                     }
@@ -773,7 +776,10 @@ pub fn winit_runner(mut app: App) {
     // TODO(clean): the winit docs mention using `spawn` instead of `run` on WASM.
     if let Err(err) = event_loop.run(event_handler) {
         error!("winit event loop returned an error: {err}");
+        return AppExit::error();
     }
+
+    app_exit.unwrap_or_default()
 }
 
 fn run_app_update_if_should(
@@ -790,7 +796,7 @@ fn run_app_update_if_should(
         ResMut<WinitActionHandlers>,
         ResMut<AccessibilityRequested>,
     )>,
-    app_exit_event_reader: &mut ManualEventReader<AppExit>,
+    app_exit: &mut Option<AppExit>,
     redraw_event_reader: &mut ManualEventReader<RequestRedraw>,
 ) {
     if !runner_state.active.should_run() {
@@ -860,10 +866,9 @@ fn run_app_update_if_should(
             }
         }
 
-        if let Some(app_exit_events) = app.world.get_resource::<Events<AppExit>>() {
-            if app_exit_event_reader.read(app_exit_events).last().is_some() {
-                event_loop.exit();
-            }
+        if let Some(exit) = app.should_exit() {
+            *app_exit = Some(exit);
+            event_loop.exit();
         }
     }
 
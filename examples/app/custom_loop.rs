@@ -7,7 +7,7 @@ use std::io;
 #[derive(Resource)]
 struct Input(String);
 
-fn my_runner(mut app: App) {
+fn my_runner(mut app: App) -> AppExit {
     println!("Type stuff into the console");
     for line in io::stdin().lines() {
         {
@@ -15,17 +15,23 @@ fn my_runner(mut app: App) {
             input.0 = line.unwrap();
         }
         app.update();
+
+        if let Some(exit) = app.should_exit() {
+            return exit;
+        }
     }
+
+    AppExit::Success
 }
 
 fn print_system(input: Res<Input>) {
     println!("You typed: {}", input.0);
 }
 
-fn main() {
+fn main() -> AppExit {
     App::new()
         .insert_resource(Input(String::new()))
         .set_runner(my_runner)
         .add_systems(Update, print_system)
-        .run();
+        .run()
 }
@@ -4,7 +4,7 @@ use bevy::{
     asset::{
         embedded_asset,
         io::{Reader, Writer},
-        processor::LoadAndSave,
+        processor::{AssetProcessor, LoadAndSave, LocalProcessedAssetCache},
         ron,
         saver::{AssetSaver, SavedAsset},
         AssetLoader, AsyncReadExt, AsyncWriteExt, LoadContext,
@@ -17,32 +17,65 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 fn main() {
+    // Run with `-- --headless` to process every asset once and exit, like a build pipeline would.
+    if std::env::args().any(|arg| arg == "--headless") {
+        process_assets_headless();
+    }
+
     App::new()
-        // Using the "processed" mode will configure the AssetPlugin to use asset processing.
-        // If you also enable the `asset_processor` cargo feature, this will run the AssetProcessor
-        // in the background, run them through configured asset processors, and write the results to
-        // the `imported_assets` folder. If you also enable the `file_watcher` cargo feature, changes to the
-        // source assets will be detected and they will be reprocessed.
-        //
-        // The AssetProcessor will create `.meta` files automatically for assets in the `assets` folder,
-        // which can then be used to configure how the asset will be processed.
-        .add_plugins((
-            DefaultPlugins.set(AssetPlugin {
-                mode: AssetMode::Processed,
-                // This is just overriding the default paths to scope this to the correct example folder
-                // You can generally skip this in your own projects
-                file_path: "examples/asset/processing/assets".to_string(),
-                processed_file_path: "examples/asset/processing/imported_assets/Default"
-                    .to_string(),
-                ..default()
-            }),
-            TextPlugin,
-        ))
+        .add_plugins((DefaultPlugins.set(asset_plugin()), TextPlugin))
         .add_systems(Startup, setup)
         .add_systems(Update, print_text)
         .run();
 }
 
+fn asset_plugin() -> AssetPlugin {
+    // Using the "processed" mode will configure the AssetPlugin to use asset processing.
+    // If you also enable the `asset_processor` cargo feature, this will run the AssetProcessor
+    // in the background, run them through configured asset processors, and write the results to
+    // the `imported_assets` folder. If you also enable the `file_watcher` cargo feature, changes to the
+    // source assets will be detected and they will be reprocessed.
+    //
+    // The AssetProcessor will create `.meta` files automatically for assets in the `assets` folder,
+    // which can then be used to configure how the asset will be processed.
+    AssetPlugin {
+        mode: AssetMode::Processed,
+        // This is just overriding the default paths to scope this to the correct example folder
+        // You can generally skip this in your own projects
+        file_path: "examples/asset/processing/assets".to_string(),
+        processed_file_path: "examples/asset/processing/imported_assets/Default".to_string(),
+        ..default()
+    }
+}
+
+/// Processes every asset without opening a window, then exits with a nonzero exit code if any asset failed to process.
+fn process_assets_headless() -> ! {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, asset_plugin(), TextPlugin))
+        // Processed assets are shared through this cache, so pointing it at a shared folder
+        // lets other machines skip processing assets that have already been processed.
+        .set_processed_asset_cache(LocalProcessedAssetCache::new(
+            "examples/asset/processing/processed_asset_cache",
+        ));
+    app.finish();
+    app.cleanup();
+
+    let Some(processor) = app.world.get_resource::<AssetProcessor>() else {
+        eprintln!("Headless processing requires the `asset_processor` cargo feature");
+        std::process::exit(1);
+    };
+    let summary = processor.process_assets_headless();
+    for path in &summary.failed {
+        eprintln!("Failed to process {path}");
+    }
+    println!(
+        "Processed {} assets ({} failed)",
+        summary.processed,
+        summary.failed.len()
+    );
+    std::process::exit(summary.exit_code());
+}
+
 /// This [`TextPlugin`] defines two assets types:
 /// * [`CoolText`]: a custom RON text format that supports dependencies and embedded dependencies
 /// * [`Text`]: a "normal" plain text file
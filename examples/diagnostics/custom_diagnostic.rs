@@ -1,7 +1,9 @@
 //! This example illustrates how to create a custom diagnostic.
 
 use bevy::{
-    diagnostic::{Diagnostic, DiagnosticId, Diagnostics, LogDiagnosticsPlugin, RegisterDiagnostic},
+    diagnostic::{
+        Diagnostic, DiagnosticPath, Diagnostics, LogDiagnosticsPlugin, RegisterDiagnostic,
+    },
     prelude::*,
 };
 
@@ -15,19 +17,20 @@ fn main() {
         ))
         // Diagnostics must be initialized before measurements can be added.
         .register_diagnostic(
-            Diagnostic::new(SYSTEM_ITERATION_COUNT, "system_iteration_count", 10)
+            Diagnostic::new(SYSTEM_ITERATION_COUNT)
+                .with_max_history_length(10)
                 .with_suffix(" iterations"),
         )
         .add_systems(Update, my_system)
         .run();
 }
 
-// All diagnostics should have a unique DiagnosticId.
-// For each new diagnostic, generate a new random number.
-pub const SYSTEM_ITERATION_COUNT: DiagnosticId =
-    DiagnosticId::from_u128(337040787172757619024841343456040760896);
+// All diagnostics should have a unique DiagnosticPath.
+// Related diagnostics can be grouped under a common prefix, separated by `/`.
+pub const SYSTEM_ITERATION_COUNT: DiagnosticPath =
+    DiagnosticPath::const_new("my_app/system_iteration_count");
 
 fn my_system(mut diagnostics: Diagnostics) {
     // Add a measurement of 10.0 for our diagnostic each time this system runs.
-    diagnostics.add_measurement(SYSTEM_ITERATION_COUNT, || 10.0);
+    diagnostics.add_measurement(&SYSTEM_ITERATION_COUNT, || 10.0);
 }
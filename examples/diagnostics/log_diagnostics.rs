@@ -11,7 +11,9 @@ fn main() {
             DefaultPlugins,
             // Adds frame time diagnostics
             FrameTimeDiagnosticsPlugin,
-            // Adds a system that prints diagnostics to the console
+            // Adds a system that prints diagnostics to the console. Diagnostics are grouped by path,
+            // e.g. the frame time diagnostics are under `frame_time`, so only that group is logged by
+            // `LogDiagnosticsPlugin::filtered(vec![DiagnosticPath::new("frame_time")])`.
             LogDiagnosticsPlugin::default(),
             // Any plugin can register diagnostics. Uncomment this to add an entity count diagnostics:
             // bevy::diagnostic::EntityCountDiagnosticsPlugin::default(),
@@ -28,14 +28,14 @@ fn help() {
     println!("  u: Unpause");
 }
 
-fn runner(mut app: App) {
+fn runner(mut app: App) -> AppExit {
     banner();
     help();
     let stdin = io::stdin();
     for line in stdin.lock().lines() {
         if let Err(err) = line {
             println!("read err: {:#}", err);
-            break;
+            return AppExit::error();
         }
         match line.unwrap().as_str() {
             "" => {
@@ -76,6 +76,8 @@ fn runner(mut app: App) {
             }
         }
     }
+
+    AppExit::Success
 }
 
 fn print_real_time(time: Res<Time<Real>>) {